@@ -352,7 +352,28 @@ fn trans_type_impl<'tcx>(
 
                     assert!(matches!(ty.fields, FieldsShape::Arbitrary { .. }));
 
-                    return trans_struct(cx, span, ty, true);
+                    let base_offset = parse_attrs(cx, cx.tcx.get_attrs(adt.did))
+                        .find_map(|attr| match attr {
+                            SpirvAttribute::PushConstantOffset(offset) => {
+                                Some(Size::from_bytes(offset))
+                            }
+                            _ => None,
+                        })
+                        .unwrap_or(Size::ZERO);
+                    if !base_offset.is_aligned(ty.align.abi.max(Align::from_bytes(4).unwrap())) {
+                        cx.tcx.sess.span_err(
+                            span,
+                            &format!(
+                                "`#[spirv(push_constant_offset = {})]` must be a multiple of \
+                                 4 and of the alignment of `{}` ({} bytes)",
+                                base_offset.bytes(),
+                                ty.ty,
+                                ty.align.abi.bytes(),
+                            ),
+                        );
+                    }
+
+                    return trans_struct(cx, span, ty, true, base_offset);
                 }
                 SpirvAttribute::Image => return trans_image(cx, span, ty, substs),
                 SpirvAttribute::Sampler => {
@@ -362,6 +383,15 @@ fn trans_type_impl<'tcx>(
                     }
                     return SpirvType::Sampler.def(span, cx);
                 }
+                SpirvAttribute::AccelerationStructure => {
+                    // see SpirvType::sizeof
+                    if ty.size != Size::from_bytes(4) {
+                        cx.tcx
+                            .sess
+                            .fatal("#[spirv(acceleration_structure)] type must have size 4");
+                    }
+                    return SpirvType::AccelerationStructureKhr.def(span, cx);
+                }
                 SpirvAttribute::SampledImage => {
                     // see SpirvType::sizeof
                     if ty.size != Size::from_bytes(4) {
@@ -382,6 +412,13 @@ fn trans_type_impl<'tcx>(
                             .fatal("#[spirv(sampled_image)] type must have a generic image type");
                     }
                 }
+                SpirvAttribute::Matrix => return trans_matrix(cx, span, ty),
+                SpirvAttribute::F16 => {
+                    if ty.size != Size::from_bytes(2) {
+                        cx.tcx.sess.fatal("#[spirv(f16)] type must have size 2");
+                    }
+                    return SpirvType::Float(16).def(span, cx);
+                }
                 SpirvAttribute::Bind => {
                     let mut subst_types = substs.types();
                     if let (Some(desc_ty), None) = (subst_types.next(), subst_types.next()) {
@@ -434,7 +471,7 @@ fn trans_type_impl<'tcx>(
                 align: ty.align.abi,
                 field_types: vec![one_spirv, two_spirv],
                 field_offsets: vec![one_offset, two_offset],
-                field_names: None,
+                field_names: scalar_pair_field_names(cx, ty),
                 is_block: false,
             }
             .def_with_name(cx, span, TyLayoutNameKey::from(ty))
@@ -800,7 +837,7 @@ fn trans_aggregate<'tcx>(cx: &CodegenCx<'tcx>, span: Span, ty: TyAndLayout<'tcx>
         FieldsShape::Arbitrary {
             offsets: _,
             memory_index: _,
-        } => trans_struct(cx, span, ty, false),
+        } => trans_struct(cx, span, ty, false, Size::ZERO),
     }
 }
 
@@ -835,6 +872,9 @@ fn trans_struct<'tcx>(
     span: Span,
     ty: TyAndLayout<'tcx>,
     is_block: bool,
+    // Only observable through the `Offset` decorations, as field
+    // accesses on blocks always go through member indices.
+    base_offset: Size,
 ) -> Word {
     if let TyKind::Foreign(_) = ty.ty.kind() {
         // "An unsized FFI type that is opaque to Rust", `extern type A;` (currently unstable)
@@ -856,7 +896,7 @@ fn trans_struct<'tcx>(
     for i in ty.fields.index_by_increasing_offset() {
         let field_ty = ty.field(cx, i);
         field_types.push(trans_type_impl(cx, span, field_ty, false));
-        let offset = ty.fields.offset(i);
+        let offset = base_offset + ty.fields.offset(i);
         field_offsets.push(offset);
         if let Variants::Single { index } = ty.variants {
             if let TyKind::Adt(adt, _) = ty.ty.kind() {
@@ -889,6 +929,34 @@ fn trans_struct<'tcx>(
     .def_with_name(cx, span, TyLayoutNameKey::from(ty))
 }
 
+/// The names of the two fields of a struct or tuple laid out as a `ScalarPair`, in
+/// order of increasing offset, ignoring zero-sized fields. Anything else (e.g. fat
+/// pointers, or enums with a scalar payload) is left unnamed.
+fn scalar_pair_field_names<'tcx>(
+    cx: &CodegenCx<'tcx>,
+    ty: TyAndLayout<'tcx>,
+) -> Option<Vec<String>> {
+    let name_of = |i: usize| match ty.ty.kind() {
+        TyKind::Adt(adt, _) if adt.is_struct() => {
+            let field = &adt.non_enum_variant().fields[i];
+            Some(field.ident.name.to_ident_string())
+        }
+        TyKind::Tuple(_) => Some(format!("{}", i)),
+        _ => None,
+    };
+    let field_names = ty
+        .fields
+        .index_by_increasing_offset()
+        .filter(|&i| !ty.field(cx, i).is_zst())
+        .map(name_of)
+        .collect::<Option<Vec<_>>>()?;
+    if field_names.len() == 2 {
+        Some(field_names)
+    } else {
+        None
+    }
+}
+
 /// Grab a `DefId` from the type if possible to avoid too much deduplication,
 /// which could result in one SPIR-V `OpType*` having many names
 /// (not in itself an issue, but it makes error reporting harder).
@@ -937,6 +1005,55 @@ impl fmt::Display for TyLayoutNameKey<'_> {
     }
 }
 
+/// A `#[spirv(matrix)]` struct must consist of 2 to 4 fields of the same float vector type, its
+/// columns, laid out one after the other.
+fn trans_matrix<'tcx>(cx: &CodegenCx<'tcx>, span: Span, ty: TyAndLayout<'tcx>) -> Word {
+    let count = ty.fields.count();
+    if !matches!(ty.fields, FieldsShape::Arbitrary { .. }) || !(2..=4).contains(&count) {
+        cx.tcx.sess.span_fatal(
+            span,
+            &format!(
+                "#[spirv(matrix)] type `{}` must be a struct of 2 to 4 column vectors",
+                ty.ty
+            ),
+        );
+    }
+    let column = ty.field(cx, 0);
+    let element = trans_type_impl(cx, span, column, false);
+    let is_float_vector = match cx.lookup_type(element) {
+        SpirvType::Vector { element, .. } => {
+            matches!(cx.lookup_type(element), SpirvType::Float(_))
+        }
+        _ => false,
+    };
+    if !is_float_vector {
+        cx.tcx.sess.span_fatal(
+            span,
+            &format!(
+                "#[spirv(matrix)] type `{}` must have float vector columns, not `{}`",
+                ty.ty, column.ty
+            ),
+        );
+    }
+    for i in 0..count {
+        if ty.field(cx, i).ty != column.ty || ty.fields.offset(i) != column.size * i as u64 {
+            cx.tcx.sess.span_fatal(
+                span,
+                &format!(
+                    "#[spirv(matrix)] type `{}` must have all its columns of type `{}`, \
+                     in order and without padding",
+                    ty.ty, column.ty
+                ),
+            );
+        }
+    }
+    SpirvType::Matrix {
+        element,
+        count: count as u32,
+    }
+    .def_with_name(cx, span, TyLayoutNameKey::from(ty))
+}
+
 fn trans_image<'tcx>(
     cx: &CodegenCx<'tcx>,
     span: Span,
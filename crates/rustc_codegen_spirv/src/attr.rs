@@ -3,7 +3,9 @@
 //! The attribute-checking parts of this try to follow `rustc_passes::check_attr`.
 
 use crate::symbols::{SpirvAttribute, Symbols};
+use rspirv::spirv::StorageClass;
 use rustc_ast::Attribute;
+use rustc_errors::Applicability;
 use rustc_hir as hir;
 use rustc_hir::def_id::LocalDefId;
 use rustc_hir::intravisit::{self, NestedVisitorMap, Visitor};
@@ -11,6 +13,7 @@ use rustc_hir::{HirId, MethodKind, Target, CRATE_HIR_ID};
 use rustc_middle::hir::map::Map;
 use rustc_middle::ty::query::Providers;
 use rustc_middle::ty::TyCtxt;
+use rustc_span::Span;
 use std::rc::Rc;
 
 // FIXME(eddyb) make this reusable from somewhere in `rustc`.
@@ -67,7 +70,18 @@ impl CheckSpirvAttrVisitor<'_> {
                 SpirvAttribute::Builtin(_)
                 | SpirvAttribute::DescriptorSet(_)
                 | SpirvAttribute::Binding(_)
-                | SpirvAttribute::Flat => match target {
+                | SpirvAttribute::Flat
+                | SpirvAttribute::NoPerspective
+                | SpirvAttribute::Centroid
+                | SpirvAttribute::Sample
+                | SpirvAttribute::Patch
+                | SpirvAttribute::Invariant
+                | SpirvAttribute::Component(_)
+                | SpirvAttribute::InputAttachmentIndex(_)
+                | SpirvAttribute::MemoryDecoration(_)
+                | SpirvAttribute::RelaxedPrecision
+                | SpirvAttribute::SpecConstant { .. }
+                | SpirvAttribute::ConservativeDepth(_) => match target {
                     Target::Param => {
                         let parent_hir_id = self.tcx.hir().get_parent_node(hir_id);
                         let parent_is_entry_point =
@@ -83,6 +97,41 @@ impl CheckSpirvAttrVisitor<'_> {
                         Ok(())
                     }
 
+                    // Storage class types like `ReadOnlyStorageBuffer` imply their access.
+                    Target::Struct
+                        if matches!(parsed_attr, SpirvAttribute::MemoryDecoration(_)) =>
+                    {
+                        Ok(())
+                    }
+
+                    // `relaxed_precision` can also apply to a function's arithmetic.
+                    Target::Fn
+                    | Target::Closure
+                    | Target::Method(MethodKind::Trait { body: true })
+                    | Target::Method(MethodKind::Inherent)
+                        if matches!(parsed_attr, SpirvAttribute::RelaxedPrecision) =>
+                    {
+                        Ok(())
+                    }
+
+                    // Spec constant statics are declared by `declare_spec_constant_static`.
+                    Target::Static
+                        if matches!(parsed_attr, SpirvAttribute::SpecConstant { .. }) =>
+                    {
+                        Ok(())
+                    }
+
+                    // rustc inlines the value of a `const` wherever it's used, so there's no
+                    // value left for the host to specialize.
+                    Target::Const if matches!(parsed_attr, SpirvAttribute::SpecConstant { .. }) => {
+                        self.tcx
+                            .sess
+                            .struct_span_err(span, "spec constants can't be `const`s")
+                            .help("declare it as a `static` instead")
+                            .emit();
+                        Ok(())
+                    }
+
                     _ => Err(Expected("function parameter")),
                 },
 
@@ -92,13 +141,17 @@ impl CheckSpirvAttrVisitor<'_> {
                     | Target::Method(MethodKind::Inherent) => {
                         // FIXME(eddyb) further check entry-point attribute validity,
                         // e.g. signature, shouldn't have `#[inline]` or generics, etc.
+                        self.warn_deprecated_execution_modes(attr, span);
                         Ok(())
                     }
 
                     _ => Err(Expected("function")),
                 },
 
-                SpirvAttribute::UnrollLoops => match target {
+                SpirvAttribute::UnrollLoops(_)
+                | SpirvAttribute::LoopIterations(_)
+                | SpirvAttribute::NoContraction
+                | SpirvAttribute::FastMath => match target {
                     Target::Fn
                     | Target::Closure
                     | Target::Method(MethodKind::Trait { body: true })
@@ -107,19 +160,38 @@ impl CheckSpirvAttrVisitor<'_> {
                     _ => Err(Expected("function or closure")),
                 },
 
-                SpirvAttribute::IndexDescriptorArray => match target {
+                SpirvAttribute::IndexDescriptorArray
+                | SpirvAttribute::IndexDescriptorArrayNonUniform
+                | SpirvAttribute::Undef => match target {
                     Target::Fn
                     | Target::Method(MethodKind::Trait { body: true })
                     | Target::Method(MethodKind::Inherent) => Ok(()),
                     _ => Err(Expected("function or method")),
-                }
+                },
+
+                SpirvAttribute::ShaderStruct => match target {
+                    Target::Trait => Ok(()),
+                    _ => Err(Expected("trait")),
+                },
+
+                // Workgroup memory can also be declared as a static, shared by the
+                // invocations of a workgroup.
+                SpirvAttribute::StorageClass(StorageClass::Workgroup) => match target {
+                    Target::Struct | Target::Static => Ok(()),
+                    _ => Err(Expected("struct or static")),
+                },
 
                 SpirvAttribute::StorageClass(_)
                 | SpirvAttribute::Image
                 | SpirvAttribute::Sampler
+                | SpirvAttribute::AccelerationStructure
                 | SpirvAttribute::SampledImage
+                | SpirvAttribute::F16
+                | SpirvAttribute::Matrix
                 | SpirvAttribute::Block
-                | SpirvAttribute::Bind => match target {
+                | SpirvAttribute::Bind
+                | SpirvAttribute::Layout(_)
+                | SpirvAttribute::PushConstantOffset(_) => match target {
                     Target::Struct => {
                         // FIXME(eddyb) further check type attribute validity,
                         // e.g. layout, generics, other attributes, etc.
@@ -141,6 +213,30 @@ impl CheckSpirvAttrVisitor<'_> {
             }
         }
     }
+
+    /// Warns about deprecated spellings of execution modes, in the entry-point
+    /// argument at `entry_span` of the `#[spirv(...)]` attribute `attr`.
+    fn warn_deprecated_execution_modes(&self, attr: &Attribute, entry_span: Span) {
+        let entries = attr.meta_item_list().unwrap_or_default();
+        let modes = entries
+            .iter()
+            .filter(|entry| entry.span() == entry_span)
+            .flat_map(|entry| entry.meta_item_list().unwrap_or_default());
+        for mode in modes {
+            if mode.has_name(self.sym.orgin_upper_left) {
+                self.tcx
+                    .sess
+                    .struct_span_warn(mode.span(), "`orgin_upper_left` is deprecated")
+                    .span_suggestion(
+                        mode.span(),
+                        "use the correct spelling",
+                        "origin_upper_left".to_string(),
+                        Applicability::MachineApplicable,
+                    )
+                    .emit();
+            }
+        }
+    }
 }
 
 // FIXME(eddyb) DRY this somehow and make it reusable from somewhere in `rustc`.
@@ -2,7 +2,9 @@ use super::Builder;
 use crate::builder_spirv::{BuilderCursor, SpirvConst, SpirvValue, SpirvValueExt, SpirvValueKind};
 use crate::spirv_type::SpirvType;
 use rspirv::dr::{InsertPoint, Instruction, Operand};
-use rspirv::spirv::{Capability, MemoryModel, MemorySemantics, Op, Scope, StorageClass, Word};
+use rspirv::spirv::{
+    Capability, Decoration, Dim, MemoryModel, MemorySemantics, Op, Scope, StorageClass, Word,
+};
 use rustc_codegen_ssa::common::{
     AtomicOrdering, AtomicRmwBinOp, IntPredicate, RealPredicate, SynchronizationScope,
 };
@@ -20,9 +22,10 @@ use std::convert::TryInto;
 use std::iter::empty;
 use std::ops::Range;
 
+// Integer arithmetic, which is an `OpSpecConstantOp` on spec constants (see `spec_constant_op`).
 macro_rules! simple_op {
     (
-        $func_name:ident, $inst_name:ident
+        $func_name:ident, $inst_name:ident, $spec_constant_op:ident
         $(, fold_const {
             $(int($fold_int_lhs:ident, $fold_int_rhs:ident) => $fold_int:expr)?
         })?
@@ -59,6 +62,12 @@ macro_rules! simple_op {
                 }
             })?
 
+            if let Some(result) =
+                self.spec_constant_op(result_type, Op::$spec_constant_op, &[lhs, rhs])
+            {
+                return result;
+            }
+
             self.emit()
                 .$inst_name(result_type, None, lhs.def(self), rhs.def(self))
                 .unwrap()
@@ -69,8 +78,12 @@ macro_rules! simple_op {
 
 // shl and shr allow different types as their operands
 macro_rules! simple_op_unchecked_type {
-    ($func_name:ident, $inst_name:ident) => {
+    ($func_name:ident, $inst_name:ident, $spec_constant_op:ident) => {
         fn $func_name(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
+            if let Some(result) = self.spec_constant_op(lhs.ty, Op::$spec_constant_op, &[lhs, rhs])
+            {
+                return result;
+            }
             self.emit()
                 .$inst_name(lhs.ty, None, lhs.def(self), rhs.def(self))
                 .unwrap()
@@ -80,8 +93,11 @@ macro_rules! simple_op_unchecked_type {
 }
 
 macro_rules! simple_uni_op {
-    ($func_name:ident, $inst_name:ident) => {
+    ($func_name:ident, $inst_name:ident, $spec_constant_op:ident) => {
         fn $func_name(&mut self, val: Self::Value) -> Self::Value {
+            if let Some(result) = self.spec_constant_op(val.ty, Op::$spec_constant_op, &[val]) {
+                return result;
+            }
             self.emit()
                 .$inst_name(val.ty, None, val.def(self))
                 .unwrap()
@@ -90,6 +106,31 @@ macro_rules! simple_uni_op {
     };
 }
 
+// Floating-point arithmetic, which is affected by `decorate_fp_math`.
+macro_rules! simple_fp_op {
+    ($func_name:ident, $inst_name:ident) => {
+        fn $func_name(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
+            assert_ty_eq!(self, lhs.ty, rhs.ty);
+            let result = self
+                .emit()
+                .$inst_name(lhs.ty, None, lhs.def(self), rhs.def(self))
+                .unwrap();
+            self.decorate_fp_math(result);
+            result.with_type(lhs.ty)
+        }
+    };
+}
+
+macro_rules! simple_fp_uni_op {
+    ($func_name:ident, $inst_name:ident) => {
+        fn $func_name(&mut self, val: Self::Value) -> Self::Value {
+            let result = self.emit().$inst_name(val.ty, None, val.def(self)).unwrap();
+            self.decorate_fp_math(result);
+            result.with_type(val.ty)
+        }
+    };
+}
+
 fn memset_fill_u16(b: u8) -> u16 {
     b as u16 | ((b as u16) << 8)
 }
@@ -210,7 +251,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             },
             SpirvType::Adt { .. } => self.fatal("memset on structs not implemented yet"),
             SpirvType::Opaque { .. } => self.fatal("memset on opaque type is invalid"),
-            SpirvType::Vector { element, count } => {
+            SpirvType::Vector { element, count } | SpirvType::Matrix { element, count } => {
                 let elem_pat = self.memset_const_pattern(&self.lookup_type(element), fill_byte);
                 self.constant_composite(
                     ty.clone().def(self.span(), self),
@@ -232,6 +273,9 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             SpirvType::Image { .. } => self.fatal("cannot memset image"),
             SpirvType::Sampler => self.fatal("cannot memset sampler"),
             SpirvType::SampledImage { .. } => self.fatal("cannot memset sampled image"),
+            SpirvType::AccelerationStructureKhr => {
+                self.fatal("cannot memset acceleration structure")
+            }
         }
     }
 
@@ -270,7 +314,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                     )
                     .unwrap()
             }
-            SpirvType::Vector { element, count } => {
+            SpirvType::Vector { element, count } | SpirvType::Matrix { element, count } => {
                 let elem_pat = self.memset_dynamic_pattern(&self.lookup_type(element), fill_var);
                 self.emit()
                     .composite_construct(
@@ -288,6 +332,9 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             SpirvType::Image { .. } => self.fatal("cannot memset image"),
             SpirvType::Sampler => self.fatal("cannot memset sampler"),
             SpirvType::SampledImage { .. } => self.fatal("cannot memset sampled image"),
+            SpirvType::AccelerationStructureKhr => {
+                self.fatal("cannot memset acceleration structure")
+            }
         }
     }
 
@@ -367,6 +414,52 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         }
     }
 
+    /// If `ty` is a fat pointer to a block ending in a runtime array (e.g. `&Data` for
+    /// `struct Data { len: u32, items: [u32] }`), return the types of its pointer and length
+    /// halves, and the member index of the runtime array (always the block's last member).
+    fn runtime_array_fat_pointer(&self, ty: Word) -> Option<(Word, Word, u32)> {
+        let (ptr_ty, len_ty) = match self.lookup_type(ty) {
+            SpirvType::Adt { field_types, .. } if field_types.len() == 2 => {
+                (field_types[0], field_types[1])
+            }
+            _ => return None,
+        };
+        let pointee = match self.lookup_type(ptr_ty) {
+            SpirvType::Pointer { pointee } => pointee,
+            _ => return None,
+        };
+        match self.lookup_type(pointee) {
+            SpirvType::Adt {
+                size: None,
+                field_types,
+                ..
+            } => Some((ptr_ty, len_ty, field_types.len() as u32 - 1)),
+            _ => None,
+        }
+    }
+
+    /// Vulkan needs an extra capability to index arrays of images and samplers
+    /// with a non-uniform index, on top of `ShaderNonUniform`, which differs
+    /// between the kinds of descriptor the pointer `ptr_ty` points to.
+    fn nonuniform_indexing_capability(&self, ptr_ty: Word) -> Option<Capability> {
+        let pointee = match self.lookup_type(ptr_ty) {
+            SpirvType::Pointer { pointee } => pointee,
+            _ => return None,
+        };
+        match self.lookup_type(pointee) {
+            SpirvType::Sampler | SpirvType::SampledImage { .. } => {
+                Some(Capability::SampledImageArrayNonUniformIndexing)
+            }
+            SpirvType::Image { dim, sampled, .. } => Some(match (dim, sampled) {
+                (Dim::DimBuffer, 2) => Capability::StorageTexelBufferArrayNonUniformIndexing,
+                (Dim::DimBuffer, _) => Capability::UniformTexelBufferArrayNonUniformIndexing,
+                (_, 2) => Capability::StorageImageArrayNonUniformIndexing,
+                _ => Capability::SampledImageArrayNonUniformIndexing,
+            }),
+            _ => None,
+        }
+    }
+
     /// If possible, return the appropriate `OpAccessChain` indices for going from
     /// a pointer to `ty`, to a pointer to `leaf_ty`, with an added `offset`.
     ///
@@ -422,6 +515,7 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
                     offset = offset_in_field;
                 }
                 SpirvType::Vector { element, .. }
+                | SpirvType::Matrix { element, .. }
                 | SpirvType::Array { element, .. }
                 | SpirvType::RuntimeArray { element } => {
                     ty = element;
@@ -439,6 +533,40 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
             }
         }
     }
+
+    /// Integer arithmetic on spec constants (and constants) has to be a spec constant itself,
+    /// so that e.g. `SIZE * 2` follows what the host specializes `SIZE` to, which takes an
+    /// `OpSpecConstantOp` instead of an instruction in the current block.
+    fn spec_constant_op(
+        &self,
+        result_type: Word,
+        op: Op,
+        operands: &[SpirvValue],
+    ) -> Option<SpirvValue> {
+        let is_spec_constant = |value: &SpirvValue| match value.kind {
+            SpirvValueKind::Def(id) => self.spec_constant_ids.borrow().contains(&id),
+            _ => false,
+        };
+        let is_constant = |value: &SpirvValue| {
+            is_spec_constant(value) || self.builder.lookup_const(*value).is_some()
+        };
+        if !operands.iter().any(&is_spec_constant) || !operands.iter().all(is_constant) {
+            return None;
+        }
+        let operands = std::iter::once(Operand::LiteralSpecConstantOpInteger(op))
+            .chain(operands.iter().map(|value| Operand::IdRef(value.def(self))))
+            .collect();
+        let mut emit = self.emit_global();
+        let result = emit.id();
+        emit.module_mut().types_global_values.push(Instruction::new(
+            Op::SpecConstantOp,
+            Some(result_type),
+            Some(result),
+            operands,
+        ));
+        self.spec_constant_ids.borrow_mut().insert(result);
+        Some(result.with_type(result_type))
+    }
 }
 
 impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
@@ -631,52 +759,55 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
         self.emit().unreachable().unwrap()
     }
 
-    simple_op! {add, i_add}
-    simple_op! {fadd, f_add}
-    simple_op! {fadd_fast, f_add} // fast=normal
-    simple_op! {sub, i_sub}
-    simple_op! {fsub, f_sub}
-    simple_op! {fsub_fast, f_sub} // fast=normal
+    simple_op! {add, i_add, IAdd}
+    simple_fp_op! {fadd, f_add}
+    simple_fp_op! {fadd_fast, f_add} // fast=normal
+    simple_op! {sub, i_sub, ISub}
+    simple_fp_op! {fsub, f_sub}
+    simple_fp_op! {fsub_fast, f_sub} // fast=normal
     simple_op! {
-        mul, i_mul,
+        mul, i_mul, IMul,
         // HACK(eddyb) `rustc_codegen_ssa` relies on `Builder` methods doing
         // on-the-fly constant-folding, for e.g. intrinsics that copy memory.
         fold_const {
             int(a, b) => a * b
         }
     }
-    simple_op! {fmul, f_mul}
-    simple_op! {fmul_fast, f_mul} // fast=normal
-    simple_op! {udiv, u_div}
+    simple_fp_op! {fmul, f_mul}
+    simple_fp_op! {fmul_fast, f_mul} // fast=normal
+    simple_op! {udiv, u_div, UDiv}
     // Note: exactudiv is UB when there's a remainder, so it's valid to implement as a normal div.
     // TODO: Can we take advantage of the UB and emit something else?
-    simple_op! {exactudiv, u_div}
-    simple_op! {sdiv, s_div}
+    simple_op! {exactudiv, u_div, UDiv}
+    simple_op! {sdiv, s_div, SDiv}
     // Same note and TODO as exactudiv
-    simple_op! {exactsdiv, s_div}
-    simple_op! {fdiv, f_div}
-    simple_op! {fdiv_fast, f_div} // fast=normal
-    simple_op! {urem, u_mod}
-    simple_op! {srem, s_rem}
-    simple_op! {frem, f_rem}
-    simple_op! {frem_fast, f_rem} // fast=normal
-    simple_op_unchecked_type! {shl, shift_left_logical}
-    simple_op_unchecked_type! {lshr, shift_right_logical}
-    simple_op_unchecked_type! {ashr, shift_right_arithmetic}
-    simple_op! {unchecked_sadd, i_add} // already unchecked by default
-    simple_op! {unchecked_uadd, i_add} // already unchecked by default
-    simple_op! {unchecked_ssub, i_sub} // already unchecked by default
-    simple_op! {unchecked_usub, i_sub} // already unchecked by default
-    simple_op! {unchecked_smul, i_mul} // already unchecked by default
-    simple_op! {unchecked_umul, i_mul} // already unchecked by default
-    simple_uni_op! {neg, s_negate}
-    simple_uni_op! {fneg, f_negate}
+    simple_op! {exactsdiv, s_div, SDiv}
+    simple_fp_op! {fdiv, f_div}
+    simple_fp_op! {fdiv_fast, f_div} // fast=normal
+    simple_op! {urem, u_mod, UMod}
+    simple_op! {srem, s_rem, SRem}
+    simple_fp_op! {frem, f_rem}
+    simple_fp_op! {frem_fast, f_rem} // fast=normal
+    simple_op_unchecked_type! {shl, shift_left_logical, ShiftLeftLogical}
+    simple_op_unchecked_type! {lshr, shift_right_logical, ShiftRightLogical}
+    simple_op_unchecked_type! {ashr, shift_right_arithmetic, ShiftRightArithmetic}
+    simple_op! {unchecked_sadd, i_add, IAdd} // already unchecked by default
+    simple_op! {unchecked_uadd, i_add, IAdd} // already unchecked by default
+    simple_op! {unchecked_ssub, i_sub, ISub} // already unchecked by default
+    simple_op! {unchecked_usub, i_sub, ISub} // already unchecked by default
+    simple_op! {unchecked_smul, i_mul, IMul} // already unchecked by default
+    simple_op! {unchecked_umul, i_mul, IMul} // already unchecked by default
+    simple_uni_op! {neg, s_negate, SNegate}
+    simple_fp_uni_op! {fneg, f_negate}
 
     fn and(&mut self, lhs: Self::Value, rhs: Self::Value) -> Self::Value {
         assert_ty_eq!(self, lhs.ty, rhs.ty);
         let ty = lhs.ty;
         match self.lookup_type(ty) {
             SpirvType::Integer(_, _) => {
+                if let Some(result) = self.spec_constant_op(ty, Op::BitwiseAnd, &[lhs, rhs]) {
+                    return result;
+                }
                 self.emit()
                     .bitwise_and(ty, None, lhs.def(self), rhs.def(self))
             }
@@ -696,6 +827,9 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
         let ty = lhs.ty;
         match self.lookup_type(ty) {
             SpirvType::Integer(_, _) => {
+                if let Some(result) = self.spec_constant_op(ty, Op::BitwiseOr, &[lhs, rhs]) {
+                    return result;
+                }
                 self.emit()
                     .bitwise_or(ty, None, lhs.def(self), rhs.def(self))
             }
@@ -715,6 +849,9 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
         let ty = lhs.ty;
         match self.lookup_type(ty) {
             SpirvType::Integer(_, _) => {
+                if let Some(result) = self.spec_constant_op(ty, Op::BitwiseXor, &[lhs, rhs]) {
+                    return result;
+                }
                 self.emit()
                     .bitwise_xor(ty, None, lhs.def(self), rhs.def(self))
             }
@@ -1040,9 +1177,10 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
             } => field_types[idx as usize],
             SpirvType::Array { element, .. }
             | SpirvType::RuntimeArray { element, .. }
-            | SpirvType::Vector { element, .. } => element,
+            | SpirvType::Vector { element, .. }
+            | SpirvType::Matrix { element, .. } => element,
             other => self.fatal(&format!(
-                "struct_gep not on struct, array, or vector type: {:?}, index {}",
+                "struct_gep not on struct, array, vector or matrix type: {:?}, index {}",
                 other, idx
             )),
         };
@@ -1063,7 +1201,8 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
                 SpirvType::Adt { field_offsets, .. } => field_offsets[idx as usize],
                 SpirvType::Array { element, .. }
                 | SpirvType::RuntimeArray { element, .. }
-                | SpirvType::Vector { element, .. } => {
+                | SpirvType::Vector { element, .. }
+                | SpirvType::Matrix { element, .. } => {
                     self.lookup_type(element).sizeof(self).unwrap() * idx
                 }
                 _ => unreachable!(),
@@ -1281,16 +1420,32 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
                 .unwrap()
                 .with_type(dest_ty),
             // width change, and optional sign change
-            (SpirvType::Integer(_, _), SpirvType::Integer(_, dest_signedness)) => {
-                // spir-v spec doesn't seem to say that signedness needs to match the operands, only that the signedness
-                // of the destination type must match the instruction's signedness.
-                if dest_signedness {
-                    self.emit().s_convert(dest_ty, None, val.def(self))
+            (SpirvType::Integer(_, _), SpirvType::Integer(dest_width, dest_signedness)) => {
+                // Whether to sign-extend depends on the signedness of the source (`is_signed`),
+                // not of the destination, e.g. `-1i32 as u64` is all ones, and `u8::MAX as i32`
+                // is 255. `OpSConvert` can produce any integer type, but `OpUConvert` must
+                // produce an unsigned one, so zero-extending into a signed type needs a bitcast.
+                if is_signed {
+                    self.emit()
+                        .s_convert(dest_ty, None, val.def(self))
+                        .unwrap()
+                        .with_type(dest_ty)
+                } else if dest_signedness {
+                    let unsigned_ty = SpirvType::Integer(dest_width, false).def(self.span(), self);
+                    let unsigned = self
+                        .emit()
+                        .u_convert(unsigned_ty, None, val.def(self))
+                        .unwrap();
+                    self.emit()
+                        .bitcast(dest_ty, None, unsigned)
+                        .unwrap()
+                        .with_type(dest_ty)
                 } else {
-                    self.emit().u_convert(dest_ty, None, val.def(self))
+                    self.emit()
+                        .u_convert(dest_ty, None, val.def(self))
+                        .unwrap()
+                        .with_type(dest_ty)
                 }
-                .unwrap()
-                .with_type(dest_ty)
             }
             // bools are ints in llvm, so we have to implement this here
             (SpirvType::Bool, SpirvType::Integer(_, _)) => {
@@ -2120,13 +2275,45 @@ impl<'a, 'tcx> BuilderMethods<'a, 'tcx> for Builder<'a, 'tcx> {
             // needing to materialize `&core::panic::Location` or `format_args!`.
             self.abort();
             self.undef(result_type)
+        } else if self.undef_fn_ids.borrow().contains(&llfn_def) {
+            self.undef(result_type)
         } else if self.index_descriptor_array_id.borrow().contains(&llfn_def) {
             let base = self.load(args[0], Align::from_bytes(0).unwrap()).def(self);
             let index = args[1].def(self);
-            self.emit()
-                .access_chain(result_type, None, base, core::iter::once(index))
-                .unwrap()
-                .with_type(result_type)
+            // Blocks ending in a runtime array are returned as a fat pointer, the length half of
+            // which is queried with `OpArrayLength`, like entry points do for their parameters.
+            let fat_pointer = self.runtime_array_fat_pointer(result_type);
+            let ptr_type = fat_pointer.map_or(result_type, |(ptr_type, _, _)| ptr_type);
+            let ptr = self
+                .emit()
+                .access_chain(ptr_type, None, base, core::iter::once(index))
+                .unwrap();
+            if self
+                .nonuniform_index_descriptor_array_id
+                .borrow()
+                .contains(&llfn_def)
+            {
+                // The linker propagates the decoration to values derived from this pointer.
+                let capabilities = core::iter::once(Capability::ShaderNonUniform)
+                    .chain(self.nonuniform_indexing_capability(ptr_type));
+                for capability in capabilities {
+                    if !self.builder.has_capability(capability) {
+                        self.emit_global().capability(capability);
+                    }
+                }
+                self.emit_global()
+                    .decorate(ptr, Decoration::NonUniform, empty());
+            }
+            let result = match fat_pointer {
+                Some((_, len_type, member)) => {
+                    let mut emit = self.emit();
+                    let len = emit.array_length(len_type, None, ptr, member).unwrap();
+                    emit.composite_construct(result_type, None, [ptr, len].iter().copied())
+                        .unwrap()
+                }
+                None => ptr,
+            };
+            result.with_type(result_type)
         } else {
             let args = args.iter().map(|arg| arg.def(self)).collect::<Vec<_>>();
             self.emit()
@@ -40,6 +40,16 @@ impl ExtInst {
         }
     }
 
+    /// Import the extended instruction set `name`, e.g. from an `OpExtInstImport`
+    /// in `asm!`, reusing the existing import for `GLSL.std.450` or `OpenCL.std`.
+    pub fn import<'a, 'tcx>(&mut self, bx: &Builder<'a, 'tcx>, name: &str) -> Word {
+        match name {
+            GLSL_STD_450 if !bx.kernel_mode => self.import_glsl(bx),
+            OPENCL_STD if bx.kernel_mode => self.import_opencl(bx),
+            _ => bx.emit_global().ext_inst_import(name),
+        }
+    }
+
     pub fn import_integer_functions_2_intel<'tcx>(&mut self, cx: &CodegenCx<'tcx>) {
         if !self.integer_functions_2_intel {
             assert!(!cx.kernel_mode);
@@ -12,7 +12,8 @@ use crate::abi::ConvSpirvType;
 use crate::builder_spirv::{BuilderCursor, SpirvValue, SpirvValueExt};
 use crate::codegen_cx::CodegenCx;
 use crate::spirv_type::SpirvType;
-use rspirv::spirv::Word;
+use rspirv::dr::Operand;
+use rspirv::spirv::{Decoration, FPFastMathMode, Word};
 use rustc_codegen_ssa::mir::operand::OperandValue;
 use rustc_codegen_ssa::mir::place::PlaceRef;
 use rustc_codegen_ssa::traits::{
@@ -54,6 +55,34 @@ impl<'a, 'tcx> Builder<'a, 'tcx> {
         }
     }
 
+    /// Applies the `#[spirv(no_contraction)]`, `#[spirv(fast_math)]` or
+    /// `#[spirv(relaxed_precision)]` of the current function, if any, to the
+    /// result of a floating-point operation.
+    fn decorate_fp_math(&self, result: Word) {
+        let current_fn = self.current_fn.def(self);
+        if self.no_contraction_fn_ids.borrow().contains(&current_fn) {
+            self.emit_global()
+                .decorate(result, Decoration::NoContraction, std::iter::empty());
+        }
+        if self.relaxed_precision_fn_ids.borrow().contains(&current_fn) {
+            self.emit_global()
+                .decorate(result, Decoration::RelaxedPrecision, std::iter::empty());
+        }
+        if self.fast_math_fn_ids.borrow().contains(&current_fn) {
+            self.emit_global().decorate(
+                result,
+                Decoration::FPFastMathMode,
+                std::iter::once(Operand::FPFastMathMode(
+                    FPFastMathMode::NOT_NAN
+                        | FPFastMathMode::NOT_INF
+                        | FPFastMathMode::NSZ
+                        | FPFastMathMode::ALLOW_RECIP
+                        | FPFastMathMode::FAST,
+                )),
+            );
+        }
+    }
+
     pub fn validate_atomic(&self, ty: Word, to_zombie: Word) {
         if !self.i8_i16_atomics_allowed {
             match self.lookup_type(ty) {
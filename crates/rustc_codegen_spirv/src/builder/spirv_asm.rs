@@ -15,6 +15,7 @@ use rustc_codegen_ssa::traits::{AsmBuilderMethods, InlineAsmOperandRef};
 use rustc_hir::LlvmInlineAsmInner;
 use rustc_middle::bug;
 use rustc_span::source_map::Span;
+use rustc_target::abi::{Align, Size};
 use rustc_target::asm::{InlineAsmRegClass, InlineAsmRegOrRegClass, SpirVInlineAsmRegClass};
 use std::collections::{HashMap, HashSet};
 
@@ -270,6 +271,11 @@ impl<'cx, 'tcx> Builder<'cx, 'tcx> {
                 count: inst.operands[1].unwrap_literal_int32(),
             }
             .def(self.span(), self),
+            Op::TypeMatrix => SpirvType::Matrix {
+                element: inst.operands[0].unwrap_id_ref(),
+                count: inst.operands[1].unwrap_literal_int32(),
+            }
+            .def(self.span(), self),
             Op::TypeArray => {
                 self.err("OpTypeArray in asm! is not supported yet");
                 return;
@@ -278,6 +284,13 @@ impl<'cx, 'tcx> Builder<'cx, 'tcx> {
                 element: inst.operands[0].unwrap_id_ref(),
             }
             .def(self.span(), self),
+            Op::TypePointer if inst.operands[0].unwrap_storage_class() == StorageClass::Image => {
+                // Pointers to image texels (from `OpImageTexelPointer`) never point into an
+                // `OpVariable`, so their storage class can't be inferred and must be explicit.
+                self.emit_global()
+                    .insert_types_global_values(dr::InsertPoint::End, inst);
+                return;
+            }
             Op::TypePointer => {
                 let storage_class = inst.operands[0].unwrap_storage_class();
                 if storage_class != StorageClass::Generic {
@@ -312,6 +325,13 @@ impl<'cx, 'tcx> Builder<'cx, 'tcx> {
                 image_type: inst.operands[0].unwrap_id_ref(),
             }
             .def(self.span(), self),
+            Op::TypeAccelerationStructureKHR => {
+                SpirvType::AccelerationStructureKhr.def(self.span(), self)
+            }
+            Op::ExtInstImport => {
+                let name = inst.operands[0].unwrap_literal_string();
+                self.ext_inst.borrow_mut().import(self, name)
+            }
             Op::Variable if inst.operands[0].unwrap_storage_class() != StorageClass::Function => {
                 // OpVariable with Function storage class should be emitted inside the function,
                 // however, all other OpVariables should appear in the global scope instead.
@@ -568,6 +588,7 @@ impl<'cx, 'tcx> Builder<'cx, 'tcx> {
                     (TyPat::Void, SpirvType::Void) => Ok(None),
                     (TyPat::Pointer(_, pat), SpirvType::Pointer { pointee: ty, .. })
                     | (TyPat::Vector(pat), SpirvType::Vector { element: ty, .. })
+                    | (TyPat::Matrix(pat), SpirvType::Matrix { element: ty, .. })
                     | (
                         TyPat::Vector4(pat),
                         SpirvType::Vector {
@@ -657,6 +678,41 @@ impl<'cx, 'tcx> Builder<'cx, 'tcx> {
             TyPat::SampledImage(&TyPat::T) => {
                 Some(SpirvType::SampledImage { image_type: var }.def(self.span(), self))
             }
+            // Sparse image instructions return the residency code alongside the texel.
+            TyPat::Struct(TyListPat::Cons {
+                first: TyPat::Any,
+                suffix:
+                    TyListPat::Cons {
+                        first: texel,
+                        suffix: TyListPat::Nil,
+                    },
+            }) => {
+                let texel = match texel {
+                    &TyPat::T => var,
+                    TyPat::Vector4(&TyPat::T) => SpirvType::Vector {
+                        element: var,
+                        count: 4,
+                    }
+                    .def(self.span(), self),
+                    _ => return None,
+                };
+                let residency_code = SpirvType::Integer(32, false).def(self.span(), self);
+                let texel_ty = self.lookup_type(texel);
+                let align = texel_ty.alignof(self).max(Align::from_bytes(4).unwrap());
+                let texel_offset = Size::from_bytes(4).align_to(texel_ty.alignof(self));
+                Some(
+                    SpirvType::Adt {
+                        def_id: None,
+                        align,
+                        size: Some((texel_offset + texel_ty.sizeof(self)?).align_to(align)),
+                        field_types: vec![residency_code, texel],
+                        field_offsets: vec![Size::ZERO, texel_offset],
+                        field_names: None,
+                        is_block: false,
+                    }
+                    .def(self.span(), self),
+                )
+            }
             _ => None,
         }
     }
@@ -148,6 +148,8 @@ impl SpirvValueExt for Word {
 pub enum SpirvConst {
     U32(Word, u32),
     U64(Word, u64),
+    /// f16 isn't a Rust type, so store bits
+    F16(Word, u16),
     /// f32 isn't hash, so store bits
     F32(Word, u32),
     /// f64 isn't hash, so store bits
@@ -217,12 +219,26 @@ impl BuilderSpirv {
             if version < (1, 3) {
                 builder.extension("SPV_KHR_variable_pointers");
             }
+            // 8-bit and 16-bit types in buffer blocks, removed by the linker if unused.
+            if version < (1, 5) {
+                builder.extension("SPV_KHR_8bit_storage");
+            }
+            builder.capability(Capability::StorageBuffer8BitAccess);
+            builder.capability(Capability::UniformAndStorageBuffer8BitAccess);
+            builder.capability(Capability::StoragePushConstant8);
+            if version < (1, 3) {
+                builder.extension("SPV_KHR_16bit_storage");
+            }
+            builder.capability(Capability::StorageBuffer16BitAccess);
+            builder.capability(Capability::UniformAndStorageBuffer16BitAccess);
+            builder.capability(Capability::StoragePushConstant16);
         }
         // The linker will always be ran on this module
         builder.capability(Capability::Linkage);
         builder.capability(Capability::Int8);
         builder.capability(Capability::Int16);
         builder.capability(Capability::Int64);
+        builder.capability(Capability::Float16);
         builder.capability(Capability::Float64);
         if kernel_mode {
             builder.capability(Capability::Addresses);
@@ -312,6 +328,8 @@ impl BuilderSpirv {
         let id = match val {
             SpirvConst::U32(ty, v) => builder.constant_u32(ty, v).with_type(ty),
             SpirvConst::U64(ty, v) => builder.constant_u64(ty, v).with_type(ty),
+            // A 16-bit literal takes up the low-order bits of a single word.
+            SpirvConst::F16(ty, v) => builder.constant_u32(ty, v as u32).with_type(ty),
             SpirvConst::F32(ty, v) => builder.constant_f32(ty, f32::from_bits(v)).with_type(ty),
             SpirvConst::F64(ty, v) => builder.constant_f64(ty, f64::from_bits(v)).with_type(ty),
             SpirvConst::Bool(ty, v) => {
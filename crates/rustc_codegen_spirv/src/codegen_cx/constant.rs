@@ -219,6 +219,11 @@ impl<'tcx> ConstMethods<'tcx> for CodegenCx<'tcx> {
                             }
                             self.constant_int(ty, data as u64)
                         }
+                        // `f16` is a `u16` newtype, so its constants are its bits.
+                        SpirvType::Float(16) => {
+                            assert_eq!(int_size.size().bits(), 16);
+                            self.builder.def_constant(SpirvConst::F16(ty, data as u16))
+                        }
                         SpirvType::Bool => match data {
                             0 => self.constant_bool(DUMMY_SP, false),
                             1 => self.constant_bool(DUMMY_SP, true),
@@ -391,6 +396,8 @@ impl<'tcx> CodegenCx<'tcx> {
                         Primitive::Int(integer, int_signedness)
                     }
                     SpirvType::Float(float_size) => match float_size {
+                        // See `scalar_to_backend`, `f16` constants are read as their bits.
+                        16 => Primitive::Int(Integer::I16, false),
                         32 => Primitive::F32,
                         64 => Primitive::F64,
                         other => {
@@ -455,6 +462,15 @@ impl<'tcx> CodegenCx<'tcx> {
                 "Cannot create const alloc of type opaque: {}",
                 name
             )),
+            SpirvType::Matrix { element, count } => {
+                let values = (0..count)
+                    .map(|_| {
+                        self.create_const_alloc2(alloc, offset, element)
+                            .def_cx(self)
+                    })
+                    .collect::<Vec<_>>();
+                self.constant_composite(ty, values)
+            }
             SpirvType::Array { element, count } => {
                 let count = self.builder.lookup_const_u64(count).unwrap() as usize;
                 let values = (0..count)
@@ -524,6 +540,10 @@ impl<'tcx> CodegenCx<'tcx> {
                 .tcx
                 .sess
                 .fatal("Cannot create a constant sampled image value"),
+            SpirvType::AccelerationStructureKhr => self
+                .tcx
+                .sess
+                .fatal("Cannot create a constant acceleration structure"),
         }
     }
 }
@@ -3,7 +3,7 @@ use crate::abi::ConvSpirvType;
 use crate::builder_spirv::{SpirvConst, SpirvValue, SpirvValueExt};
 use crate::decorations::UnrollLoopsDecoration;
 use crate::spirv_type::SpirvType;
-use crate::symbols::{parse_attrs, SpirvAttribute};
+use crate::symbols::{parse_attrs, SpecConstantValue, SpirvAttribute};
 use rspirv::spirv::{FunctionControl, LinkageType, StorageClass, Word};
 use rustc_attr::InlineAttr;
 use rustc_codegen_ssa::traits::{PreDefineMethods, StaticMethods};
@@ -15,7 +15,8 @@ use rustc_middle::ty::{self, Instance, ParamEnv, TypeFoldable};
 use rustc_span::def_id::DefId;
 use rustc_span::{Span, DUMMY_SP};
 use rustc_target::abi::call::FnAbi;
-use rustc_target::abi::{Align, LayoutOf};
+use rustc_target::abi::{Align, LayoutOf, Size};
+use std::collections::HashSet;
 
 fn attrs_to_spirv(attrs: &CodegenFnAttrs) -> FunctionControl {
     let mut control = FunctionControl::NONE;
@@ -111,6 +112,10 @@ impl<'tcx> CodegenCx<'tcx> {
 
         let declared = fn_id.with_type(function_type);
 
+        // The same function can be the entry point of several execution models, each
+        // getting its own stub (and interface variables), but an entry point is
+        // identified by its execution model and name, so those can't repeat.
+        let mut entry_points = HashSet::new();
         for attr in parse_attrs(self, self.tcx.get_attrs(instance.def_id())) {
             match attr {
                 SpirvAttribute::Entry(entry) => {
@@ -119,16 +124,71 @@ impl<'tcx> CodegenCx<'tcx> {
                         .as_ref()
                         .map(ToString::to_string)
                         .unwrap_or_else(|| instance.to_string());
+                    if !entry_points.insert((entry.execution_model, entry_name.clone())) {
+                        self.tcx.sess.span_err(
+                            self.tcx.def_span(instance.def_id()),
+                            &format!(
+                                "`{}` is declared as a `{:?}` entry point more than once, \
+                                 use `entry_point_name` to tell them apart",
+                                entry_name, entry.execution_model
+                            ),
+                        );
+                        continue;
+                    }
                     self.entry_stub(&instance, &fn_abi, declared, entry_name, entry)
                 }
-                SpirvAttribute::UnrollLoops => {
+                SpirvAttribute::UnrollLoops(unroll_loops) => {
+                    if let UnrollLoopsDecoration::PartialCount(_) = unroll_loops {
+                        if self.emit_global().version().unwrap() < (1, 4) {
+                            self.tcx.sess.span_err(
+                                self.tcx.def_span(instance.def_id()),
+                                "`#[spirv(unroll_loops(count = N))]` requires SPIR-V 1.4 or later",
+                            );
+                        }
+                    }
                     self.unroll_loops_decorations
                         .borrow_mut()
-                        .insert(fn_id, UnrollLoopsDecoration {});
+                        .insert(fn_id, unroll_loops);
+                }
+                SpirvAttribute::LoopIterations(loop_iterations) => {
+                    if self.emit_global().version().unwrap() < (1, 4) {
+                        self.tcx.sess.span_err(
+                            self.tcx.def_span(instance.def_id()),
+                            "`#[spirv(loop(...))]` requires SPIR-V 1.4 or later",
+                        );
+                    }
+                    self.loop_iterations_decorations
+                        .borrow_mut()
+                        .insert(fn_id, loop_iterations);
+                }
+                SpirvAttribute::NoContraction => {
+                    self.no_contraction_fn_ids.borrow_mut().insert(fn_id);
+                }
+                SpirvAttribute::FastMath => {
+                    if !self.kernel_mode {
+                        self.tcx.sess.span_err(
+                            self.tcx.def_span(instance.def_id()),
+                            "`#[spirv(fast_math)]` requires the `kernel` target feature, \
+                             as `FPFastMathMode` is only available to OpenCL kernels",
+                        );
+                    }
+                    self.fast_math_fn_ids.borrow_mut().insert(fn_id);
+                }
+                SpirvAttribute::RelaxedPrecision => {
+                    self.relaxed_precision_fn_ids.borrow_mut().insert(fn_id);
                 }
                 SpirvAttribute::IndexDescriptorArray => {
                     self.index_descriptor_array_id.borrow_mut().insert(fn_id);
                 }
+                SpirvAttribute::IndexDescriptorArrayNonUniform => {
+                    self.index_descriptor_array_id.borrow_mut().insert(fn_id);
+                    self.nonuniform_index_descriptor_array_id
+                        .borrow_mut()
+                        .insert(fn_id);
+                }
+                SpirvAttribute::Undef => {
+                    self.undef_fn_ids.borrow_mut().insert(fn_id);
+                }
                 _ => {}
             }
         }
@@ -179,7 +239,13 @@ impl<'tcx> CodegenCx<'tcx> {
         let ty = instance.ty(self.tcx, ParamEnv::reveal_all());
         let sym = self.tcx.symbol_name(instance).name;
         let span = self.tcx.def_span(def_id);
-        let g = self.declare_global(span, self.layout_of(ty).spirv_type(span, self));
+        let spvty = self.layout_of(ty).spirv_type(span, self);
+        // Every module declares the spec constant of a spec constant static itself.
+        if let Some(g) = self.declare_spec_constant_static(def_id, span, spvty) {
+            self.instances.borrow_mut().insert(instance, g);
+            return g;
+        }
+        let g = self.declare_global(span, spvty);
         self.instances.borrow_mut().insert(instance, g);
         self.set_linkage(g.def_cx(self), sym.to_string(), LinkageType::Import);
         g
@@ -196,6 +262,99 @@ impl<'tcx> CodegenCx<'tcx> {
         self.zombie_with_span(result.def_cx(self), span, "Globals are not supported yet");
         result
     }
+
+    /// Whether the static is `#[spirv(workgroup)]`, i.e. shared by the invocations of a
+    /// workgroup instead of being a (not yet supported) global of the current invocation.
+    fn is_workgroup_static(&self, def_id: DefId) -> bool {
+        parse_attrs(self, self.tcx.get_attrs(def_id))
+            .any(|attr| matches!(attr, SpirvAttribute::StorageClass(StorageClass::Workgroup)))
+    }
+
+    /// The `#[spirv(spec_constant(id = N, default = V))]` of the static, if any.
+    fn static_spec_constant(&self, def_id: DefId) -> Option<(u32, Option<SpecConstantValue>)> {
+        parse_attrs(self, self.tcx.get_attrs(def_id)).find_map(|attr| match attr {
+            SpirvAttribute::SpecConstant { id, default } => Some((id, default)),
+            _ => None,
+        })
+    }
+
+    /// A spec constant static isn't a variable, but a constant pointer to its `OpSpecConstant`
+    /// (with the initializer as the default), so that reading it yields the spec constant,
+    /// and arithmetic on it can be an `OpSpecConstantOp`. Returns `None` for other statics,
+    /// or if an error was reported.
+    fn declare_spec_constant_static(
+        &self,
+        def_id: DefId,
+        span: Span,
+        ty: Word,
+    ) -> Option<SpirvValue> {
+        let (id, attr_default) = self.static_spec_constant(def_id)?;
+        if self.tcx.is_mutable_static(def_id) {
+            self.tcx
+                .sess
+                .span_err(span, "spec constant statics can't be `static mut`");
+            return None;
+        }
+        // Error has already been reported
+        let alloc = self.tcx.eval_static_initializer(def_id).ok()?;
+        let initializer = self.create_const_alloc(alloc, ty);
+        let default = match (self.lookup_type(ty), self.builder.lookup_const(initializer)) {
+            (SpirvType::Integer(32, _), Some(SpirvConst::U32(_, x))) => SpecConstantValue::Int(x),
+            (SpirvType::Float(32), Some(SpirvConst::F32(_, x))) => {
+                SpecConstantValue::Float(f32::from_bits(x))
+            }
+            _ => {
+                self.tcx
+                    .sess
+                    .struct_span_err(
+                        span,
+                        "spec constant statics must be 32-bit integers or `f32`",
+                    )
+                    .help("`bool` spec constants can only be entry point parameters")
+                    .emit();
+                return None;
+            }
+        };
+        if attr_default.map_or(false, |attr_default| attr_default != default) {
+            self.tcx
+                .sess
+                .struct_span_err(
+                    span,
+                    "spec constant `default` doesn't match the initializer",
+                )
+                .help("remove the `default`, a spec constant static defaults to its initializer")
+                .emit();
+        }
+        let value = self.spec_constant(span, ty, id, default).with_type(ty);
+        Some(self.make_constant_pointer(span, value))
+    }
+
+    /// The initializer of a `Workgroup` static is ignored, so only allow ones that don't
+    /// look like they mean anything: all zeros, or `MaybeUninit::uninit()`.
+    fn check_workgroup_initializer(&self, def_id: DefId) {
+        let alloc = match self.tcx.eval_static_initializer(def_id) {
+            Ok(alloc) => alloc,
+            // Error has already been reported
+            Err(_) => return,
+        };
+        let bytes = alloc.inspect_with_uninit_and_ptr_outside_interpreter(0..alloc.len());
+        let is_zero_or_uninit = alloc.relocations().is_empty()
+            && bytes
+                .iter()
+                .enumerate()
+                .all(|(i, &byte)| byte == 0 || !alloc.init_mask().get(Size::from_bytes(i)));
+        if !is_zero_or_uninit {
+            self.tcx
+                .sess
+                .struct_span_err(
+                    self.tcx.def_span(def_id),
+                    "`#[spirv(workgroup)]` statics can't be initialized",
+                )
+                .note("`Workgroup` variables start out undefined, so this initializer is ignored")
+                .help("initialize it with zeros or `MaybeUninit::uninit()` instead")
+                .emit();
+        }
+    }
 }
 
 impl<'tcx> PreDefineMethods<'tcx> for CodegenCx<'tcx> {
@@ -219,7 +378,20 @@ impl<'tcx> PreDefineMethods<'tcx> for CodegenCx<'tcx> {
             )),
         };
 
-        let g = self.declare_global(span, spvty);
+        if let Some(g) = self.declare_spec_constant_static(def_id, span, spvty) {
+            // Not a variable, so there's nothing to link either.
+            self.instances.borrow_mut().insert(instance, g);
+            return;
+        }
+
+        let g = if self.is_workgroup_static(def_id) {
+            let ptr_ty = SpirvType::Pointer { pointee: spvty }.def(span, self);
+            self.emit_global()
+                .variable(ptr_ty, None, StorageClass::Workgroup, None)
+                .with_type(ptr_ty)
+        } else {
+            self.declare_global(span, spvty)
+        };
 
         self.instances.borrow_mut().insert(instance, g);
         if let Some(linkage) = linkage {
@@ -254,6 +426,18 @@ impl<'tcx> StaticMethods for CodegenCx<'tcx> {
     }
 
     fn codegen_static(&self, def_id: DefId, _is_mutable: bool) {
+        // `Workgroup` variables can't have an initializer, so the invocations of a workgroup
+        // have to write to them (and synchronize with a barrier) before reading them.
+        if self.is_workgroup_static(def_id) {
+            self.check_workgroup_initializer(def_id);
+            return;
+        }
+        // The initializer of a spec constant static is the default of its `OpSpecConstant`,
+        // which `predefine_static` already declared.
+        if self.static_spec_constant(def_id).is_some() {
+            return;
+        }
+
         let g = self.get_static(def_id);
         let span = self.tcx.def_span(def_id);
 
@@ -1,20 +1,31 @@
 use super::CodegenCx;
+use crate::abi::ConvSpirvType;
 use crate::builder_spirv::SpirvValue;
 use crate::spirv_type::SpirvType;
-use crate::symbols::{parse_attrs, Entry, SpirvAttribute};
-use rspirv::dr::Operand;
-use rspirv::spirv::{Decoration, ExecutionModel, FunctionControl, StorageClass, Word};
+use crate::symbols::{
+    parse_attrs, BlockLayout, Entry, ExecutionModeExtra, SpecConstantValue, SpirvAttribute,
+};
+use rspirv::binary::Assemble;
+use rspirv::dr::{Instruction, Operand};
+use rspirv::spirv::{
+    BuiltIn, Capability, Decoration, Dim, ExecutionMode, ExecutionModel, FunctionControl, Op,
+    StorageClass, Word,
+};
+use rustc_data_structures::captures::Captures;
 use rustc_hir as hir;
 use rustc_middle::{
     mir::terminator::Mutability,
     ty::{layout::HasParamEnv, AdtDef, Instance, Ty, TyKind},
 };
+use rustc_span::def_id::{DefId, LOCAL_CRATE};
 use rustc_span::Span;
 use rustc_target::abi::{
     call::{ArgAbi, ArgAttribute, ArgAttributes, FnAbi, PassMode},
-    Size,
+    Abi, Align, LayoutOf, Size,
 };
-use std::collections::HashMap;
+use std::collections::{hash_map, HashMap};
+use std::hash::Hash;
+use std::ops::Range;
 
 impl<'tcx> CodegenCx<'tcx> {
     // Entry points declare their "interface" (all uniforms, inputs, outputs, etc.) as parameters.
@@ -27,7 +38,7 @@ impl<'tcx> CodegenCx<'tcx> {
         fn_abi: &FnAbi<'tcx, Ty<'tcx>>,
         entry_func: SpirvValue,
         name: String,
-        entry: Entry,
+        mut entry: Entry,
     ) {
         let local_id = match instance.def_id().as_local() {
             Some(id) => id,
@@ -40,8 +51,9 @@ impl<'tcx> CodegenCx<'tcx> {
         };
         let fn_hir_id = self.tcx.hir().local_def_id_to_hir_id(local_id);
         let body = self.tcx.hir().body(self.tcx.hir().body_owned_by(fn_hir_id));
+        let fn_decl = self.tcx.hir().fn_decl_by_hir_id(fn_hir_id);
         const EMPTY: ArgAttribute = ArgAttribute::empty();
-        for (abi, arg) in fn_abi.args.iter().zip(body.params) {
+        for (i, (abi, arg)) in fn_abi.args.iter().zip(body.params).enumerate() {
             if let PassMode::Direct(_) = abi.mode {
             } else if let PassMode::Pair(
                 // plain DST/RTA/VLA
@@ -62,23 +74,38 @@ impl<'tcx> CodegenCx<'tcx> {
             ) = abi.mode
             {
             } else {
-                self.tcx.sess.span_err(
-                    arg.span,
-                    &format!("PassMode {:?} invalid for entry point parameter", abi.mode),
-                )
+                let ty_span = fn_decl
+                    .and_then(|decl| decl.inputs.get(i))
+                    .map_or(arg.span, |ty| ty.span);
+                let msg = match abi.mode {
+                    PassMode::Indirect { .. } => format!(
+                        "entry point parameters must be passed by reference to a storage-class \
+                         wrapper (e.g. `Uniform<T>`); found a by-value aggregate of size {}",
+                        abi.layout.size.bytes()
+                    ),
+                    _ => format!(
+                        "entry point parameters must be passed by reference to a storage-class \
+                         wrapper (e.g. `Uniform<T>`); found `{}`",
+                        abi.layout.ty
+                    ),
+                };
+                self.tcx
+                    .sess
+                    .struct_span_err(ty_span, &msg)
+                    .span_label(ty_span, format!("`{}` is passed by value", abi.layout.ty))
+                    .emit();
             }
         }
-        if let PassMode::Ignore = fn_abi.ret.mode {
-        } else {
-            self.tcx.sess.span_err(
-                self.tcx.hir().span(fn_hir_id),
-                &format!(
-                    "PassMode {:?} invalid for entry point return type",
-                    fn_abi.ret.mode
-                ),
-            )
-        }
         let execution_model = entry.execution_model;
+        self.check_entry_return(fn_hir_id, fn_decl, execution_model, &fn_abi.ret);
+        if is_ray_tracing(execution_model) {
+            self.declare_ray_tracing();
+        }
+        self.add_frag_depth_execution_modes(body.params, &mut entry);
+        self.check_per_vertex_interface(body.params, &fn_abi.args, &entry);
+        if execution_model == ExecutionModel::GLCompute {
+            self.check_local_size(self.tcx.def_span(instance.def_id()), &entry);
+        }
         let fn_id = if execution_model == ExecutionModel::Kernel {
             self.kernel_entry_stub(entry_func, name, execution_model)
         } else {
@@ -91,13 +118,427 @@ impl<'tcx> CodegenCx<'tcx> {
                 execution_model,
             )
         };
+        let has_spec_local_size = entry.local_size_spec_ids.iter().any(Option::is_some);
+        for (execution_mode, execution_mode_extra) in &entry.execution_modes {
+            if *execution_mode == ExecutionMode::LocalSize && has_spec_local_size {
+                let span = self.tcx.def_span(instance.def_id());
+                if self.emit_spec_local_size(
+                    span,
+                    fn_id,
+                    execution_mode_extra.as_ref(),
+                    entry.local_size_spec_ids,
+                ) {
+                    continue;
+                }
+            }
+            self.emit_global()
+                .execution_mode(fn_id, *execution_mode, execution_mode_extra);
+        }
+    }
+
+    /// Declares a local work group size with some of its dimensions being specialization
+    /// constants, using `LocalSizeId` where available (SPIR-V 1.2+), and a `WorkgroupSize`
+    /// builtin otherwise. Returns `false` if the literal `LocalSize` (with the spec constant
+    /// defaults) still needs to be emitted alongside.
+    fn emit_spec_local_size(
+        &self,
+        span: Span,
+        fn_id: Word,
+        local_size: &[u32],
+        spec_ids: [Option<u32>; 3],
+    ) -> bool {
+        let u32_ty = SpirvType::Integer(32, false).def(span, self);
+        let dims = local_size
+            .iter()
+            .zip(&spec_ids)
+            .map(|(&size, &spec_id)| match spec_id {
+                Some(spec_id) => {
+                    self.spec_constant(span, u32_ty, spec_id, SpecConstantValue::Int(size))
+                }
+                None => self.constant_u32(span, size).def_cx(self),
+            })
+            .collect::<Vec<_>>();
+        let uvec3 = SpirvType::Vector {
+            element: u32_ty,
+            count: 3,
+        }
+        .def(span, self);
         let mut emit = self.emit_global();
-        entry
+        if emit.version().unwrap() >= (1, 2) {
+            let operands = vec![
+                Operand::IdRef(fn_id),
+                Operand::ExecutionMode(ExecutionMode::LocalSizeId),
+            ]
+            .into_iter()
+            .chain(dims.into_iter().map(Operand::IdRef))
+            .collect();
+            emit.module_mut().execution_modes.push(Instruction::new(
+                Op::ExecutionModeId,
+                None,
+                None,
+                operands,
+            ));
+            return true;
+        }
+        // The `WorkgroupSize` builtin applies to the whole module, so there can only be one.
+        let has_workgroup_size = emit.module_ref().annotations.iter().any(|inst| {
+            inst.class.opcode == Op::Decorate
+                && inst.operands[1] == Operand::Decoration(Decoration::BuiltIn)
+                && inst.operands[2] == Operand::BuiltIn(BuiltIn::WorkgroupSize)
+        });
+        if has_workgroup_size {
+            self.tcx.sess.span_err(
+                span,
+                "only one compute entry point per module can use `spec_constant` in \
+                 `threads` before SPIR-V 1.2",
+            );
+            return false;
+        }
+        let workgroup_size = emit.id();
+        emit.module_mut().types_global_values.push(Instruction::new(
+            Op::SpecConstantComposite,
+            Some(uvec3),
+            Some(workgroup_size),
+            dims.into_iter().map(Operand::IdRef).collect(),
+        ));
+        emit.decorate(
+            workgroup_size,
+            Decoration::BuiltIn,
+            std::iter::once(Operand::BuiltIn(BuiltIn::WorkgroupSize)),
+        );
+        false
+    }
+
+    /// Ray tracing shaders need the `RayTracingKHR` capability, even if they don't use any of
+    /// the ray tracing instructions themselves.
+    fn declare_ray_tracing(&self) {
+        if !self.builder.has_capability(Capability::RayTracingKHR) {
+            let mut emit = self.emit_global();
+            emit.extension("SPV_KHR_ray_tracing");
+            emit.capability(Capability::RayTracingKHR);
+        }
+    }
+
+    /// Writing the `FragDepth` builtin requires the `DepthReplacing` execution mode, which
+    /// `#[spirv(frag_depth_{greater,less,unchanged})]` also add on top of their own.
+    fn add_frag_depth_execution_modes(&self, hir_params: &[hir::Param<'tcx>], entry: &mut Entry) {
+        for hir_param in hir_params {
+            for attr in parse_attrs(self, self.tcx.hir().attrs(hir_param.hir_id)) {
+                let conservative_depth = match attr {
+                    SpirvAttribute::Builtin(BuiltIn::FragDepth) => None,
+                    SpirvAttribute::ConservativeDepth(execution_mode) => Some(execution_mode),
+                    _ => continue,
+                };
+                if entry.execution_model != ExecutionModel::Fragment {
+                    self.tcx.sess.span_err(
+                        hir_param.span,
+                        "`FragDepth` can only be written by fragment entry points",
+                    );
+                    continue;
+                }
+                for &execution_mode in std::iter::once(&ExecutionMode::DepthReplacing)
+                    .chain(conservative_depth.as_ref())
+                {
+                    if !entry
+                        .execution_modes
+                        .iter()
+                        .any(|&(existing, _)| existing == execution_mode)
+                    {
+                        entry
+                            .execution_modes
+                            .push((execution_mode, ExecutionModeExtra::new([])));
+                    }
+                }
+            }
+        }
+    }
+
+    /// Geometry and tessellation shaders see their (non-builtin, non-patch) inputs and outputs
+    /// once per vertex, so those have to be arrays: of one element per vertex of the input
+    /// primitive for geometry inputs, and of `output_vertices` elements for tessellation
+    /// control outputs.
+    fn check_per_vertex_interface(
+        &self,
+        hir_params: &[hir::Param<'tcx>],
+        arg_abis: &[ArgAbi<'tcx, Ty<'tcx>>],
+        entry: &Entry,
+    ) {
+        let output_vertices = entry
             .execution_modes
             .iter()
-            .for_each(|(execution_mode, execution_mode_extra)| {
-                emit.execution_mode(fn_id, *execution_mode, execution_mode_extra);
-            });
+            .find(|(execution_mode, _)| *execution_mode == ExecutionMode::OutputVertices)
+            .and_then(|(_, extra)| extra.as_ref().first().map(|&x| x as u64));
+        // `None` for non-arrayed, `Some(None)` for arrays of any length.
+        let (input_len, output_len) = match entry.execution_model {
+            ExecutionModel::Geometry => {
+                let vertex_count = entry
+                    .execution_modes
+                    .iter()
+                    .find_map(|&(execution_mode, _)| match execution_mode {
+                        ExecutionMode::InputPoints => Some(1),
+                        ExecutionMode::InputLines => Some(2),
+                        ExecutionMode::InputLinesAdjacency => Some(4),
+                        ExecutionMode::Triangles => Some(3),
+                        ExecutionMode::InputTrianglesAdjacency => Some(6),
+                        _ => None,
+                    });
+                match vertex_count {
+                    Some(vertex_count) => (Some(Some(vertex_count)), None),
+                    // Already reported while parsing the entry point attribute.
+                    None => return,
+                }
+            }
+            ExecutionModel::TessellationControl => (Some(None), Some(output_vertices)),
+            ExecutionModel::TessellationEvaluation => (Some(None), None),
+            _ => return,
+        };
+        for (hir_param, arg_abi) in hir_params.iter().zip(arg_abis) {
+            let expected_len = match self.get_storage_class(arg_abi) {
+                Some((StorageClass::Input, _)) => input_len,
+                Some((StorageClass::Output, _)) => output_len,
+                _ => None,
+            };
+            let expected_len = match expected_len {
+                Some(expected_len) => expected_len,
+                None => continue,
+            };
+            // Builtins like `Position` and `ClipDistance` are arrayed like any other
+            // per-vertex interface, so they need the same number of elements.
+            let is_per_vertex =
+                parse_attrs(self, self.tcx.hir().attrs(hir_param.hir_id)).all(|attr| match attr {
+                    SpirvAttribute::Builtin(builtin) => {
+                        builtin_requirements(builtin).map_or(false, |r| r.per_vertex)
+                    }
+                    SpirvAttribute::Patch => false,
+                    _ => true,
+                });
+            if !is_per_vertex {
+                continue;
+            }
+            let value_ty = match arg_abi.layout.ty.kind() {
+                TyKind::Ref(_, ty, _) => Some(*ty),
+                TyKind::Adt(_, substs) => substs.types().next(),
+                _ => None,
+            };
+            let len = match value_ty.map(|ty| ty.kind()) {
+                Some(TyKind::Array(_, len)) => Some(len.try_eval_usize(self.tcx, self.param_env())),
+                _ => None,
+            };
+            match (len, expected_len) {
+                (Some(_), None) => {}
+                (Some(len), Some(_)) if len == expected_len => {}
+                _ => {
+                    let count = match expected_len {
+                        Some(expected_len) => format!(" of {} elements", expected_len),
+                        None => String::new(),
+                    };
+                    self.tcx.sess.span_err(
+                        hir_param.span,
+                        &format!(
+                            "`{:?}` per-vertex inputs and outputs must be arrays{}, \
+                             or else `#[spirv(patch)]`",
+                            entry.execution_model, count
+                        ),
+                    );
+                }
+            }
+        }
+    }
+
+    /// Spec constant parameters are passed by value, as an `OpSpecConstant*` the host can
+    /// override through its `SpecId`.
+    fn declare_spec_constant(
+        &self,
+        ty: Word,
+        hir_param: &hir::Param<'tcx>,
+        id: u32,
+        default: Option<SpecConstantValue>,
+        declared_interface: &mut DeclaredInterface,
+    ) -> Word {
+        let default = match (self.lookup_type(ty), default) {
+            (_, Some(default)) => default,
+            (SpirvType::Integer(32, _), None) => SpecConstantValue::Int(0),
+            (SpirvType::Float(32), None) => SpecConstantValue::Float(0.0),
+            (SpirvType::Bool, None) => SpecConstantValue::Bool(false),
+            (other, None) => self.tcx.sess.span_fatal(
+                hir_param.span,
+                &format!(
+                    "spec constant of type `{}` is not supported, \
+                     only 32-bit integers, `f32` and `bool` are",
+                    other.debug(ty, self)
+                ),
+            ),
+        };
+        self.check_unique_interface_slot(
+            &mut declared_interface.spec_ids,
+            id,
+            hir_param.span,
+            &format!("spec constant id {}", id),
+        );
+        self.spec_constant(hir_param.span, ty, id, default)
+    }
+
+    /// The `OpSpecConstant*` with the `SpecId` `id`, declared once per module and shared by
+    /// every entry point (or static) that uses it with the same type and default.
+    pub fn spec_constant(&self, span: Span, ty: Word, id: u32, default: SpecConstantValue) -> Word {
+        let existing = self.spec_constants.borrow().get(&id).copied();
+        if let Some(existing) = existing {
+            if existing.ty != ty || existing.default != default {
+                self.tcx
+                    .sess
+                    .struct_span_err(
+                        span,
+                        &format!(
+                            "spec constant id {} is already declared with a different type \
+                             or default",
+                            id
+                        ),
+                    )
+                    .span_note(existing.span, "previously declared here")
+                    .emit();
+            }
+            return existing.result;
+        }
+        let (op, operands) = match (self.lookup_type(ty), default) {
+            (SpirvType::Integer(32, _), SpecConstantValue::Int(x)) => {
+                (Op::SpecConstant, vec![Operand::LiteralInt32(x)])
+            }
+            (SpirvType::Float(32), SpecConstantValue::Float(x)) => {
+                (Op::SpecConstant, vec![Operand::LiteralFloat32(x)])
+            }
+            (SpirvType::Bool, SpecConstantValue::Bool(true)) => (Op::SpecConstantTrue, vec![]),
+            (SpirvType::Bool, SpecConstantValue::Bool(false)) => (Op::SpecConstantFalse, vec![]),
+            (other, _) => self.tcx.sess.span_fatal(
+                span,
+                &format!(
+                    "spec constant of type `{}` can't have the default value `{:?}`, \
+                     only 32-bit integers, `f32` and `bool` are supported",
+                    other.debug(ty, self),
+                    default
+                ),
+            ),
+        };
+        let mut emit = self.emit_global();
+        let result = emit.id();
+        emit.module_mut().types_global_values.push(Instruction::new(
+            op,
+            Some(ty),
+            Some(result),
+            operands,
+        ));
+        emit.decorate(
+            result,
+            Decoration::SpecId,
+            std::iter::once(Operand::LiteralInt32(id)),
+        );
+        self.spec_constant_ids.borrow_mut().insert(result);
+        self.spec_constants.borrow_mut().insert(
+            id,
+            SpecConstant {
+                ty,
+                default,
+                result,
+                span,
+            },
+        );
+        result
+    }
+
+    /// Compute entry points must declare their local work group size, which also has to fit
+    /// within `max_compute_workgroup_invocations`.
+    fn check_local_size(&self, span: Span, entry: &Entry) {
+        let local_size = entry
+            .execution_modes
+            .iter()
+            .find(|(execution_mode, _)| *execution_mode == ExecutionMode::LocalSize)
+            .map(|(_, local_size)| local_size.as_ref());
+        let local_size = match local_size {
+            Some(local_size) => local_size,
+            None => {
+                self.tcx
+                    .sess
+                    .struct_span_err(span, "compute entry point requires a local work group size")
+                    .help("specify it with e.g. `#[spirv(compute(threads(64)))]`")
+                    .emit();
+                return;
+            }
+        };
+        if local_size.contains(&0) {
+            self.tcx.sess.span_err(
+                span,
+                &format!(
+                    "compute local work group size {:?} must be nonzero in every dimension",
+                    local_size
+                ),
+            );
+            return;
+        }
+        let invocations = local_size.iter().map(|&x| x as u64).product::<u64>();
+        if invocations > self.max_compute_workgroup_invocations as u64 {
+            self.tcx
+                .sess
+                .struct_span_err(
+                    span,
+                    &format!(
+                        "compute local work group size {:?} has {} invocations, \
+                         more than the maximum of {}",
+                        local_size, invocations, self.max_compute_workgroup_invocations
+                    ),
+                )
+                .note("`maxComputeWorkGroupInvocations` may be this low on some devices")
+                .emit();
+        }
+    }
+
+    /// Entry points either return `()`, or a single scalar or vector which is written to an
+    /// implicit `Output` at location 0, in the stages that write their outputs once per
+    /// invocation.
+    fn check_entry_return(
+        &self,
+        fn_hir_id: hir::HirId,
+        fn_decl: Option<&hir::FnDecl<'_>>,
+        execution_model: ExecutionModel,
+        ret: &ArgAbi<'tcx, Ty<'tcx>>,
+    ) {
+        if let PassMode::Ignore = ret.mode {
+            return;
+        }
+        let ret_span =
+            fn_decl.map_or_else(|| self.tcx.hir().span(fn_hir_id), |decl| decl.output.span());
+        let is_single_output = matches!(ret.mode, PassMode::Direct(_))
+            && match ret.layout.abi {
+                Abi::Scalar(_) => ret.layout.ty.is_integral() || ret.layout.ty.is_floating_point(),
+                Abi::Vector { .. } => true,
+                _ => false,
+            };
+        if !is_single_output {
+            self.tcx
+                .sess
+                .struct_span_err(
+                    ret_span,
+                    "entry points can only return `()`, or a single scalar or vector",
+                )
+                .span_label(ret_span, format!("returns `{}`", ret.layout.ty))
+                .help("write multiple results through `Output` parameters instead")
+                .emit();
+        } else if !matches!(
+            execution_model,
+            ExecutionModel::Vertex
+                | ExecutionModel::TessellationEvaluation
+                | ExecutionModel::Fragment
+        ) {
+            self.tcx
+                .sess
+                .struct_span_err(
+                    ret_span,
+                    &format!("`{:?}` entry points must return `()`", execution_model),
+                )
+                .span_label(ret_span, format!("returns `{}`", ret.layout.ty))
+                .help(
+                    "only vertex, tessellation evaluation and fragment shaders can return a value",
+                )
+                .emit();
+        }
     }
 
     fn shader_entry_stub(
@@ -125,13 +566,51 @@ impl<'tcx> CodegenCx<'tcx> {
                 other.debug(entry_func.ty, self)
             )),
         };
+        // NOTE: the interface locations are assigned anew for every stub, so several entry
+        // points over the same function don't interfere. Variables that end up identical to
+        // those of another entry point are then shared, see `reuse_interface_variable`.
         let mut decoration_locations = HashMap::new();
+        let mut declared_interface = DeclaredInterface::default();
         // Create OpVariables before OpFunction so they're global instead of local vars.
         let new_spirv = self.emit_global().version().unwrap() > (1, 3);
         let arg_len = arg_abis.len();
         let mut arguments = Vec::with_capacity(arg_len);
         let mut interface = Vec::with_capacity(arg_len);
         let mut rta_lens = Vec::with_capacity(arg_len / 2);
+        // The return value, if any, takes the first `Output` location, before any of the
+        // parameters.
+        let return_output = if entry_func_return_type == void {
+            None
+        } else {
+            let ptr = SpirvType::Pointer {
+                pointee: entry_func_return_type,
+            }
+            .def(span, self);
+            let mark = self.module_mark();
+            let variable = self
+                .emit_global()
+                .variable(ptr, None, StorageClass::Output, None);
+            self.emit_global().decorate(
+                variable,
+                Decoration::Location,
+                std::iter::once(Operand::LiteralInt32(0)),
+            );
+            let components = 0..self
+                .location_component_count(entry_func_return_type)
+                .unwrap_or(4);
+            let location_count = self.location_count(entry_func_return_type);
+            self.check_unique_location(
+                &mut declared_interface,
+                StorageClass::Output,
+                0..location_count,
+                components,
+                span,
+            );
+            decoration_locations.insert(StorageClass::Output, location_count);
+            let variable = self.reuse_interface_variable(mark, variable, ptr, StorageClass::Output);
+            interface.push(variable);
+            Some(variable)
+        };
         let mut arg_types = entry_func_arg_types.iter();
         for (hir_param, arg_abi) in hir_params.iter().zip(arg_abis) {
             // explicit next because there are two args for scalar pairs, but only one param & abi
@@ -144,8 +623,30 @@ impl<'tcx> CodegenCx<'tcx> {
                     ),
                 )
             });
-            let (argument, storage_class) =
-                self.declare_parameter(arg_t, hir_param, arg_abi, &mut decoration_locations);
+            let spec_constant = parse_attrs(self, self.tcx.hir().attrs(hir_param.hir_id)).find_map(
+                |attr| match attr {
+                    SpirvAttribute::SpecConstant { id, default } => Some((id, default)),
+                    _ => None,
+                },
+            );
+            if let Some((id, default)) = spec_constant {
+                arguments.push(self.declare_spec_constant(
+                    arg_t,
+                    hir_param,
+                    id,
+                    default,
+                    &mut declared_interface,
+                ));
+                continue;
+            }
+            let (argument, storage_class) = self.declare_parameter(
+                execution_model,
+                arg_t,
+                hir_param,
+                arg_abi,
+                &mut decoration_locations,
+                &mut declared_interface,
+            );
             // SPIR-V <= v1.3 only includes Input and Output in the interface.
             if new_spirv
                 || storage_class == StorageClass::Input
@@ -155,12 +656,27 @@ impl<'tcx> CodegenCx<'tcx> {
             }
             arguments.push(argument);
             if let SpirvType::Pointer { pointee } = self.lookup_type(arg_t) {
-                if let SpirvType::Adt {
-                    size: None,
-                    field_types,
-                    ..
-                } = self.lookup_type(pointee)
-                {
+                // The runtime array member of a block, if the parameter is a fat pointer to one.
+                // Descriptor arrays of such blocks are fat pointers too, but their elements each
+                // have their own length, which indexing the array queries instead.
+                let rta_member = match self.lookup_type(pointee) {
+                    SpirvType::Adt {
+                        size: None,
+                        field_types,
+                        ..
+                    } => Some(Some(field_types.len() as u32 - 1)),
+                    SpirvType::Array { element, .. } | SpirvType::RuntimeArray { element }
+                        if is_descriptor_array(arg_abi.layout.ty)
+                            && matches!(
+                                self.lookup_type(element),
+                                SpirvType::Adt { size: None, .. }
+                            ) =>
+                    {
+                        Some(None)
+                    }
+                    _ => None,
+                };
+                if let Some(member_idx) = rta_member {
                     let len_t = *arg_types.next().unwrap_or_else(|| {
                         self.tcx.sess.span_fatal(
                             hir_param.span,
@@ -170,8 +686,13 @@ impl<'tcx> CodegenCx<'tcx> {
                             ),
                         )
                     });
-                    rta_lens.push((arguments.len() as u32, len_t, field_types.len() as u32 - 1));
-                    arguments.push(u32::MAX);
+                    match member_idx {
+                        Some(member_idx) => {
+                            rta_lens.push((arguments.len() as u32, len_t, member_idx));
+                            arguments.push(u32::MAX);
+                        }
+                        None => arguments.push(self.undef(len_t).def_cx(self)),
+                    }
                 }
             }
         }
@@ -185,13 +706,18 @@ impl<'tcx> CodegenCx<'tcx> {
                 .array_length(len_t, None, arguments[len_idx as usize - 1], member_idx)
                 .unwrap()
         });
-        emit.function_call(
-            entry_func_return_type,
-            None,
-            entry_func.def_cx(self),
-            arguments,
-        )
-        .unwrap();
+        let result = emit
+            .function_call(
+                entry_func_return_type,
+                None,
+                entry_func.def_cx(self),
+                arguments,
+            )
+            .unwrap();
+        if let Some(return_output) = return_output {
+            emit.store(return_output, result, None, std::iter::empty())
+                .unwrap();
+        }
         emit.ret().unwrap();
         emit.end_function().unwrap();
         emit.entry_point(execution_model, fn_id, name, interface);
@@ -200,10 +726,12 @@ impl<'tcx> CodegenCx<'tcx> {
 
     fn declare_parameter(
         &self,
+        execution_model: ExecutionModel,
         arg: Word,
         hir_param: &hir::Param<'tcx>,
         arg_abi: &ArgAbi<'tcx, Ty<'tcx>>,
         decoration_locations: &mut HashMap<StorageClass, u32>,
+        declared_interface: &mut DeclaredInterface,
     ) -> (Word, StorageClass) {
         let (storage_class, mut spirv_binding) =
             self.get_storage_class(arg_abi).unwrap_or_else(|| {
@@ -212,28 +740,249 @@ impl<'tcx> CodegenCx<'tcx> {
                     &format!("invalid entry param type `{}`", arg_abi.layout.ty),
                 );
             });
+        let mark = self.module_mark();
         // Note: this *declares* the variable too.
         let variable = self.emit_global().variable(arg, None, storage_class, None);
+        if storage_class == StorageClass::AtomicCounter
+            && !self.builder.has_capability(Capability::AtomicStorage)
+        {
+            self.emit_global().capability(Capability::AtomicStorage);
+        }
+        if is_descriptor_array(arg_abi.layout.ty)
+            && !self
+                .builder
+                .has_capability(Capability::RuntimeDescriptorArray)
+        {
+            if let SpirvType::Pointer { pointee } = self.lookup_type(arg) {
+                if let SpirvType::RuntimeArray { .. } = self.lookup_type(pointee) {
+                    self.emit_global()
+                        .capability(Capability::RuntimeDescriptorArray);
+                }
+            }
+        }
+        if storage_class != StorageClass::PushConstant
+            && self
+                .block_data_type_attrs(storage_class, arg_abi.layout.ty)
+                .any(|attr| matches!(attr, SpirvAttribute::PushConstantOffset(_)))
+        {
+            self.tcx.sess.span_err(
+                hir_param.span,
+                "`#[spirv(push_constant_offset)]` blocks can only be used as `PushConstant`s",
+            );
+        }
+        if let Some(layout) = self.block_layout(storage_class, arg_abi.layout.ty) {
+            if let SpirvType::Pointer { pointee } = self.lookup_type(arg) {
+                // Descriptor arrays aren't part of the block's memory layout.
+                let block = match self.lookup_type(pointee) {
+                    SpirvType::Array { element, .. } | SpirvType::RuntimeArray { element }
+                        if is_descriptor_array(arg_abi.layout.ty) =>
+                    {
+                        element
+                    }
+                    _ => pointee,
+                };
+                let base_offset = self
+                    .block_data_type_attrs(storage_class, arg_abi.layout.ty)
+                    .find_map(|attr| match attr {
+                        SpirvAttribute::PushConstantOffset(offset) => Some(offset),
+                        _ => None,
+                    })
+                    .unwrap_or(0);
+                self.decorate_block_layout(hir_param.span, layout, block, base_offset);
+                // The decorations follow `layout` either way, so the Rust layout only has to
+                // match when the host uploads values of the Rust type as-is.
+                if self.strict_block_layout {
+                    self.check_block_layout(hir_param.span, layout, block);
+                }
+            }
+        }
         if let hir::PatKind::Binding(_, _, ident, _) = &hir_param.pat.kind {
             self.emit_global().name(variable, ident.to_string());
         }
+        self.decorate_memory_access(variable, storage_class, hir_param, arg_abi);
+        let mut is_flat = false;
+        let mut component = None;
+        let mut input_attachment_index = None;
         for attr in parse_attrs(self, self.tcx.hir().attrs(hir_param.hir_id)) {
             match attr {
                 SpirvAttribute::Builtin(builtin) => {
+                    self.check_builtin(
+                        hir_param.span,
+                        execution_model,
+                        storage_class,
+                        builtin,
+                        arg,
+                    );
+                    self.check_unique_interface_slot(
+                        &mut declared_interface.builtins,
+                        (storage_class, builtin),
+                        hir_param.span,
+                        &format!("builtin `{:?}`", builtin),
+                    );
                     self.emit_global().decorate(
                         variable,
                         Decoration::BuiltIn,
                         std::iter::once(Operand::BuiltIn(builtin)),
                     );
+                    let capability = match (builtin, execution_model) {
+                        // Point sizes are only passed through these stages with their own
+                        // capabilities (and device features).
+                        (BuiltIn::PointSize, ExecutionModel::Geometry) => {
+                            Some(Capability::GeometryPointSize)
+                        }
+                        (BuiltIn::PointSize, ExecutionModel::TessellationControl)
+                        | (BuiltIn::PointSize, ExecutionModel::TessellationEvaluation) => {
+                            Some(Capability::TessellationPointSize)
+                        }
+                        (BuiltIn::ClipDistance, _) => Some(Capability::ClipDistance),
+                        (BuiltIn::CullDistance, _) => Some(Capability::CullDistance),
+                        // Reading either makes fragment shaders run once per sample.
+                        (BuiltIn::SampleId, _) | (BuiltIn::SamplePosition, _) => {
+                            Some(Capability::SampleRateShading)
+                        }
+                        _ => None,
+                    };
+                    if let Some(capability) = capability {
+                        if !self.builder.has_capability(capability) {
+                            self.emit_global().capability(capability);
+                        }
+                    }
+                    spirv_binding = SpirvBinding::Builtin;
+                }
+                SpirvAttribute::ConservativeDepth(_) => {
+                    self.check_builtin(
+                        hir_param.span,
+                        execution_model,
+                        storage_class,
+                        BuiltIn::FragDepth,
+                        arg,
+                    );
+                    self.check_unique_interface_slot(
+                        &mut declared_interface.builtins,
+                        (storage_class, BuiltIn::FragDepth),
+                        hir_param.span,
+                        "builtin `FragDepth`",
+                    );
+                    self.emit_global().decorate(
+                        variable,
+                        Decoration::BuiltIn,
+                        std::iter::once(Operand::BuiltIn(BuiltIn::FragDepth)),
+                    );
                     spirv_binding = SpirvBinding::Builtin;
                 }
                 SpirvAttribute::Flat => {
+                    self.decorate_interpolation(
+                        variable,
+                        storage_class,
+                        hir_param,
+                        Decoration::Flat,
+                    );
+                    is_flat = true;
+                }
+                SpirvAttribute::NoPerspective => {
+                    self.decorate_interpolation(
+                        variable,
+                        storage_class,
+                        hir_param,
+                        Decoration::NoPerspective,
+                    );
+                }
+                SpirvAttribute::Invariant => {
+                    if storage_class != StorageClass::Output {
+                        self.tcx.sess.span_err(
+                            hir_param.span,
+                            &format!(
+                                "`#[spirv(invariant)]` is only valid on `Output` parameters, \
+                                 not `{:?}`",
+                                storage_class
+                            ),
+                        );
+                    }
+                    self.emit_global().decorate(
+                        variable,
+                        Decoration::Invariant,
+                        std::iter::empty(),
+                    );
+                }
+                SpirvAttribute::Patch => {
+                    if !matches!(
+                        execution_model,
+                        ExecutionModel::TessellationControl
+                            | ExecutionModel::TessellationEvaluation
+                    ) {
+                        self.tcx.sess.span_err(
+                            hir_param.span,
+                            "`#[spirv(patch)]` is only valid in tessellation shaders",
+                        );
+                    }
                     self.emit_global()
-                        .decorate(variable, Decoration::Flat, std::iter::empty());
+                        .decorate(variable, Decoration::Patch, std::iter::empty());
+                }
+                SpirvAttribute::Centroid => {
+                    self.decorate_interpolation(
+                        variable,
+                        storage_class,
+                        hir_param,
+                        Decoration::Centroid,
+                    );
+                }
+                SpirvAttribute::Sample => {
+                    self.decorate_interpolation(
+                        variable,
+                        storage_class,
+                        hir_param,
+                        Decoration::Sample,
+                    );
+                    if !self.builder.has_capability(Capability::SampleRateShading) {
+                        self.emit_global().capability(Capability::SampleRateShading);
+                    }
+                }
+                SpirvAttribute::Component(first) => component = Some(first),
+                SpirvAttribute::InputAttachmentIndex(index) => input_attachment_index = Some(index),
+                SpirvAttribute::RelaxedPrecision => {
+                    self.emit_global().decorate(
+                        variable,
+                        Decoration::RelaxedPrecision,
+                        std::iter::empty(),
+                    );
                 }
                 _ => {}
             }
         }
+        self.decorate_input_attachment(
+            variable,
+            execution_model,
+            hir_param,
+            arg_abi,
+            arg,
+            input_attachment_index,
+        );
+        let components = self.location_components(hir_param.span, arg, spirv_binding, component);
+        let location_count = match self.lookup_type(arg) {
+            SpirvType::Pointer { pointee } => self.location_count(pointee),
+            _ => 1,
+        };
+        if let Some(component) = component {
+            self.emit_global().decorate(
+                variable,
+                Decoration::Component,
+                std::iter::once(Operand::LiteralInt32(component)),
+            );
+        }
+        if execution_model == ExecutionModel::Fragment
+            && storage_class == StorageClass::Input
+            && spirv_binding != SpirvBinding::Builtin
+            && !is_flat
+        {
+            // Only floating-point values can be interpolated, so Vulkan requires the others
+            // to be `Flat`, whether or not they were marked `#[spirv(flat)]`.
+            if let SpirvType::Pointer { pointee } = self.lookup_type(arg) {
+                if self.needs_flat_interpolation(pointee) {
+                    self.emit_global()
+                        .decorate(variable, Decoration::Flat, std::iter::empty());
+                }
+            }
+        }
         match spirv_binding {
             SpirvBinding::DescriptorSet { set, binding } => {
                 self.emit_global().decorate(
@@ -248,14 +997,17 @@ impl<'tcx> CodegenCx<'tcx> {
                 );
             }
             SpirvBinding::Location(location) => {
-                let last_location = decoration_locations.entry(storage_class).or_insert(0);
-                if location >= *last_location {
-                    *last_location = location + 1;
-                } else {
-                    self.tcx
-                        .sess
-                        .span_err(hir_param.span, "Locations must appear in ascending order");
-                }
+                // Explicit locations may be declared in any order, as long as they don't
+                // overlap, and inferred locations continue after the highest one so far.
+                self.check_unique_location(
+                    declared_interface,
+                    storage_class,
+                    location..location + location_count,
+                    components,
+                    hir_param.span,
+                );
+                let next_location = decoration_locations.entry(storage_class).or_insert(0);
+                *next_location = (*next_location).max(location + location_count);
                 self.emit_global().decorate(
                     variable,
                     Decoration::Location,
@@ -268,18 +1020,946 @@ impl<'tcx> CodegenCx<'tcx> {
                 // TODO: Is this right for UniformConstant? Do they share locations with
                 // input/outpus?
                 let location = decoration_locations.entry(storage_class).or_insert(0);
+                self.check_unique_location(
+                    declared_interface,
+                    storage_class,
+                    *location..*location + location_count,
+                    components,
+                    hir_param.span,
+                );
                 self.emit_global().decorate(
                     variable,
                     Decoration::Location,
                     std::iter::once(Operand::LiteralInt32(*location)),
                 );
-                *location += 1;
+                *location += location_count;
             }
             _ => {}
         }
+        let variable = match spirv_binding {
+            SpirvBinding::DescriptorSet { .. }
+            | SpirvBinding::Location(_)
+            | SpirvBinding::InferredLocation => {
+                self.reuse_interface_variable(mark, variable, arg, storage_class)
+            }
+            _ => variable,
+        };
         (variable, storage_class)
     }
 
+    /// Where each module section the instructions of a new interface variable can end up in
+    /// currently ends, so that `reuse_interface_variable` only has to look at those emitted
+    /// since, rather than at the whole module for every variable.
+    fn module_mark(&self) -> ModuleMark {
+        let mut emit = self.emit_global();
+        let module = emit.module_mut();
+        ModuleMark {
+            types_global_values: module.types_global_values.len(),
+            annotations: module.annotations.len(),
+            debugs: module.debugs.len(),
+        }
+    }
+
+    /// Replace `variable` with an identical one declared for another entry point, i.e. one
+    /// with the same storage class, type and decorations (which include its descriptor set
+    /// and binding, or location), if there is one. Some drivers reject modules with several
+    /// variables bound to the same descriptor.
+    ///
+    /// `mark` must have been taken right before `variable` was declared.
+    fn reuse_interface_variable(
+        &self,
+        mark: ModuleMark,
+        variable: Word,
+        ty: Word,
+        storage_class: StorageClass,
+    ) -> Word {
+        let mut emit = self.emit_global();
+        let module = emit.module_mut();
+        let targets_variable = |inst: &Instruction| {
+            inst.operands.first().and_then(|op| op.id_ref_any()) == Some(variable)
+        };
+        let mut decorations = module.annotations[mark.annotations..]
+            .iter()
+            .filter(|inst| inst.class.opcode == Op::Decorate && targets_variable(inst))
+            .map(|inst| {
+                let mut decoration = Vec::new();
+                for op in &inst.operands[1..] {
+                    op.assemble_into(&mut decoration);
+                }
+                decoration
+            })
+            .collect::<Vec<_>>();
+        decorations.sort();
+        let key = InterfaceVariableKey {
+            storage_class,
+            ty,
+            decorations,
+        };
+        match self.interface_variables.borrow_mut().entry(key) {
+            hash_map::Entry::Occupied(existing) => {
+                // Everything about `variable` was emitted after `mark`.
+                retain_since(
+                    &mut module.types_global_values,
+                    mark.types_global_values,
+                    |inst| inst.result_id != Some(variable),
+                );
+                retain_since(&mut module.annotations, mark.annotations, |inst| {
+                    !targets_variable(inst)
+                });
+                retain_since(&mut module.debugs, mark.debugs, |inst| {
+                    inst.class.opcode != Op::Name || !targets_variable(inst)
+                });
+                *existing.get()
+            }
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(variable);
+                variable
+            }
+        }
+    }
+
+    /// Input attachments (subpass data images) must be decorated with the index of
+    /// the attachment they read, which `#[spirv(input_attachment_index)]` gives.
+    fn decorate_input_attachment(
+        &self,
+        variable: Word,
+        execution_model: ExecutionModel,
+        hir_param: &hir::Param<'tcx>,
+        arg_abi: &ArgAbi<'tcx, Ty<'tcx>>,
+        arg: Word,
+        input_attachment_index: Option<u32>,
+    ) {
+        let is_input_attachment = match self.lookup_type(arg) {
+            SpirvType::Pointer { pointee } => {
+                // Descriptor arrays of input attachments share a single index.
+                let image = match self.lookup_type(pointee) {
+                    SpirvType::Array { element, .. } | SpirvType::RuntimeArray { element }
+                        if is_descriptor_array(arg_abi.layout.ty) =>
+                    {
+                        element
+                    }
+                    _ => pointee,
+                };
+                matches!(
+                    self.lookup_type(image),
+                    SpirvType::Image {
+                        dim: Dim::DimSubpassData,
+                        ..
+                    }
+                )
+            }
+            _ => false,
+        };
+        match (is_input_attachment, input_attachment_index) {
+            (true, Some(index)) => {
+                if execution_model != ExecutionModel::Fragment {
+                    self.tcx.sess.span_err(
+                        hir_param.span,
+                        "input attachments are only valid in fragment shaders",
+                    );
+                }
+                self.emit_global().decorate(
+                    variable,
+                    Decoration::InputAttachmentIndex,
+                    std::iter::once(Operand::LiteralInt32(index)),
+                );
+            }
+            (true, None) => {
+                self.tcx.sess.span_err(
+                    hir_param.span,
+                    "input attachments require `#[spirv(input_attachment_index = N)]`",
+                );
+            }
+            (false, Some(_)) => {
+                self.tcx.sess.span_err(
+                    hir_param.span,
+                    "`#[spirv(input_attachment_index)]` is only valid on input attachments",
+                );
+            }
+            (false, None) => {}
+        }
+    }
+
+    /// Interpolation decorations only make sense on values passed between shader stages.
+    fn decorate_interpolation(
+        &self,
+        variable: Word,
+        storage_class: StorageClass,
+        hir_param: &hir::Param<'tcx>,
+        decoration: Decoration,
+    ) {
+        if storage_class != StorageClass::Input && storage_class != StorageClass::Output {
+            self.tcx.sess.span_err(
+                hir_param.span,
+                &format!(
+                    "`{:?}` interpolation is only valid on `Input` and `Output` parameters, \
+                     not `{:?}`",
+                    decoration, storage_class
+                ),
+            );
+            return;
+        }
+        self.emit_global()
+            .decorate(variable, decoration, std::iter::empty());
+    }
+
+    /// Whether a fragment shader input of this type can't be interpolated, and so gets
+    /// the `Flat` decoration even without `#[spirv(flat)]`.
+    fn needs_flat_interpolation(&self, ty: Word) -> bool {
+        match self.lookup_type(ty) {
+            SpirvType::Integer(..) | SpirvType::Float(64) => true,
+            SpirvType::Vector { element, .. }
+            | SpirvType::Array { element, .. }
+            | SpirvType::RuntimeArray { element } => self.needs_flat_interpolation(element),
+            SpirvType::Adt { field_types, .. } => field_types
+                .iter()
+                .any(|&field_type| self.needs_flat_interpolation(field_type)),
+            _ => false,
+        }
+    }
+
+    /// Error if a builtin is declared in the wrong storage class, in an execution model
+    /// it's not available in, or with the wrong type. Only the builtins listed in
+    /// `builtin_requirements` are checked, the rest are left to the validator.
+    fn check_builtin(
+        &self,
+        span: Span,
+        execution_model: ExecutionModel,
+        storage_class: StorageClass,
+        builtin: BuiltIn,
+        ptr: Word,
+    ) {
+        let requirements = match builtin_requirements(builtin) {
+            Some(requirements) => requirements,
+            None => return,
+        };
+        let allowed = match storage_class {
+            StorageClass::Input => requirements.input,
+            StorageClass::Output => requirements.output,
+            _ => &[],
+        };
+        if !allowed.contains(&execution_model) {
+            let expected = if requirements.input.contains(&execution_model) {
+                Some(StorageClass::Input)
+            } else if requirements.output.contains(&execution_model) {
+                Some(StorageClass::Output)
+            } else {
+                None
+            };
+            let msg = match expected {
+                Some(expected) => format!(
+                    "builtin `{:?}` must be an `{:?}` in `{:?}` shaders, not `{:?}`",
+                    builtin, expected, execution_model, storage_class
+                ),
+                None => format!(
+                    "builtin `{:?}` is not available in `{:?}` shaders",
+                    builtin, execution_model
+                ),
+            };
+            self.tcx.sess.span_err(span, &msg);
+            return;
+        }
+
+        let mut ty = match self.lookup_type(ptr) {
+            SpirvType::Pointer { pointee } => pointee,
+            _ => return,
+        };
+        let per_vertex_array = requirements.per_vertex
+            && match storage_class {
+                StorageClass::Input => matches!(
+                    execution_model,
+                    ExecutionModel::TessellationControl
+                        | ExecutionModel::TessellationEvaluation
+                        | ExecutionModel::Geometry
+                ),
+                StorageClass::Output => execution_model == ExecutionModel::TessellationControl,
+                _ => false,
+            };
+        if per_vertex_array {
+            ty = match self.lookup_type(ty) {
+                SpirvType::Array { element, .. } => element,
+                // Already reported by `check_per_vertex_interface`.
+                _ => return,
+            };
+        }
+        if !requirements.ty.matches(self, ty) {
+            self.tcx.sess.span_err(
+                span,
+                &format!(
+                    "builtin `{:?}` must be {}",
+                    builtin,
+                    requirements.ty.description()
+                ),
+            );
+        }
+    }
+
+    /// Error if `key` was already claimed by another parameter of the same entry point,
+    /// returning whether it was unique.
+    fn check_unique_interface_slot<K: Eq + Hash>(
+        &self,
+        declared: &mut HashMap<K, Span>,
+        key: K,
+        span: Span,
+        description: &str,
+    ) -> bool {
+        match declared.entry(key) {
+            hash_map::Entry::Occupied(previous) => {
+                self.tcx
+                    .sess
+                    .struct_span_err(
+                        span,
+                        &format!(
+                            "{} is used by more than one entry point parameter",
+                            description
+                        ),
+                    )
+                    .span_note(*previous.get(), "previously used here")
+                    .emit();
+                false
+            }
+            hash_map::Entry::Vacant(entry) => {
+                entry.insert(span);
+                true
+            }
+        }
+    }
+
+    /// Error if any of the `components` of `locations` were already claimed by another
+    /// parameter of the same entry point, reporting only the first one.
+    fn check_unique_location(
+        &self,
+        declared_interface: &mut DeclaredInterface,
+        storage_class: StorageClass,
+        locations: Range<u32>,
+        components: Range<u32>,
+        span: Span,
+    ) {
+        let is_whole_location = components == (0..4);
+        for location in locations {
+            for component in components.clone() {
+                let description = if is_whole_location {
+                    format!("location {}", location)
+                } else {
+                    format!("component {} of location {}", component, location)
+                };
+                if !self.check_unique_interface_slot(
+                    &mut declared_interface.locations,
+                    (storage_class, location, component),
+                    span,
+                    &description,
+                ) {
+                    return;
+                }
+            }
+        }
+    }
+
+    /// The components of its location used by an `Input`/`Output` parameter, starting at
+    /// its `#[spirv(component = N)]` if any. Types other than scalars, vectors and arrays
+    /// of them use whole locations, and can't be given a component.
+    fn location_components(
+        &self,
+        span: Span,
+        ptr: Word,
+        spirv_binding: SpirvBinding,
+        component: Option<u32>,
+    ) -> Range<u32> {
+        let count = match self.lookup_type(ptr) {
+            SpirvType::Pointer { pointee } => self.location_component_count(pointee),
+            _ => None,
+        };
+        let first = match component {
+            Some(first) => first,
+            None => return 0..count.unwrap_or(4),
+        };
+        if !matches!(
+            spirv_binding,
+            SpirvBinding::Location(_) | SpirvBinding::InferredLocation
+        ) {
+            self.tcx.sess.span_err(
+                span,
+                "`#[spirv(component = N)]` is only valid on `Input` and `Output` parameters \
+                 with a location",
+            );
+            return 0..4;
+        }
+        let count = match count {
+            Some(count) => count,
+            None => {
+                self.tcx.sess.span_err(
+                    span,
+                    "`#[spirv(component = N)]` is only valid on scalars, vectors and arrays of them",
+                );
+                return 0..4;
+            }
+        };
+        if first > 3 || first + count > 4 {
+            self.tcx.sess.span_err(
+                span,
+                &format!(
+                    "a value using {} component(s) doesn't fit in a location from component {}, \
+                     which only has components 0 to 3",
+                    count, first
+                ),
+            );
+            return 0..4;
+        }
+        if self.uses_64_bit_components(ptr) && first % 2 != 0 {
+            self.tcx
+                .sess
+                .span_err(span, "64-bit values must start at component 0 or 2");
+        }
+        first..first + count
+    }
+
+    /// The number of components of a location taken up by each element of `ty`, with 64-bit
+    /// scalars taking two, or `None` if it needs whole locations.
+    fn location_component_count(&self, ty: Word) -> Option<u32> {
+        match self.lookup_type(ty) {
+            SpirvType::Integer(64, _) | SpirvType::Float(64) => Some(2),
+            SpirvType::Integer(..) | SpirvType::Float(_) => Some(1),
+            SpirvType::Vector { element, count } => self
+                .location_component_count(element)
+                .map(|element| element * count)
+                .filter(|&count| count <= 4),
+            SpirvType::Array { element, .. } => self.location_component_count(element),
+            _ => None,
+        }
+    }
+
+    /// The number of consecutive locations an `Input`/`Output` of type `ty` takes up:
+    /// two for 64-bit vectors of more than two components, and one per column, element
+    /// or member of matrices, arrays and structs.
+    fn location_count(&self, ty: Word) -> u32 {
+        match self.lookup_type(ty) {
+            SpirvType::Vector { element, count } if count > 2 => {
+                if self.uses_64_bit_components(element) {
+                    2
+                } else {
+                    1
+                }
+            }
+            SpirvType::Matrix { element, count } => count * self.location_count(element),
+            SpirvType::Array { element, count } => {
+                let count = self.builder.lookup_const_u64(count).unwrap_or(1) as u32;
+                count * self.location_count(element)
+            }
+            SpirvType::Adt { field_types, .. } => field_types
+                .iter()
+                .map(|&field| self.location_count(field))
+                .sum(),
+            _ => 1,
+        }
+    }
+
+    fn uses_64_bit_components(&self, ty: Word) -> bool {
+        match self.lookup_type(ty) {
+            SpirvType::Integer(width, _) | SpirvType::Float(width) => width == 64,
+            SpirvType::Pointer { pointee: element }
+            | SpirvType::Vector { element, .. }
+            | SpirvType::Array { element, .. } => self.uses_64_bit_components(element),
+            _ => false,
+        }
+    }
+
+    /// The layout rules the host side expects for the block behind an entry point parameter:
+    /// `#[spirv(layout = "...")]` on the block's data type if present, otherwise std140 for
+    /// `Uniform` and std430 for `StorageBuffer` and `PushConstant`.
+    fn block_layout(&self, storage_class: StorageClass, ty: Ty<'tcx>) -> Option<BlockLayout> {
+        let default = match storage_class {
+            StorageClass::Uniform => BlockLayout::Std140,
+            StorageClass::StorageBuffer | StorageClass::PushConstant => BlockLayout::Std430,
+            _ => return None,
+        };
+        let explicit = self
+            .block_data_type_attrs(storage_class, ty)
+            .find_map(|attr| match attr {
+                SpirvAttribute::Layout(layout) => Some(layout),
+                _ => None,
+            });
+        Some(explicit.unwrap_or(default))
+    }
+
+    /// The storage class type behind an entry point parameter, i.e. `S` in `Bind<S, ..>` (or
+    /// in a descriptor array of `S`), or the parameter's own type for `PushConstant<T>`.
+    fn storage_class_ty(&self, storage_class: StorageClass, ty: Ty<'tcx>) -> Option<Ty<'tcx>> {
+        if is_descriptor_array(ty) {
+            match first_type_param(ty).map(|ty| ty.kind()) {
+                Some(&TyKind::Array(element, _)) | Some(&TyKind::Slice(element)) => Some(element),
+                _ => None,
+            }
+        } else if storage_class == StorageClass::PushConstant {
+            Some(ty)
+        } else {
+            first_type_param(ty)
+        }
+    }
+
+    /// The `#[spirv(...)]` attributes on the data type of the block behind an entry point
+    /// parameter, i.e. `T` in `PushConstant<T>` or `Bind<Uniform<T>, ..>`.
+    fn block_data_type_attrs(
+        &self,
+        storage_class: StorageClass,
+        ty: Ty<'tcx>,
+    ) -> impl Iterator<Item = SpirvAttribute> + Captures<'tcx> + '_ {
+        let data_ty = self
+            .storage_class_ty(storage_class, ty)
+            .and_then(first_type_param);
+        let attrs = match data_ty.map(|ty| ty.kind()) {
+            Some(TyKind::Adt(adt, _)) => self.tcx.get_attrs(adt.did),
+            _ => &[],
+        };
+        parse_attrs(self, attrs)
+    }
+
+    /// Decorate a buffer or image with the memory access qualifiers from its parameter's
+    /// `#[spirv(readonly)]`-like attributes, and from its storage class type (e.g.
+    /// `ReadOnlyStorageBuffer`). These go on the variable rather than on the members of
+    /// the block, as the same block type can be bound with different qualifiers.
+    fn decorate_memory_access(
+        &self,
+        variable: Word,
+        storage_class: StorageClass,
+        hir_param: &hir::Param<'tcx>,
+        arg_abi: &ArgAbi<'tcx, Ty<'tcx>>,
+    ) {
+        let storage_class_attrs = match self
+            .storage_class_ty(storage_class, arg_abi.layout.ty)
+            .map(|ty| ty.kind())
+        {
+            Some(TyKind::Adt(adt, _)) => self.tcx.get_attrs(adt.did),
+            _ => &[],
+        };
+        let mut decorations = parse_attrs(self, self.tcx.hir().attrs(hir_param.hir_id))
+            .chain(parse_attrs(self, storage_class_attrs))
+            .filter_map(|attr| match attr {
+                SpirvAttribute::MemoryDecoration(decoration) => Some(decoration),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        decorations.sort_by_key(|&decoration| decoration as u32);
+        decorations.dedup();
+        if decorations.is_empty() {
+            return;
+        }
+        if !matches!(
+            storage_class,
+            StorageClass::StorageBuffer | StorageClass::UniformConstant
+        ) {
+            self.tcx.sess.span_err(
+                hir_param.span,
+                &format!(
+                    "memory access qualifiers are only valid on storage buffers and \
+                     storage images, not `{:?}`",
+                    storage_class
+                ),
+            );
+            return;
+        }
+        if decorations.contains(&Decoration::NonWritable)
+            && decorations.contains(&Decoration::NonReadable)
+        {
+            self.tcx.sess.span_err(
+                hir_param.span,
+                "`#[spirv(readonly)]` and `#[spirv(writeonly)]` can't be used together",
+            );
+        }
+        for decoration in decorations {
+            if matches!(decoration, Decoration::Coherent | Decoration::Volatile)
+                && self.vulkan_memory_model()
+            {
+                self.tcx
+                    .sess
+                    .struct_span_err(
+                        hir_param.span,
+                        &format!(
+                            "`{:?}` can't be used with the Vulkan memory model",
+                            decoration
+                        ),
+                    )
+                    .note(
+                        "the Vulkan memory model expresses coherence through availability \
+                         and visibility operations instead",
+                    )
+                    .help("select the `GLSL450` memory model in `SpirvBuilder`")
+                    .emit();
+                continue;
+            }
+            self.emit_global()
+                .decorate(variable, decoration, std::iter::empty());
+        }
+    }
+
+    /// Decorate the types of a block with the `Offset`s, `ArrayStride`s and `MatrixStride`s of
+    /// `layout`, in place of those of their Rust layout, as that's where the host side puts
+    /// their members. `base_offset` is that of a `#[spirv(push_constant_offset)]`.
+    ///
+    /// The types are shared with every other use of the same Rust types, so blocks that need
+    /// one of them laid out differently are an error.
+    fn decorate_block_layout(&self, span: Span, layout: BlockLayout, ty: Word, base_offset: u32) {
+        let mut decorations = Vec::new();
+        self.collect_block_layout(layout, ty, &mut decorations);
+        // Only the top-level members of the block itself start at the base offset.
+        for ((target, _, decoration), value) in &mut decorations {
+            if *target == ty && *decoration == Decoration::Offset {
+                *value += base_offset;
+            }
+        }
+
+        let mut applied = self.block_layout_decorations.borrow_mut();
+        let mut new_decorations = Vec::new();
+        for (key, value) in decorations {
+            match applied.entry(key) {
+                hash_map::Entry::Occupied(entry) => {
+                    if *entry.get() != value {
+                        let (target, _, decoration) = key;
+                        self.tcx
+                            .sess
+                            .struct_span_err(
+                                span,
+                                &format!(
+                                    "`{}` is used by blocks with different layouts",
+                                    self.debug_type(target)
+                                ),
+                            )
+                            .note(&format!(
+                                "this block needs a `{:?}` of {}, but another one needs {}",
+                                decoration,
+                                value,
+                                entry.get()
+                            ))
+                            .help(
+                                "give the blocks the same `#[spirv(layout = \"...\")]`, \
+                                 or use types that both layouts lay out the same",
+                            )
+                            .emit();
+                    }
+                }
+                hash_map::Entry::Vacant(entry) => {
+                    entry.insert(value);
+                    new_decorations.push((key, value));
+                }
+            }
+        }
+        if new_decorations.is_empty() {
+            return;
+        }
+
+        // Replace the decorations the types got from their Rust layout, and add the missing
+        // ones (e.g. the `ArrayStride` of runtime arrays).
+        let mut pending = new_decorations.iter().copied().collect::<HashMap<_, _>>();
+        let mut emit = self.emit_global();
+        for inst in &mut emit.module_mut().annotations {
+            // `OpDecorate` and `OpMemberDecorate` respectively.
+            let key = match inst.operands[..] {
+                [Operand::IdRef(id), Operand::Decoration(dec), _] => (id, None, dec),
+                [Operand::IdRef(id), Operand::LiteralInt32(index), Operand::Decoration(dec), _] => {
+                    (id, Some(index), dec)
+                }
+                _ => continue,
+            };
+            if let Some(value) = pending.remove(&key) {
+                *inst.operands.last_mut().unwrap() = Operand::LiteralInt32(value);
+            }
+        }
+        for (key, value) in new_decorations {
+            if !pending.contains_key(&key) {
+                continue;
+            }
+            let value = std::iter::once(Operand::LiteralInt32(value));
+            match key {
+                (target, None, decoration) => emit.decorate(target, decoration, value),
+                (target, Some(member), decoration) => {
+                    emit.member_decorate(target, member, decoration, value)
+                }
+            }
+        }
+    }
+
+    /// Collect the decorations `layout` gives `ty` (and the types it's made of) into
+    /// `decorations`, returning the size of `ty` under `layout`, or `None` if it's unsized.
+    ///
+    /// Members are placed in order, each at the next offset aligned as `block_member_align`
+    /// requires, and arrays and matrices are strided by the size of their element (column)
+    /// rounded up to their own alignment, which also gives structs trailing padding.
+    fn collect_block_layout(
+        &self,
+        layout: BlockLayout,
+        ty: Word,
+        decorations: &mut Vec<(LayoutDecorationKey, u32)>,
+    ) -> Option<Size> {
+        let ty_kind = self.lookup_type(ty);
+        match ty_kind {
+            SpirvType::Adt {
+                ref field_types, ..
+            } => {
+                let mut offset = Size::ZERO;
+                for (index, &field_type) in field_types.iter().enumerate() {
+                    let member = Some(index as u32);
+                    offset = offset.align_to(self.block_member_align(layout, field_type));
+                    decorations.push(((ty, member, Decoration::Offset), offset.bytes() as u32));
+                    if let Some(stride) = self.block_matrix_stride(layout, field_type) {
+                        decorations.push(((ty, member, Decoration::MatrixStride), stride));
+                    }
+                    // Only the last member can be unsized, leaving the struct unsized too.
+                    offset = offset + self.collect_block_layout(layout, field_type, decorations)?;
+                }
+                Some(offset.align_to(self.block_member_align(layout, ty)))
+            }
+            SpirvType::Array { element, count } => {
+                let stride = self.block_stride(layout, ty, element, decorations)?;
+                decorations.push(((ty, None, Decoration::ArrayStride), stride.bytes() as u32));
+                Some(stride * self.builder.lookup_const_u64(count).unwrap())
+            }
+            SpirvType::RuntimeArray { element } => {
+                let stride = self.block_stride(layout, ty, element, decorations)?;
+                decorations.push(((ty, None, Decoration::ArrayStride), stride.bytes() as u32));
+                None
+            }
+            SpirvType::Matrix { element, count } => {
+                Some(self.block_stride(layout, ty, element, decorations)? * u64::from(count))
+            }
+            SpirvType::Vector { element, count } => {
+                Some(self.lookup_type(element).sizeof(self)? * u64::from(count))
+            }
+            _ => ty_kind.sizeof(self),
+        }
+    }
+
+    /// The stride `layout` gives the elements (columns) of the array (matrix) `ty`.
+    fn block_stride(
+        &self,
+        layout: BlockLayout,
+        ty: Word,
+        element: Word,
+        decorations: &mut Vec<(LayoutDecorationKey, u32)>,
+    ) -> Option<Size> {
+        let element_size = self.collect_block_layout(layout, element, decorations)?;
+        Some(element_size.align_to(self.block_member_align(layout, ty)))
+    }
+
+    /// The `MatrixStride` `layout` gives a struct member of type `ty`, if it's a matrix (or an
+    /// array of them), which is decorated on the member rather than on the matrix type.
+    fn block_matrix_stride(&self, layout: BlockLayout, ty: Word) -> Option<u32> {
+        match self.lookup_type(ty) {
+            SpirvType::Array { element, .. } | SpirvType::RuntimeArray { element } => {
+                self.block_matrix_stride(layout, element)
+            }
+            SpirvType::Matrix { element, .. } => {
+                let stride = self.block_stride(layout, ty, element, &mut Vec::new())?;
+                Some(stride.bytes() as u32)
+            }
+            _ => None,
+        }
+    }
+
+    /// With `-C llvm-args=--strict-block-layout`, the Rust type of a block has to be laid out
+    /// the way the host side expects too, so it can upload values of it as-is. Its layout has
+    /// to follow the alignment rules of `layout`, all the way through nested structs and arrays.
+    /// Error on members that would end up somewhere `layout` doesn't put them, e.g. a nested
+    /// struct that std140 rounds up to a 16 byte alignment, or a tightly packed array.
+    fn check_block_layout(&self, span: Span, layout: BlockLayout, ty: Word) {
+        let layout_name = match layout {
+            BlockLayout::Std140 => "std140",
+            BlockLayout::Std430 => "std430",
+        };
+        match self.lookup_type(ty) {
+            SpirvType::Adt {
+                def_id,
+                field_types,
+                field_offsets,
+                field_names,
+                ..
+            } => {
+                let mut fields = (0..field_types.len()).collect::<Vec<_>>();
+                fields.sort_by_key(|&i| field_offsets[i]);
+                // The end of the preceding struct or array, rounded up to its alignment.
+                let mut min_offset = Size::ZERO;
+                for i in fields {
+                    let (field_type, offset) = (field_types[i], field_offsets[i]);
+                    let align = self.block_member_align(layout, field_type);
+                    if offset.bytes() % align.bytes() != 0 || offset < min_offset {
+                        let field = field_names
+                            .as_ref()
+                            .map_or_else(|| i.to_string(), |names| names[i].clone());
+                        let field_span = self.field_span(def_id, &field);
+                        let mut err = self.tcx.sess.struct_span_err(
+                            field_span.unwrap_or(span),
+                            &format!(
+                                "field `{}` of `{}` is at offset {}, which {} blocks don't allow",
+                                field,
+                                self.debug_type(ty),
+                                offset.bytes(),
+                                layout_name
+                            ),
+                        );
+                        if field_span.is_some() {
+                            err.span_label(span, format!("{} layout required here", layout_name));
+                        }
+                        if offset < min_offset {
+                            err.note(&format!(
+                                "{} requires it to start after the padding of the preceding \
+                                 struct or array, at offset {} or later",
+                                layout_name,
+                                min_offset.bytes()
+                            ));
+                        } else {
+                            err.note(&format!(
+                                "{} requires `{}` to be aligned to {} bytes",
+                                layout_name,
+                                self.debug_type(field_type),
+                                align.bytes()
+                            ));
+                        }
+                        err.help(
+                            "add padding fields, or raise the alignment of nested structs \
+                             with `#[repr(C, align(16))]`",
+                        )
+                        .emit();
+                    }
+                    let field_kind = self.lookup_type(field_type);
+                    if matches!(
+                        field_kind,
+                        SpirvType::Adt { .. } | SpirvType::Array { .. } | SpirvType::Matrix { .. }
+                    ) {
+                        if let Some(size) = field_kind.sizeof(self) {
+                            min_offset = (offset + size).align_to(align);
+                        }
+                    }
+                    self.check_block_layout(span, layout, field_type);
+                }
+            }
+            SpirvType::Array { element, .. } | SpirvType::Matrix { element, .. } => {
+                let kind = if let SpirvType::Matrix { .. } = self.lookup_type(ty) {
+                    "matrices"
+                } else {
+                    "arrays"
+                };
+                // std140 also rounds the stride of every array (and of the columns of every
+                // matrix) up to a multiple of 16 bytes.
+                if let (BlockLayout::Std140, Some(stride)) =
+                    (layout, self.lookup_type(element).sizeof(self))
+                {
+                    if stride.bytes() % 16 != 0 {
+                        self.tcx
+                            .sess
+                            .struct_span_err(
+                                span,
+                                &format!(
+                                    "{} in std140 blocks must have a stride that is a \
+                                     multiple of 16 bytes",
+                                    kind
+                                ),
+                            )
+                            .note(&format!(
+                                "`{}` has a stride of {} bytes",
+                                self.debug_type(ty),
+                                stride.bytes()
+                            ))
+                            .help(
+                                "use an element type that is aligned to 16 bytes, or \
+                                 `#[spirv(layout = \"std430\")]` if the block allows it",
+                            )
+                            .emit();
+                    }
+                }
+                self.check_block_layout(span, layout, element);
+            }
+            SpirvType::RuntimeArray { element } => self.check_block_layout(span, layout, element),
+            _ => {}
+        }
+    }
+
+    /// The span of the field named `field` of the struct `def_id`, if it's defined in this crate.
+    fn field_span(&self, def_id: Option<DefId>, field: &str) -> Option<Span> {
+        let def_id = def_id.filter(|def_id| def_id.is_local())?;
+        if self.tcx.def_kind(def_id) != hir::def::DefKind::Struct {
+            return None;
+        }
+        self.tcx
+            .adt_def(def_id)
+            .non_enum_variant()
+            .fields
+            .iter()
+            .find(|f| f.ident.as_str() == field)
+            .map(|f| self.tcx.def_span(f.did))
+    }
+
+    /// Check the block layout of every type in this crate that implements `ShaderStruct`
+    /// (usually through `#[derive(ShaderStruct)]`), whether or not a shader uses it as a block:
+    /// its layout is the one the host uploads, so it has to follow `#[spirv(layout = "...")]`,
+    /// or std140 (which also satisfies std430) by default.
+    pub fn check_shader_structs(&self) {
+        for (&trait_def_id, impls) in self.tcx.all_local_trait_impls(LOCAL_CRATE) {
+            if !parse_attrs(self, self.tcx.get_attrs(trait_def_id))
+                .any(|attr| matches!(attr, SpirvAttribute::ShaderStruct))
+            {
+                continue;
+            }
+            for &impl_def_id in impls {
+                let impl_def_id = impl_def_id.to_def_id();
+                // Generic structs only have a layout once instantiated.
+                if self.tcx.generics_of(impl_def_id).count() != 0 {
+                    continue;
+                }
+                let ty = self.tcx.type_of(impl_def_id);
+                let adt = match ty.kind() {
+                    TyKind::Adt(adt, _) => adt,
+                    _ => continue,
+                };
+                let layout = parse_attrs(self, self.tcx.get_attrs(adt.did))
+                    .find_map(|attr| match attr {
+                        SpirvAttribute::Layout(layout) => Some(layout),
+                        _ => None,
+                    })
+                    .unwrap_or(BlockLayout::Std140);
+                let span = self.tcx.def_span(adt.did);
+                let span = self.tcx.sess.source_map().guess_head_span(span);
+                let spirv_ty = self.spanned_layout_of(ty, span).spirv_type(span, self);
+                self.check_block_layout(span, layout, spirv_ty);
+            }
+        }
+    }
+
+    /// The alignment `layout` requires of a block member of type `ty`: structs are aligned to
+    /// their most aligned member, and arrays (and matrices) to their element (column), which
+    /// std140 then rounds up to 16 bytes. Scalars and vectors are aligned to their size, except
+    /// that vectors of 3 are aligned like vectors of 4.
+    fn block_member_align(&self, layout: BlockLayout, ty: Word) -> Align {
+        let ty_kind = self.lookup_type(ty);
+        let align = match ty_kind {
+            SpirvType::Adt {
+                ref field_types, ..
+            } => field_types
+                .iter()
+                .map(|&field_type| self.block_member_align(layout, field_type))
+                .max()
+                .unwrap_or_else(|| Align::from_bytes(1).unwrap()),
+            SpirvType::Array { element, .. }
+            | SpirvType::RuntimeArray { element }
+            | SpirvType::Matrix { element, .. } => self.block_member_align(layout, element),
+            SpirvType::Vector { element, count } => {
+                let element_align = self.lookup_type(element).alignof(self);
+                Align::from_bytes(element_align.bytes() * u64::from(count.next_power_of_two()))
+                    .unwrap()
+            }
+            _ => ty_kind.alignof(self),
+        };
+        let is_aggregate = matches!(
+            ty_kind,
+            SpirvType::Adt { .. }
+                | SpirvType::Array { .. }
+                | SpirvType::RuntimeArray { .. }
+                | SpirvType::Matrix { .. }
+        );
+        if layout == BlockLayout::Std140 && is_aggregate {
+            align.max(Align::from_bytes(16).unwrap())
+        } else {
+            align
+        }
+    }
+
     fn get_storage_class(
         &self,
         arg_abi: &ArgAbi<'tcx, Ty<'tcx>>,
@@ -325,6 +2005,43 @@ impl<'tcx> CodegenCx<'tcx> {
                 SpirvAttribute::StorageClass(StorageClass::PushConstant) => {
                     return Some((StorageClass::PushConstant, SpirvBinding::PushConstant))
                 }
+                // Samplers and images bound directly, with their `Set` then `Binding` numbers
+                // as const parameters, instead of through `Bind`.
+                SpirvAttribute::StorageClass(StorageClass::UniformConstant) => {
+                    let mut consts = substs.consts();
+                    return if let (Some(set), Some(binding), None) =
+                        (consts.next(), consts.next(), consts.next())
+                    {
+                        Some((
+                            StorageClass::UniformConstant,
+                            SpirvBinding::DescriptorSet {
+                                set: set.eval_usize(self.tcx, self.param_env()) as u32,
+                                binding: binding.eval_usize(self.tcx, self.param_env()) as u32,
+                            },
+                        ))
+                    } else {
+                        None
+                    };
+                }
+                // Module-scope variables that are neither part of the pipeline interface nor
+                // bound to a descriptor, e.g. `Workgroup` memory or ray payloads.
+                SpirvAttribute::StorageClass(storage_class)
+                    if matches!(
+                        storage_class,
+                        StorageClass::Workgroup
+                            | StorageClass::CrossWorkgroup
+                            | StorageClass::Private
+                            | StorageClass::AtomicCounter
+                            | StorageClass::CallableDataKHR
+                            | StorageClass::IncomingCallableDataKHR
+                            | StorageClass::RayPayloadKHR
+                            | StorageClass::HitAttributeKHR
+                            | StorageClass::IncomingRayPayloadKHR
+                            | StorageClass::ShaderRecordBufferKHR
+                    ) =>
+                {
+                    return Some((storage_class, SpirvBinding::Unbound))
+                }
                 SpirvAttribute::Bind => {
                     let parse_storage_class_attr = |adt: &AdtDef| {
                         for attr in parse_attrs(self, self.tcx.get_attrs(adt.did)) {
@@ -423,6 +2140,222 @@ impl<'tcx> CodegenCx<'tcx> {
     }
 }
 
+fn is_ray_tracing(execution_model: ExecutionModel) -> bool {
+    matches!(
+        execution_model,
+        ExecutionModel::RayGenerationKHR
+            | ExecutionModel::IntersectionKHR
+            | ExecutionModel::AnyHitKHR
+            | ExecutionModel::ClosestHitKHR
+            | ExecutionModel::MissKHR
+            | ExecutionModel::CallableKHR
+    )
+}
+
+/// The first type parameter of a storage class wrapper (or other ADT), e.g. the
+/// `T` of `Uniform<T>`.
+fn first_type_param(ty: Ty<'_>) -> Option<Ty<'_>> {
+    match ty.kind() {
+        TyKind::Adt(_, substs) => substs.types().next(),
+        _ => None,
+    }
+}
+
+/// Whether an entry point parameter is a `Bind` of an array or slice of descriptors.
+fn is_descriptor_array(ty: Ty<'_>) -> bool {
+    matches!(
+        first_type_param(ty).map(|ty| ty.kind()),
+        Some(TyKind::Array(..)) | Some(TyKind::Slice(..))
+    )
+}
+
+/// Like `section.retain(keep)`, but only looking at the instructions from `start` on.
+fn retain_since(section: &mut Vec<Instruction>, start: usize, keep: impl Fn(&Instruction) -> bool) {
+    let emitted_since = section.split_off(start);
+    section.extend(emitted_since.into_iter().filter(|inst| keep(inst)));
+}
+
+/// The type of a builtin variable, ignoring the signedness of integers.
+#[derive(Copy, Clone)]
+enum BuiltinType {
+    Bool,
+    Int,
+    Float,
+    IntVector(u32),
+    FloatVector(u32),
+    IntArray,
+    FloatArray,
+}
+
+impl BuiltinType {
+    fn matches(self, cx: &CodegenCx<'_>, ty: Word) -> bool {
+        let is_int = |ty| matches!(cx.lookup_type(ty), SpirvType::Integer(32, _));
+        let is_float = |ty| matches!(cx.lookup_type(ty), SpirvType::Float(32));
+        match (self, cx.lookup_type(ty)) {
+            (BuiltinType::Bool, SpirvType::Bool) => true,
+            (BuiltinType::Int, _) => is_int(ty),
+            (BuiltinType::Float, _) => is_float(ty),
+            (BuiltinType::IntVector(n), SpirvType::Vector { element, count }) => {
+                count == n && is_int(element)
+            }
+            (BuiltinType::FloatVector(n), SpirvType::Vector { element, count }) => {
+                count == n && is_float(element)
+            }
+            (BuiltinType::IntArray, SpirvType::Array { element, .. }) => is_int(element),
+            (BuiltinType::FloatArray, SpirvType::Array { element, .. }) => is_float(element),
+            _ => false,
+        }
+    }
+
+    fn description(self) -> String {
+        match self {
+            BuiltinType::Bool => "a `bool`".to_string(),
+            BuiltinType::Int => "a `u32` or `i32`".to_string(),
+            BuiltinType::Float => "an `f32`".to_string(),
+            BuiltinType::IntVector(n) => format!("a vector of {} `u32`s or `i32`s", n),
+            BuiltinType::FloatVector(n) => format!("a vector of {} `f32`s", n),
+            BuiltinType::IntArray => "an array of `u32` or `i32`".to_string(),
+            BuiltinType::FloatArray => "an array of `f32`".to_string(),
+        }
+    }
+}
+
+/// How a builtin must be declared, per the Vulkan environment spec.
+struct BuiltinRequirements {
+    ty: BuiltinType,
+    /// The execution models the builtin can be an `Input` in.
+    input: &'static [ExecutionModel],
+    /// The execution models the builtin can be an `Output` in.
+    output: &'static [ExecutionModel],
+    /// Whether the builtin is arrayed per vertex in the inputs of tessellation and geometry
+    /// shaders, and in the outputs of tessellation control shaders.
+    per_vertex: bool,
+}
+
+fn builtin_requirements(builtin: BuiltIn) -> Option<BuiltinRequirements> {
+    use ExecutionModel::{
+        Fragment, GLCompute, Geometry, MeshNV, TaskNV, TessellationControl as TessControl,
+        TessellationEvaluation as TessEval, Vertex,
+    };
+    const PRE_RASTERIZATION: &[ExecutionModel] = &[Vertex, TessControl, TessEval, Geometry];
+    const COMPUTE: &[ExecutionModel] = &[GLCompute, TaskNV, MeshNV];
+
+    let (ty, input, output, per_vertex): (_, &[_], &[_], _) = match builtin {
+        BuiltIn::Position => (
+            BuiltinType::FloatVector(4),
+            &[TessControl, TessEval, Geometry],
+            PRE_RASTERIZATION,
+            true,
+        ),
+        BuiltIn::PointSize => (
+            BuiltinType::Float,
+            &[TessControl, TessEval, Geometry],
+            PRE_RASTERIZATION,
+            true,
+        ),
+        BuiltIn::ClipDistance | BuiltIn::CullDistance => (
+            BuiltinType::FloatArray,
+            &[TessControl, TessEval, Geometry, Fragment],
+            PRE_RASTERIZATION,
+            true,
+        ),
+        BuiltIn::VertexIndex
+        | BuiltIn::InstanceIndex
+        | BuiltIn::BaseVertex
+        | BuiltIn::BaseInstance
+        | BuiltIn::DrawIndex => (BuiltinType::Int, &[Vertex], &[], false),
+        BuiltIn::PrimitiveId => (
+            BuiltinType::Int,
+            &[
+                TessControl,
+                TessEval,
+                Geometry,
+                Fragment,
+                ExecutionModel::IntersectionKHR,
+                ExecutionModel::AnyHitKHR,
+                ExecutionModel::ClosestHitKHR,
+            ],
+            &[Geometry],
+            false,
+        ),
+        BuiltIn::InvocationId => (BuiltinType::Int, &[TessControl, Geometry], &[], false),
+        BuiltIn::Layer | BuiltIn::ViewportIndex => (
+            BuiltinType::Int,
+            &[Fragment],
+            &[Vertex, TessEval, Geometry],
+            false,
+        ),
+        BuiltIn::TessLevelOuter | BuiltIn::TessLevelInner => {
+            (BuiltinType::FloatArray, &[TessEval], &[TessControl], false)
+        }
+        BuiltIn::TessCoord => (BuiltinType::FloatVector(3), &[TessEval], &[], false),
+        BuiltIn::PatchVertices => (BuiltinType::Int, &[TessControl, TessEval], &[], false),
+        BuiltIn::FragCoord => (BuiltinType::FloatVector(4), &[Fragment], &[], false),
+        BuiltIn::PointCoord | BuiltIn::SamplePosition => {
+            (BuiltinType::FloatVector(2), &[Fragment], &[], false)
+        }
+        BuiltIn::FrontFacing | BuiltIn::HelperInvocation => {
+            (BuiltinType::Bool, &[Fragment], &[], false)
+        }
+        BuiltIn::SampleId => (BuiltinType::Int, &[Fragment], &[], false),
+        BuiltIn::SampleMask => (BuiltinType::IntArray, &[Fragment], &[Fragment], false),
+        BuiltIn::FragDepth => (BuiltinType::Float, &[], &[Fragment], false),
+        BuiltIn::NumWorkgroups
+        | BuiltIn::WorkgroupId
+        | BuiltIn::LocalInvocationId
+        | BuiltIn::GlobalInvocationId => (BuiltinType::IntVector(3), COMPUTE, &[], false),
+        BuiltIn::LocalInvocationIndex => (BuiltinType::Int, COMPUTE, &[], false),
+        _ => return None,
+    };
+    Some(BuiltinRequirements {
+        ty,
+        input,
+        output,
+        per_vertex,
+    })
+}
+
+/// Builtins and location components claimed so far by the parameters of an entry point, per storage
+/// class, and its spec constant ids, along with the span of the parameter that claimed them.
+#[derive(Default)]
+struct DeclaredInterface {
+    builtins: HashMap<(StorageClass, BuiltIn), Span>,
+    locations: HashMap<(StorageClass, u32, u32), Span>,
+    spec_ids: HashMap<u32, Span>,
+}
+
+/// A spec constant declared by an entry point, which others can only share if they declare
+/// it with the same type and default.
+#[derive(Copy, Clone)]
+pub struct SpecConstant {
+    ty: Word,
+    default: SpecConstantValue,
+    result: Word,
+    span: Span,
+}
+
+/// What makes two interface variables of different entry points interchangeable: their
+/// storage class, pointer type and decorations (each encoded without its target).
+#[derive(PartialEq, Eq, Hash)]
+pub struct InterfaceVariableKey {
+    storage_class: StorageClass,
+    ty: Word,
+    decorations: Vec<Vec<u32>>,
+}
+
+/// A decoration that depends on the layout of the blocks a type is used in, e.g. `ArrayStride`,
+/// as its target and, for `OpMemberDecorate`, member.
+pub type LayoutDecorationKey = (Word, Option<u32>, Decoration);
+
+/// The lengths of the module sections an interface variable's instructions go into, taken
+/// right before declaring it, see `CodegenCx::module_mark`.
+#[derive(Copy, Clone)]
+struct ModuleMark {
+    types_global_values: usize,
+    annotations: usize,
+    debugs: usize,
+}
+
 #[derive(Copy, Clone, Debug, PartialEq, Eq, Ord, PartialOrd, Hash)]
 pub enum SpirvBinding {
     DescriptorSet { set: u32, binding: u32 },
@@ -430,4 +2363,5 @@ pub enum SpirvBinding {
     InferredLocation,
     Builtin,
     PushConstant,
+    Unbound,
 }
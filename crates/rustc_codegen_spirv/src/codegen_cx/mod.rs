@@ -6,7 +6,8 @@ mod type_;
 use crate::builder::{ExtInst, InstructionTable};
 use crate::builder_spirv::{BuilderCursor, BuilderSpirv, SpirvValue, SpirvValueKind};
 use crate::decorations::{
-    CustomDecoration, SerializedSpan, UnrollLoopsDecoration, ZombieDecoration,
+    CustomDecoration, LoopIterationsDecoration, SerializedSpan, UnrollLoopsDecoration,
+    ZombieDecoration,
 };
 use crate::spirv_type::{SpirvType, SpirvTypePrinter, TypeCache};
 use crate::symbols::Symbols;
@@ -55,6 +56,7 @@ pub struct CodegenCx<'tcx> {
     /// get `LoopControl::UNROLL` applied to all of their loops' `OpLoopMerge`
     /// instructions, during structuralization.
     unroll_loops_decorations: RefCell<HashMap<Word, UnrollLoopsDecoration>>,
+    loop_iterations_decorations: RefCell<HashMap<Word, LoopIterationsDecoration>>,
     pub kernel_mode: bool,
     /// Cache of all the builtin symbols we need
     pub sym: Rc<Symbols>,
@@ -67,10 +69,46 @@ pub struct CodegenCx<'tcx> {
     pub panic_bounds_check_fn_id: Cell<Option<Word>>,
     /// Implements `Index` for descriptor arrays and runtime descriptor arrays.
     pub index_descriptor_array_id: RefCell<HashSet<Word>>,
+    /// The subset of `index_descriptor_array_id` whose index may be non-uniform, and so has to
+    /// be decorated with `NonUniform`.
+    pub nonuniform_index_descriptor_array_id: RefCell<HashSet<Word>>,
+    /// Functions with `#[spirv(undef)]`, calls to which are replaced with an `OpUndef` of
+    /// their return type.
+    pub undef_fn_ids: RefCell<HashSet<Word>>,
+    /// Functions with `#[spirv(no_contraction)]`, whose floating-point arithmetic
+    /// gets decorated with `NoContraction`.
+    pub no_contraction_fn_ids: RefCell<HashSet<Word>>,
+    /// Functions with `#[spirv(fast_math)]`, whose floating-point arithmetic
+    /// gets decorated with `FPFastMathMode` (only available to kernels).
+    pub fast_math_fn_ids: RefCell<HashSet<Word>>,
+    /// Functions with `#[spirv(relaxed_precision)]`, whose floating-point arithmetic
+    /// gets decorated with `RelaxedPrecision`.
+    pub relaxed_precision_fn_ids: RefCell<HashSet<Word>>,
+    /// Interface variables of the entry points declared so far, shared between entry points
+    /// that declare identical ones.
+    interface_variables: RefCell<HashMap<entry::InterfaceVariableKey, Word>>,
+    /// Spec constants declared so far, by `SpecId`.
+    spec_constants: RefCell<HashMap<u32, entry::SpecConstant>>,
+    /// Results of the `OpSpecConstant*`s and `OpSpecConstantOp`s declared so far, which
+    /// integer arithmetic keeps as `OpSpecConstantOp`s (see `Builder::spec_constant_op`).
+    pub spec_constant_ids: RefCell<HashSet<Word>>,
+    /// The `Offset`, `ArrayStride` and `MatrixStride` decorations the blocks declared so far
+    /// gave their types, which later blocks using the same types have to agree with.
+    block_layout_decorations: RefCell<HashMap<entry::LayoutDecorationKey, u32>>,
 
     /// Some runtimes (e.g. intel-compute-runtime) disallow atomics on i8 and i16, even though it's allowed by the spec.
     /// This enables/disables them.
     pub i8_i16_atomics_allowed: bool,
+
+    /// Upper bound on the number of invocations in a compute local work group, checked against
+    /// `#[spirv(compute(threads(x, y, z)))]`. Defaults to 128, the minimum
+    /// `maxComputeWorkGroupInvocations` that Vulkan guarantees, and can be raised with
+    /// `-C llvm-args=--max-compute-workgroup-invocations=N`.
+    pub max_compute_workgroup_invocations: u32,
+
+    /// Whether the Rust types of blocks also have to follow the layout the host side expects,
+    /// not just their decorations, enabled with `-C llvm-args=--strict-block-layout`.
+    pub strict_block_layout: bool,
 }
 
 impl<'tcx> CodegenCx<'tcx> {
@@ -104,6 +142,27 @@ impl<'tcx> CodegenCx<'tcx> {
                 tcx.sess.err(&format!("Unknown feature {}", feature));
             }
         }
+        // these must match spirv-builder's lib.rs
+        let mut max_compute_workgroup_invocations = 128;
+        let mut strict_block_layout = false;
+        for arg in &tcx.sess.opts.cg.llvm_args {
+            if let Some(max) = arg.strip_prefix("--max-compute-workgroup-invocations=") {
+                match max.parse() {
+                    Ok(max) => max_compute_workgroup_invocations = max,
+                    Err(_) => tcx.sess.err(&format!(
+                        "--max-compute-workgroup-invocations must be a u32, not `{}`",
+                        max
+                    )),
+                }
+            } else if arg == "--strict-block-layout" {
+                strict_block_layout = true;
+            } else {
+                // Other flags may be meant for another backend, e.g. when shared through
+                // `RUSTFLAGS`.
+                tcx.sess
+                    .warn(&format!("ignoring unknown codegen arg `{}`", arg));
+            }
+        }
         Self {
             tcx,
             codegen_unit,
@@ -115,6 +174,7 @@ impl<'tcx> CodegenCx<'tcx> {
             ext_inst: Default::default(),
             zombie_decorations: Default::default(),
             unroll_loops_decorations: Default::default(),
+            loop_iterations_decorations: Default::default(),
             kernel_mode,
             sym,
             instruction_table: InstructionTable::new(),
@@ -122,7 +182,18 @@ impl<'tcx> CodegenCx<'tcx> {
             panic_fn_id: Default::default(),
             panic_bounds_check_fn_id: Default::default(),
             index_descriptor_array_id: Default::default(),
+            nonuniform_index_descriptor_array_id: Default::default(),
+            undef_fn_ids: Default::default(),
+            no_contraction_fn_ids: Default::default(),
+            fast_math_fn_ids: Default::default(),
+            relaxed_precision_fn_ids: Default::default(),
+            interface_variables: Default::default(),
+            spec_constants: Default::default(),
+            spec_constant_ids: Default::default(),
+            block_layout_decorations: Default::default(),
             i8_i16_atomics_allowed: false,
+            max_compute_workgroup_invocations,
+            strict_block_layout,
         }
     }
 
@@ -203,6 +274,16 @@ impl<'tcx> CodegenCx<'tcx> {
             })
     }
 
+    pub fn vulkan_memory_model(&self) -> bool {
+        self.emit_global()
+            .module_ref()
+            .memory_model
+            .as_ref()
+            .map_or(false, |inst| {
+                inst.operands[1].unwrap_memory_model() == MemoryModel::Vulkan
+            })
+    }
+
     pub fn finalize_module(self) -> Module {
         let mut result = self.builder.finalize();
         result.annotations.extend(
@@ -215,6 +296,12 @@ impl<'tcx> CodegenCx<'tcx> {
                         .into_inner()
                         .into_iter()
                         .map(|(id, unroll_loops)| unroll_loops.encode(id)),
+                )
+                .chain(
+                    self.loop_iterations_decorations
+                        .into_inner()
+                        .into_iter()
+                        .map(|(id, loop_iterations)| loop_iterations.encode(id)),
                 ),
         );
         result
@@ -170,6 +170,7 @@ impl<'tcx> BaseTypeMethods<'tcx> for CodegenCx<'tcx> {
             SpirvType::Adt { .. } => TypeKind::Struct,
             SpirvType::Opaque { .. } => TypeKind::Struct,
             SpirvType::Vector { .. } => TypeKind::Vector,
+            SpirvType::Matrix { .. } => TypeKind::Array,
             SpirvType::Array { .. } => TypeKind::Array,
             SpirvType::RuntimeArray { .. } => TypeKind::Array,
             SpirvType::Pointer { .. } => TypeKind::Pointer,
@@ -177,6 +178,7 @@ impl<'tcx> BaseTypeMethods<'tcx> for CodegenCx<'tcx> {
             SpirvType::Image { .. } => TypeKind::Integer,
             SpirvType::Sampler => TypeKind::Integer,
             SpirvType::SampledImage { .. } => TypeKind::Integer,
+            SpirvType::AccelerationStructureKhr => TypeKind::Integer,
         }
     }
     fn type_ptr_to(&self, ty: Self::Type) -> Self::Type {
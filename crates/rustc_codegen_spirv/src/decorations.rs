@@ -2,7 +2,7 @@
 //! the original codegen of a crate, and consumed by the `linker`.
 
 use rspirv::dr::{Instruction, Module, Operand};
-use rspirv::spirv::{Decoration, Op, Word};
+use rspirv::spirv::{Decoration, LoopControl, Op, Word};
 use rustc_span::{source_map::SourceMap, FileName, Pos, Span};
 use serde::{Deserialize, Serialize};
 use std::marker::PhantomData;
@@ -99,16 +99,70 @@ impl<'a, D: Deserialize<'a>> LazilyDeserialized<'a, D> {
     }
 }
 
-/// An `OpFunction` with `#[spirv(unroll_loops)]` on the Rust `fn` definition,
-/// which should get `LoopControl::UNROLL` applied to all of its loops'
-/// `OpLoopMerge` instructions, during structuralization.
-#[derive(Deserialize, Serialize)]
-pub struct UnrollLoopsDecoration {}
+/// An `OpFunction` with `#[spirv(unroll_loops)]` (or `unroll_loops(count = N)`,
+/// or `dont_unroll_loops`) on the Rust `fn` definition, which should get the
+/// corresponding `LoopControl` applied to all of its loops' `OpLoopMerge`
+/// instructions, during structuralization.
+#[derive(Copy, Clone, Debug, Deserialize, Serialize)]
+pub enum UnrollLoopsDecoration {
+    Unroll,
+    /// Unroll by the given number of iterations (SPIR-V 1.4 and later).
+    PartialCount(u32),
+    DontUnroll,
+}
+
+impl UnrollLoopsDecoration {
+    /// The `LoopControl` for `OpLoopMerge`, and the literal operand it takes, if any.
+    pub fn loop_control(self) -> (LoopControl, Option<Operand>) {
+        match self {
+            Self::Unroll => (LoopControl::UNROLL, None),
+            Self::PartialCount(count) => (
+                LoopControl::PARTIAL_COUNT,
+                Some(Operand::LiteralInt32(count)),
+            ),
+            Self::DontUnroll => (LoopControl::DONT_UNROLL, None),
+        }
+    }
+}
 
 impl CustomDecoration for UnrollLoopsDecoration {
     const ENCODING_PREFIX: &'static str = "U";
 }
 
+/// An `OpFunction` with `#[spirv(loop(...))]` on the Rust `fn` definition,
+/// giving bounds on the iteration counts (and the dependency distance) of all
+/// of its loops, to be added to their `OpLoopMerge` instructions' `LoopControl`.
+#[derive(Copy, Clone, Debug, Default, Deserialize, Serialize)]
+pub struct LoopIterationsDecoration {
+    pub dependency_length: Option<u32>,
+    pub min_iterations: Option<u32>,
+    pub max_iterations: Option<u32>,
+}
+
+impl LoopIterationsDecoration {
+    /// The `LoopControl` for `OpLoopMerge`, and the literal operands it takes,
+    /// in the order SPIR-V requires them (i.e. that of the mask bits).
+    pub fn loop_control(self) -> (LoopControl, Vec<Operand>) {
+        let mut loop_control = LoopControl::NONE;
+        let mut operands = vec![];
+        for &(bit, value) in &[
+            (LoopControl::DEPENDENCY_LENGTH, self.dependency_length),
+            (LoopControl::MIN_ITERATIONS, self.min_iterations),
+            (LoopControl::MAX_ITERATIONS, self.max_iterations),
+        ] {
+            if let Some(value) = value {
+                loop_control |= bit;
+                operands.push(Operand::LiteralInt32(value));
+            }
+        }
+        (loop_control, operands)
+    }
+}
+
+impl CustomDecoration for LoopIterationsDecoration {
+    const ENCODING_PREFIX: &'static str = "L";
+}
+
 #[derive(Deserialize, Serialize)]
 pub struct ZombieDecoration {
     pub reason: String,
@@ -138,6 +138,7 @@ use rustc_middle::ty::{self, query, DefIdTree, Instance, InstanceDef, TyCtxt};
 use rustc_mir::util::write_mir_pretty;
 use rustc_session::config::{self, OptLevel, OutputFilenames, OutputType};
 use rustc_session::Session;
+use rustc_span::def_id::LOCAL_CRATE;
 use rustc_span::symbol::{sym, Symbol};
 use rustc_target::spec::abi::Abi;
 use rustc_target::spec::{LinkerFlavor, PanicStrategy, Target, TargetOptions, TargetTriple};
@@ -530,6 +531,12 @@ impl ExtraBackendMethods for SpirvCodegenBackend {
 
         let cx = CodegenCx::new(tcx, cgu);
         let do_codegen = || {
+            // `ShaderStruct` layouts don't depend on any one codegen unit, check them in the first.
+            let (_, cgus) = tcx.collect_and_partition_mono_items(LOCAL_CRATE);
+            if cgus.first().map(|cgu| cgu.name()) == Some(cgu_name) {
+                cx.check_shader_structs();
+            }
+
             let mono_items = cx.codegen_unit.items_in_deterministic_order(cx.tcx);
 
             if let Ok(path) = env::var("DUMP_MIR") {
@@ -1,6 +1,6 @@
-use rspirv::dr::{Instruction, Module};
-use rspirv::spirv::{Capability, Op};
-use std::collections::HashSet;
+use rspirv::dr::{Instruction, Module, Operand};
+use rspirv::spirv::{Capability, Decoration, Dim, ImageFormat, Op, StorageClass, Word};
+use std::collections::{HashMap, HashSet};
 
 pub fn remove_extra_capabilities(module: &mut Module) {
     let used_capabilities = used_capabilities(module);
@@ -13,6 +13,22 @@ pub fn remove_extra_capabilities(module: &mut Module) {
         Capability::IntegerFunctions2INTEL,
         Capability::DemoteToHelperInvocationEXT,
         Capability::DerivativeControl,
+        Capability::GroupNonUniform,
+        Capability::GroupNonUniformVote,
+        Capability::GroupNonUniformBallot,
+        Capability::GroupNonUniformShuffle,
+        Capability::GroupNonUniformShuffleRelative,
+        Capability::GroupNonUniformArithmetic,
+        Capability::SparseResidency,
+        Capability::ShaderNonUniform,
+        Capability::StorageImageReadWithoutFormat,
+        Capability::StorageImageWriteWithoutFormat,
+        Capability::StorageBuffer8BitAccess,
+        Capability::UniformAndStorageBuffer8BitAccess,
+        Capability::StoragePushConstant8,
+        Capability::StorageBuffer16BitAccess,
+        Capability::UniformAndStorageBuffer16BitAccess,
+        Capability::StoragePushConstant16,
     ]
     .iter()
     .copied()
@@ -25,7 +41,17 @@ pub fn remove_extra_capabilities(module: &mut Module) {
 }
 
 fn used_capabilities(module: &Module) -> HashSet<Capability> {
-    let mut set = HashSet::new();
+    let unknown_format_storage_images = unknown_format_storage_images(module);
+    let value_types: HashMap<Word, Word> = module
+        .all_inst_iter()
+        .filter_map(|inst| Some((inst.result_id?, inst.result_type?)))
+        .collect();
+    let is_unknown_format_image = |image: &Operand| {
+        value_types
+            .get(&image.unwrap_id_ref())
+            .map_or(false, |ty| unknown_format_storage_images.contains(ty))
+    };
+    let mut set = narrow_storage_capabilities(module);
     for inst in module.all_inst_iter() {
         set.extend(inst.class.capabilities);
         match inst.class.opcode {
@@ -50,12 +76,99 @@ fn used_capabilities(module: &Module) -> HashSet<Capability> {
                 }
                 _ => {}
             },
+            Op::ImageRead | Op::ImageSparseRead if is_unknown_format_image(&inst.operands[0]) => {
+                set.insert(Capability::StorageImageReadWithoutFormat);
+            }
+            Op::ImageWrite if is_unknown_format_image(&inst.operands[0]) => {
+                set.insert(Capability::StorageImageWriteWithoutFormat);
+            }
+            Op::Decorate if inst.operands[1] == Operand::Decoration(Decoration::NonUniform) => {
+                set.insert(Capability::ShaderNonUniform);
+            }
             _ => {}
         }
     }
     set
 }
 
+/// 8-bit and 16-bit types can only be stored in buffer blocks with extra capabilities, which differ
+/// between storage buffers, uniform blocks (which also allow storage buffers) and push constants.
+fn narrow_storage_capabilities(module: &Module) -> HashSet<Capability> {
+    let types: HashMap<Word, &Instruction> = module
+        .types_global_values
+        .iter()
+        .filter_map(|inst| Some((inst.result_id?, inst)))
+        .collect();
+    let mut set = HashSet::new();
+    for inst in &module.types_global_values {
+        if inst.class.opcode != Op::TypePointer {
+            continue;
+        }
+        let (capability_8, capability_16) = match inst.operands[0].unwrap_storage_class() {
+            StorageClass::StorageBuffer | StorageClass::PhysicalStorageBuffer => (
+                Capability::StorageBuffer8BitAccess,
+                Capability::StorageBuffer16BitAccess,
+            ),
+            StorageClass::Uniform => (
+                Capability::UniformAndStorageBuffer8BitAccess,
+                Capability::UniformAndStorageBuffer16BitAccess,
+            ),
+            StorageClass::PushConstant => (
+                Capability::StoragePushConstant8,
+                Capability::StoragePushConstant16,
+            ),
+            _ => continue,
+        };
+        let mut widths = HashSet::new();
+        narrow_widths(&types, inst.operands[1].unwrap_id_ref(), &mut widths);
+        if widths.contains(&8) {
+            set.insert(capability_8);
+        }
+        if widths.contains(&16) {
+            set.insert(capability_16);
+        }
+    }
+    set
+}
+
+/// Collect the widths of all the integer and float types `ty` is made of, not looking through
+/// pointers (which are separately checked in their own storage class).
+fn narrow_widths(types: &HashMap<Word, &Instruction>, ty: Word, widths: &mut HashSet<u32>) {
+    let inst = match types.get(&ty) {
+        Some(inst) => inst,
+        None => return,
+    };
+    match inst.class.opcode {
+        Op::TypeInt | Op::TypeFloat => {
+            widths.insert(inst.operands[0].unwrap_literal_int32());
+        }
+        Op::TypeVector | Op::TypeMatrix | Op::TypeArray | Op::TypeRuntimeArray => {
+            narrow_widths(types, inst.operands[0].unwrap_id_ref(), widths);
+        }
+        Op::TypeStruct => {
+            for member in &inst.operands {
+                narrow_widths(types, member.unwrap_id_ref(), widths);
+            }
+        }
+        _ => {}
+    }
+}
+
+/// Storage images without a declared format need extra capabilities to be read from or written to.
+fn unknown_format_storage_images(module: &Module) -> HashSet<Word> {
+    module
+        .types_global_values
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::TypeImage
+                && inst.operands[1].unwrap_dim() != Dim::DimSubpassData
+                && inst.operands[5].unwrap_literal_int32() == 2
+                && inst.operands[6].unwrap_image_format() == ImageFormat::Unknown
+        })
+        .map(|inst| inst.result_id.unwrap())
+        .collect()
+}
+
 fn remove_capabilities(module: &mut Module, set: &HashSet<Capability>) {
     module.capabilities.retain(|inst| {
         inst.class.opcode != Op::Capability || !set.contains(&inst.operands[0].unwrap_capability())
@@ -76,9 +189,30 @@ fn additional_extensions(module: &Module, inst: &Instruction) -> &'static [&'sta
         let version = module.header.as_ref().unwrap().version();
         match inst.operands[0].unwrap_capability() {
             Capability::VulkanMemoryModel if version < (1, 5) => &["SPV_KHR_vulkan_memory_model"],
-            Capability::RuntimeDescriptorArray if version < (1, 5) => {
+            Capability::RuntimeDescriptorArray
+            | Capability::ShaderNonUniform
+            | Capability::SampledImageArrayNonUniformIndexing
+            | Capability::StorageImageArrayNonUniformIndexing
+            | Capability::UniformTexelBufferArrayNonUniformIndexing
+            | Capability::StorageTexelBufferArrayNonUniformIndexing
+                if version < (1, 5) =>
+            {
                 &["SPV_EXT_descriptor_indexing"]
             }
+            Capability::StorageBuffer8BitAccess
+            | Capability::UniformAndStorageBuffer8BitAccess
+            | Capability::StoragePushConstant8
+                if version < (1, 5) =>
+            {
+                &["SPV_KHR_8bit_storage"]
+            }
+            Capability::StorageBuffer16BitAccess
+            | Capability::UniformAndStorageBuffer16BitAccess
+            | Capability::StoragePushConstant16
+                if version < (1, 3) =>
+            {
+                &["SPV_KHR_16bit_storage"]
+            }
             _ => &[],
         }
     } else {
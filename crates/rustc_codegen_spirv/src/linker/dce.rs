@@ -1,5 +1,5 @@
-use rspirv::dr::{Instruction, Module};
-use rspirv::spirv::Word;
+use rspirv::dr::{Instruction, Module, Operand};
+use rspirv::spirv::{BuiltIn, Decoration, Op, Word};
 use std::collections::HashSet;
 
 pub fn dce(module: &mut Module) {
@@ -13,6 +13,19 @@ pub fn collect_roots(module: &Module) -> HashSet<Word> {
     for inst in &module.entry_points {
         root(inst, &mut rooted);
     }
+    // `OpExecutionModeId` refers to (spec) constants, e.g. for `LocalSizeId`.
+    for inst in &module.execution_modes {
+        root(inst, &mut rooted);
+    }
+    // The `WorkgroupSize` builtin is only referenced through its decoration.
+    for inst in &module.annotations {
+        if inst.class.opcode == Op::Decorate
+            && inst.operands[1] == Operand::Decoration(Decoration::BuiltIn)
+            && inst.operands[2] == Operand::BuiltIn(BuiltIn::WorkgroupSize)
+        {
+            root(inst, &mut rooted);
+        }
+    }
     rooted
 }
 
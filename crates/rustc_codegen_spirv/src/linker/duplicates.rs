@@ -266,9 +266,16 @@ pub fn remove_duplicate_types(module: &mut Module) {
     module
         .annotations
         .retain(|inst| anno_set.insert(inst.assemble()));
-    // Same thing with OpName
+    // Same thing with OpName, and with OpMemberName for each member, which would otherwise
+    // give every member of a deduplicated struct several names.
     let mut name_ids = HashSet::new();
-    module.debugs.retain(|inst| {
-        inst.class.opcode != Op::Name || name_ids.insert(inst.operands[0].unwrap_id_ref())
+    let mut member_name_ids = HashSet::new();
+    module.debugs.retain(|inst| match inst.class.opcode {
+        Op::Name => name_ids.insert(inst.operands[0].unwrap_id_ref()),
+        Op::MemberName => member_name_ids.insert((
+            inst.operands[0].unwrap_id_ref(),
+            inst.operands[1].unwrap_literal_int32(),
+        )),
+        _ => true,
     });
 }
@@ -7,7 +7,7 @@
 use super::apply_rewrite_rules;
 use super::mem2reg::compute_preds;
 use rspirv::dr::{Block, Function, Instruction, Module, ModuleHeader, Operand};
-use rspirv::spirv::{FunctionControl, Op, StorageClass, Word};
+use rspirv::spirv::{Decoration, FunctionControl, Op, StorageClass, Word};
 use std::collections::{HashMap, HashSet};
 use std::mem::replace;
 
@@ -45,9 +45,28 @@ pub fn inline(module: &mut Module) {
                 .map_or(false, |id| dropped_ids.contains(&id))
         })
     });
+    let mut value_decorations = HashMap::<_, Vec<_>>::new();
+    for inst in &module.annotations {
+        if inst.class.opcode == Op::Decorate
+            && matches!(
+                inst.operands[1].unwrap_decoration(),
+                Decoration::NonUniform
+                    | Decoration::NoContraction
+                    | Decoration::FPFastMathMode
+                    | Decoration::RelaxedPrecision
+            )
+        {
+            value_decorations
+                .entry(inst.operands[0].unwrap_id_ref())
+                .or_default()
+                .push(inst.clone());
+        }
+    }
     let mut inliner = Inliner {
         header: &mut module.header.as_mut().unwrap(),
         types_global_values: &mut module.types_global_values,
+        annotations: &mut module.annotations,
+        value_decorations,
         void,
         functions: &functions,
         disallowed_argument_types: &disallowed_argument_types,
@@ -134,6 +153,10 @@ fn should_inline(disallowed_argument_types: &HashSet<Word>, function: &Function)
 struct Inliner<'m, 'map> {
     header: &'m mut ModuleHeader,
     types_global_values: &'m mut Vec<Instruction>,
+    annotations: &'m mut Vec<Instruction>,
+    /// Decorations of values (e.g. `NonUniform` or `NoContraction`), which
+    /// their clones need too.
+    value_decorations: HashMap<Word, Vec<Instruction>>,
     void: Word,
     functions: &'map FunctionMap,
     disallowed_argument_types: &'map HashSet<Word>,
@@ -305,6 +328,18 @@ impl Inliner<'_, '_> {
                     let new_id = self.id();
                     let old = rewrite_rules.insert(result_id, new_id);
                     assert!(old.is_none());
+                    if let Some(decorations) = self.value_decorations.get(&result_id) {
+                        let decorations: Vec<_> = decorations
+                            .iter()
+                            .map(|inst| {
+                                let mut inst = inst.clone();
+                                inst.operands[0] = Operand::IdRef(new_id);
+                                inst
+                            })
+                            .collect();
+                        self.annotations.extend(decorations.iter().cloned());
+                        self.value_decorations.insert(new_id, decorations);
+                    }
                 }
             }
         }
@@ -13,10 +13,10 @@ mod specializer;
 mod structurizer;
 mod zombies;
 
-use crate::decorations::{CustomDecoration, UnrollLoopsDecoration};
+use crate::decorations::{CustomDecoration, LoopIterationsDecoration, UnrollLoopsDecoration};
 use rspirv::binary::Consumer;
 use rspirv::dr::{Block, Instruction, Loader, Module, ModuleHeader, Operand};
-use rspirv::spirv::{Op, StorageClass, Word};
+use rspirv::spirv::{LoopControl, Op, StorageClass, Word};
 use rustc_errors::ErrorReported;
 use rustc_session::Session;
 use std::collections::HashMap;
@@ -169,6 +169,16 @@ pub fn link(sess: &Session, mut inputs: Vec<Module>, opts: &Options) -> Result<M
         );
     }
 
+    {
+        let _timer = sess.timer("link_complete_entry_point_interfaces");
+        simple_passes::complete_entry_point_interfaces(&mut output);
+    }
+
+    {
+        let _timer = sess.timer("link_narrow_workgroup_atomic_scopes");
+        simple_passes::narrow_workgroup_atomic_scopes(&mut output);
+    }
+
     if opts.inline {
         let _timer = sess.timer("link_inline");
         inline::inline(&mut output);
@@ -179,17 +189,30 @@ pub fn link(sess: &Session, mut inputs: Vec<Module>, opts: &Options) -> Result<M
         dce::dce(&mut output);
     }
 
-    let unroll_loops_decorations = UnrollLoopsDecoration::decode_all(&output)
-        .map(|(id, unroll_loops)| (id, unroll_loops.deserialize()))
-        .collect::<HashMap<_, _>>();
+    // Loop hints are function-scoped, and combine into the `LoopControl` (and
+    // its literal operands) used for all of that function's `OpLoopMerge`s.
+    let mut loop_controls: HashMap<Word, (LoopControl, Vec<Operand>)> = HashMap::new();
+    for (id, loop_iterations) in LoopIterationsDecoration::decode_all(&output) {
+        loop_controls.insert(id, loop_iterations.deserialize().loop_control());
+    }
+    for (id, unroll_loops) in UnrollLoopsDecoration::decode_all(&output) {
+        let (loop_control, operands) = loop_controls
+            .entry(id)
+            .or_insert((LoopControl::NONE, vec![]));
+        let (unroll_control, unroll_operand) = unroll_loops.deserialize().loop_control();
+        *loop_control |= unroll_control;
+        // `PartialCount`'s operand goes last, as its bit is above all the others.
+        operands.extend(unroll_operand);
+    }
+    LoopIterationsDecoration::remove_all(&mut output);
     UnrollLoopsDecoration::remove_all(&mut output);
 
     let mut output = if opts.structurize {
         let _timer = sess.timer("link_structurize");
         if opts.use_new_structurizer {
-            new_structurizer::structurize(output, unroll_loops_decorations)
+            new_structurizer::structurize(output, loop_controls)
         } else {
-            structurizer::structurize(sess, output, unroll_loops_decorations)
+            structurizer::structurize(sess, output, loop_controls)
         }
     } else {
         output
@@ -236,6 +259,16 @@ pub fn link(sess: &Session, mut inputs: Vec<Module>, opts: &Options) -> Result<M
             }
         }
     }
+    {
+        let _timer = sess.timer("link_propagate_non_uniform");
+        simple_passes::propagate_non_uniform(&mut output);
+    }
+
+    {
+        let _timer = sess.timer("link_use_physical_storage_buffer_addresses");
+        simple_passes::use_physical_storage_buffer_addresses(&mut output);
+    }
+
     {
         let _timer = sess.timer("link_sort_globals");
         simple_passes::sort_globals(&mut output);
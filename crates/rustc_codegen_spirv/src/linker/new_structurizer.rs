@@ -1,4 +1,3 @@
-use crate::decorations::UnrollLoopsDecoration;
 use indexmap::{indexmap, IndexMap};
 use rspirv::dr::{Block, Builder, Function, InsertPoint, Module, Operand};
 use rspirv::spirv::{LoopControl, Op, SelectionControl, Word};
@@ -33,7 +32,7 @@ impl FuncBuilder<'_> {
 
 pub fn structurize(
     module: Module,
-    unroll_loops_decorations: HashMap<Word, UnrollLoopsDecoration>,
+    loop_controls: HashMap<Word, (LoopControl, Vec<Operand>)>,
 ) -> Module {
     let mut builder = Builder::new_from_module(module);
 
@@ -45,10 +44,10 @@ pub fn structurize(
 
         let func_id = func.function().def_id().unwrap();
 
-        let loop_control = match unroll_loops_decorations.get(&func_id) {
-            Some(UnrollLoopsDecoration {}) => LoopControl::UNROLL,
-            None => LoopControl::NONE,
-        };
+        let loop_control = loop_controls
+            .get(&func_id)
+            .cloned()
+            .unwrap_or((LoopControl::NONE, vec![]));
 
         let block_id_to_idx = func
             .blocks()
@@ -102,9 +101,10 @@ struct Structurizer<'a> {
     func: FuncBuilder<'a>,
     block_id_to_idx: HashMap<BlockId, BlockIdx>,
 
-    /// `LoopControl` to use in all loops' `OpLoopMerge` instruction.
-    /// Currently only affected by function-scoped `#[spirv(unroll_loops)]`.
-    loop_control: LoopControl,
+    /// `LoopControl` (and its literal operands) to use in all loops'
+    /// `OpLoopMerge` instruction. Currently only affected by function-scoped
+    /// `#[spirv(unroll_loops)]` (and its variants) and `#[spirv(loop(...))]`.
+    loop_control: (LoopControl, Vec<Operand>),
 
     /// Number of edges pointing to each block.
     /// Computed by `post_order` and updated when structuring loops
@@ -122,7 +122,12 @@ impl Structurizer<'_> {
             let block_id = self.func.blocks()[block].label_id().unwrap();
             let terminator = self.func.blocks()[block].instructions.last().unwrap();
             let mut region = match terminator.class.opcode {
-                Op::Return | Op::ReturnValue | Op::Kill | Op::Unreachable => Region {
+                Op::Return
+                | Op::ReturnValue
+                | Op::Kill
+                | Op::Unreachable
+                | Op::IgnoreIntersectionKHR
+                | Op::TerminateRayKHR => Region {
                     merge: block,
                     merge_id: block_id,
                     exits: indexmap! {},
@@ -329,8 +334,8 @@ impl Structurizer<'_> {
                     .loop_merge(
                         while_exit_block_id,
                         while_body_merge_id,
-                        self.loop_control,
-                        iter::empty(),
+                        self.loop_control.0,
+                        self.loop_control.1.iter().cloned(),
                     )
                     .unwrap();
                 self.func
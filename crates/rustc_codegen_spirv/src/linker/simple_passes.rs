@@ -1,5 +1,6 @@
-use rspirv::dr::{Block, Function, Module};
-use rspirv::spirv::{Op, Word};
+use super::id;
+use rspirv::dr::{Block, Function, Instruction, Module, Operand};
+use rspirv::spirv::{AddressingModel, Capability, Decoration, Op, Scope, StorageClass, Word};
 use std::collections::{HashMap, HashSet};
 use std::iter::once;
 use std::mem::replace;
@@ -98,7 +99,12 @@ pub fn outgoing_edges(block: &Block) -> Vec<Word> {
                     .map(|op| op.unwrap_id_ref()),
             )
             .collect(),
-        Op::Return | Op::ReturnValue | Op::Kill | Op::Unreachable => Vec::new(),
+        Op::Return
+        | Op::ReturnValue
+        | Op::Kill
+        | Op::Unreachable
+        | Op::IgnoreIntersectionKHR
+        | Op::TerminateRayKHR => Vec::new(),
         _ => panic!("Invalid block terminator: {:?}", terminator),
     }
 }
@@ -135,3 +141,269 @@ pub fn sort_globals(module: &mut Module) {
     // have a function declaration without a body in a fully linked module?
     module.functions.sort_by_key(|f| !f.blocks.is_empty());
 }
+
+/// Atomics are emitted with `Device` scope, as the storage class of their pointer is only known
+/// after specialization. Those on `Workgroup` memory can't be observed outside the workgroup, so
+/// this narrows their scope to `Workgroup`.
+pub fn narrow_workgroup_atomic_scopes(module: &mut Module) {
+    let workgroup_pointers: HashSet<Word> = module
+        .types_global_values
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::TypePointer
+                && inst.operands[0].unwrap_storage_class() == StorageClass::Workgroup
+        })
+        .map(|inst| inst.result_id.unwrap())
+        .collect();
+    // Maps each `Device` scope constant to the type of that constant.
+    let device_scopes: HashMap<Word, Word> = module
+        .types_global_values
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Constant
+                && inst.operands[0] == Operand::LiteralInt32(Scope::Device as u32)
+        })
+        .map(|inst| (inst.result_id.unwrap(), inst.result_type.unwrap()))
+        .collect();
+    if workgroup_pointers.is_empty() || device_scopes.is_empty() {
+        return;
+    }
+    let value_types: HashMap<Word, Word> = module
+        .all_inst_iter()
+        .filter_map(|inst| Some((inst.result_id?, inst.result_type?)))
+        .collect();
+
+    let mut workgroup_scopes = HashMap::new();
+    let header = module.header.as_mut().unwrap();
+    let mut new_constants = Vec::new();
+    for inst in module
+        .functions
+        .iter_mut()
+        .flat_map(|func| &mut func.blocks)
+        .flat_map(|block| &mut block.instructions)
+    {
+        let is_atomic = matches!(
+            inst.class.opcode,
+            Op::AtomicLoad
+                | Op::AtomicStore
+                | Op::AtomicExchange
+                | Op::AtomicCompareExchange
+                | Op::AtomicCompareExchangeWeak
+                | Op::AtomicIIncrement
+                | Op::AtomicIDecrement
+                | Op::AtomicIAdd
+                | Op::AtomicISub
+                | Op::AtomicSMin
+                | Op::AtomicUMin
+                | Op::AtomicSMax
+                | Op::AtomicUMax
+                | Op::AtomicAnd
+                | Op::AtomicOr
+                | Op::AtomicXor
+                | Op::AtomicFAddEXT
+        );
+        if !is_atomic {
+            continue;
+        }
+        let pointer_ty = value_types.get(&inst.operands[0].unwrap_id_ref());
+        if !pointer_ty.map_or(false, |ty| workgroup_pointers.contains(ty)) {
+            continue;
+        }
+        if let Some(&scope_ty) = device_scopes.get(&inst.operands[1].unwrap_id_ref()) {
+            let workgroup_scope = *workgroup_scopes.entry(scope_ty).or_insert_with(|| {
+                let result_id = id(header);
+                new_constants.push(Instruction::new(
+                    Op::Constant,
+                    Some(scope_ty),
+                    Some(result_id),
+                    vec![Operand::LiteralInt32(Scope::Workgroup as u32)],
+                ));
+                result_id
+            });
+            inst.operands[1] = Operand::IdRef(workgroup_scope);
+        }
+    }
+    module.types_global_values.extend(new_constants);
+}
+
+/// Resources accessed through a non-uniform descriptor index have to be decorated `NonUniform` at
+/// the point of access, not just where the descriptor array is indexed. This decorates every
+/// pointer, image and sampled image derived from an already `NonUniform` value.
+///
+/// Requires functions' blocks to be in RPO order (i.e. `block_ordering_pass`).
+pub fn propagate_non_uniform(module: &mut Module) {
+    let mut non_uniform: HashSet<Word> = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Decorate
+                && inst.operands[1] == Operand::Decoration(Decoration::NonUniform)
+        })
+        .map(|inst| inst.operands[0].unwrap_id_ref())
+        .collect();
+    if non_uniform.is_empty() {
+        return;
+    }
+    let mut new_decorations = Vec::new();
+    for inst in module
+        .functions
+        .iter()
+        .flat_map(|func| &func.blocks)
+        .flat_map(|block| &block.instructions)
+    {
+        let is_derived = matches!(
+            inst.class.opcode,
+            Op::AccessChain
+                | Op::InBoundsAccessChain
+                | Op::PtrAccessChain
+                | Op::InBoundsPtrAccessChain
+                | Op::CopyObject
+                | Op::Load
+                | Op::SampledImage
+                | Op::Image
+        ) && non_uniform.contains(&inst.operands[0].unwrap_id_ref());
+        if !is_derived {
+            continue;
+        }
+        let result_id = inst.result_id.unwrap();
+        if non_uniform.insert(result_id) {
+            new_decorations.push(Instruction::new(
+                Op::Decorate,
+                None,
+                None,
+                vec![
+                    Operand::IdRef(result_id),
+                    Operand::Decoration(Decoration::NonUniform),
+                ],
+            ));
+        }
+    }
+    module.annotations.extend(new_decorations);
+}
+
+/// Module-scope variables can also be declared by `asm!` (e.g. the builtins read by
+/// `spirv_std::arch`), so the interface of each entry point is completed here with the
+/// variables used by any function it (transitively) calls.
+pub fn complete_entry_point_interfaces(module: &mut Module) {
+    // SPIR-V <= v1.3 only includes Input and Output in the interface.
+    let all_storage_classes = module.header.as_ref().unwrap().version() > (1, 3);
+    let interface_variables: HashSet<Word> = module
+        .types_global_values
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Variable
+                && match inst.operands[0].unwrap_storage_class() {
+                    StorageClass::Input | StorageClass::Output => true,
+                    StorageClass::Function => false,
+                    _ => all_storage_classes,
+                }
+        })
+        .map(|inst| inst.result_id.unwrap())
+        .collect();
+    if interface_variables.is_empty() {
+        return;
+    }
+    let functions: HashMap<Word, &Function> = module
+        .functions
+        .iter()
+        .map(|func| (func.def_id().unwrap(), func))
+        .collect();
+    for entry in &mut module.entry_points {
+        let mut interface: HashSet<Word> = entry.operands[3..]
+            .iter()
+            .map(|op| op.unwrap_id_ref())
+            .collect();
+        let mut visited = HashSet::new();
+        let mut stack = vec![entry.operands[1].unwrap_id_ref()];
+        while let Some(func_id) = stack.pop() {
+            if !visited.insert(func_id) {
+                continue;
+            }
+            let func = match functions.get(&func_id) {
+                Some(func) => func,
+                None => continue,
+            };
+            for inst in func.all_inst_iter() {
+                if inst.class.opcode == Op::FunctionCall {
+                    stack.push(inst.operands[0].unwrap_id_ref());
+                }
+                for op in &inst.operands {
+                    if let Some(id) = op.id_ref_any() {
+                        if interface_variables.contains(&id) && interface.insert(id) {
+                            entry.operands.push(Operand::IdRef(id));
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// Pointers into `PhysicalStorageBuffer` memory (e.g. from `spirv_std::arch::from_u64`) are only
+/// valid with the `PhysicalStorageBuffer64` addressing model, and every variable and function
+/// parameter holding one has to state whether it aliases. This switches the addressing model
+/// over, and conservatively decorates those variables `AliasedPointer` and parameters `Aliased`.
+pub fn use_physical_storage_buffer_addresses(module: &mut Module) {
+    let uses_physical_storage_buffer = module.capabilities.iter().any(|inst| {
+        inst.operands[0] == Operand::Capability(Capability::PhysicalStorageBufferAddresses)
+    });
+    if !uses_physical_storage_buffer {
+        return;
+    }
+    if let Some(memory_model) = &mut module.memory_model {
+        if memory_model.operands[0] == Operand::AddressingModel(AddressingModel::Logical) {
+            memory_model.operands[0] =
+                Operand::AddressingModel(AddressingModel::PhysicalStorageBuffer64);
+        }
+    }
+
+    let mut physical_pointers = HashSet::new();
+    let mut pointers_to_physical_pointers = HashSet::new();
+    for inst in &module.types_global_values {
+        if inst.class.opcode != Op::TypePointer {
+            continue;
+        }
+        if inst.operands[0] == Operand::StorageClass(StorageClass::PhysicalStorageBuffer) {
+            physical_pointers.insert(inst.result_id.unwrap());
+        } else if physical_pointers.contains(&inst.operands[1].unwrap_id_ref()) {
+            pointers_to_physical_pointers.insert(inst.result_id.unwrap());
+        }
+    }
+    let already_decorated: HashSet<Word> = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Decorate
+                && matches!(
+                    inst.operands[1].unwrap_decoration(),
+                    Decoration::AliasedPointer
+                        | Decoration::RestrictPointer
+                        | Decoration::Aliased
+                        | Decoration::Restrict
+                )
+        })
+        .map(|inst| inst.operands[0].unwrap_id_ref())
+        .collect();
+    let mut new_decorations = Vec::new();
+    for inst in module.all_inst_iter() {
+        let decoration = match (inst.class.opcode, inst.result_type) {
+            (Op::Variable, Some(ty)) if pointers_to_physical_pointers.contains(&ty) => {
+                Decoration::AliasedPointer
+            }
+            (Op::FunctionParameter, Some(ty)) if physical_pointers.contains(&ty) => {
+                Decoration::Aliased
+            }
+            _ => continue,
+        };
+        let result_id = inst.result_id.unwrap();
+        if !already_decorated.contains(&result_id) {
+            new_decorations.push(Instruction::new(
+                Op::Decorate,
+                None,
+                None,
+                vec![Operand::IdRef(result_id), Operand::Decoration(decoration)],
+            ));
+        }
+    }
+    module.annotations.extend(new_decorations);
+}
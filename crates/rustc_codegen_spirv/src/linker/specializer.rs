@@ -1079,6 +1079,10 @@ struct Match<'a> {
     /// `StorageClassPat::Var(i)` (currently `i` is always `0`, aka `StorageClassPat::S`).
     storage_class_var_found: SmallIntMap<[SmallVec<[InferOperand; 2]>; 1]>,
 
+    /// `storage_class_concrete_found[..]` holds all the `InferOperand`s matched by
+    /// `StorageClassPat::Concrete`, alongside the storage class they must be equal to.
+    storage_class_concrete_found: SmallVec<[(InferOperand, StorageClass); 1]>,
+
     /// `ty_var_found[i][..]` holds all the `InferOperand`s matched by
     /// `TyPat::Var(i)` (currently `i` is always `0`, aka `TyPat::T`).
     ty_var_found: SmallIntMap<[SmallVec<[InferOperand; 4]>; 1]>,
@@ -1099,6 +1103,7 @@ impl<'a> Match<'a> {
         let Match {
             ambiguous,
             storage_class_var_found,
+            storage_class_concrete_found,
             ty_var_found,
             index_composite_ty_var_found,
             ty_list_var_found,
@@ -1110,6 +1115,7 @@ impl<'a> Match<'a> {
                 .get_mut_or_default(i)
                 .extend(other_found);
         }
+        storage_class_concrete_found.extend(other.storage_class_concrete_found);
         for (i, other_found) in other.ty_var_found {
             ty_var_found.get_mut_or_default(i).extend(other_found);
         }
@@ -1130,6 +1136,7 @@ impl<'a> Match<'a> {
         let Match {
             ambiguous,
             storage_class_var_found,
+            storage_class_concrete_found,
             ty_var_found,
             index_composite_ty_var_found,
             ty_list_var_found,
@@ -1144,6 +1151,7 @@ impl<'a> Match<'a> {
                 .unwrap_or(&[]);
             self_found.retain(|x| other_found.contains(x));
         }
+        storage_class_concrete_found.retain(|x| other.storage_class_concrete_found.contains(x));
         for (i, self_found) in ty_var_found {
             let other_found = other.ty_var_found.get(i).map(|xs| &xs[..]).unwrap_or(&[]);
             self_found.retain(|x| other_found.contains(x));
@@ -1194,6 +1202,7 @@ impl<'a> Match<'a> {
             let Self {
                 ambiguous,
                 storage_class_var_found,
+                storage_class_concrete_found,
                 ty_var_found,
                 index_composite_ty_var_found,
                 ty_list_var_found,
@@ -1203,6 +1212,20 @@ impl<'a> Match<'a> {
             list.entries(debug_var_found(storage_class_var_found, &move |operand| {
                 operand.display_with_infer_cx(cx)
             }));
+            list.entries(
+                storage_class_concrete_found
+                    .iter()
+                    .map(|(operand, storage_class)| {
+                        FmtBy(move |f| {
+                            write!(
+                                f,
+                                "{} = {:?}",
+                                operand.display_with_infer_cx(cx),
+                                storage_class
+                            )
+                        })
+                    }),
+            );
             list.entries(debug_var_found(ty_var_found, &move |operand| {
                 operand.display_with_infer_cx(cx)
             }));
@@ -1276,6 +1299,12 @@ impl<'a, S: Specialization> InferCx<'a, S> {
                     .push(storage_class);
                 m
             }
+            StorageClassPat::Concrete(concrete) => {
+                let mut m = Match::default();
+                m.storage_class_concrete_found
+                    .push((storage_class, *concrete));
+                m
+            }
         }
     }
 
@@ -1789,6 +1818,7 @@ impl<'a, S: Specialization> InferCx<'a, S> {
             ambiguous: _,
 
             storage_class_var_found,
+            storage_class_concrete_found,
             ty_var_found,
             index_composite_ty_var_found,
             ty_list_var_found,
@@ -1801,6 +1831,13 @@ impl<'a, S: Specialization> InferCx<'a, S> {
             }
         }
 
+        for (found, concrete) in storage_class_concrete_found {
+            self.equate_infer_operands(
+                found,
+                InferOperand::Concrete(CopyOperand::StorageClass(concrete)),
+            )?;
+        }
+
         for (i, found) in ty_var_found {
             let mut found = found.into_iter();
             if let Some(first) = found.next() {
@@ -2010,7 +2047,13 @@ impl<'a, S: Specialization> InferCx<'a, S> {
         };
 
         // If we have some instruction signatures for `inst`, enforce them.
-        if let Some(sigs) = spirv_type_constraints::instruction_signatures(inst.class.opcode) {
+        // `OpSpecConstantOp`s are only emitted for integer arithmetic
+        // (see `Builder::spec_constant_op`), which can't constrain storage classes.
+        let sigs = match inst.class.opcode {
+            Op::SpecConstantOp => None,
+            opcode => spirv_type_constraints::instruction_signatures(opcode),
+        };
+        if let Some(sigs) = sigs {
             // HACK(eddyb) workaround for `OpFunction`, see earlier HACK commment.
             // (specifically, `type_of_result` isn't *Result Type* for `OpFunction`)
             assert_ne!(inst.class.opcode, Op::Function);
@@ -1,7 +1,6 @@
 // This pass inserts merge instructions for structured control flow with the assumption the spir-v is reducible.
 
 use super::simple_passes::outgoing_edges;
-use crate::decorations::UnrollLoopsDecoration;
 use rspirv::spirv::{Op, SelectionControl, Word};
 use rspirv::{
     dr::{Block, Builder, InsertPoint, Module, Operand},
@@ -127,7 +126,7 @@ impl ControlFlowInfo {
 pub fn structurize(
     sess: &Session,
     module: Module,
-    unroll_loops_decorations: HashMap<Word, UnrollLoopsDecoration>,
+    loop_controls: HashMap<Word, (LoopControl, Vec<Operand>)>,
 ) -> Module {
     let mut builder = Builder::new_from_module(module);
 
@@ -143,10 +142,11 @@ pub fn structurize(
             .result_id
             .unwrap();
 
-        let loop_control = match unroll_loops_decorations.get(&func_id) {
-            Some(UnrollLoopsDecoration {}) => LoopControl::UNROLL,
-            None => LoopControl::NONE,
-        };
+        let loop_control = loop_controls
+            .get(&func_id)
+            .map_or((LoopControl::NONE, &[][..]), |(loop_control, operands)| {
+                (*loop_control, operands)
+            });
 
         insert_loop_merge_on_conditional_branch(&mut builder, &mut cf_info, loop_control);
         retarget_loop_children_if_needed(&mut builder, &cf_info);
@@ -778,7 +778,7 @@ pub fn insert_selection_merge_on_conditional_branch(
 pub fn insert_loop_merge_on_conditional_branch(
     builder: &mut Builder,
     cf_info: &mut ControlFlowInfo,
-    loop_control: LoopControl,
+    (loop_control, loop_control_operands): (LoopControl, &[Operand]),
 ) {
     let mut branch_conditional_ops = Vec::new();
 
@@ -842,7 +842,7 @@ pub fn insert_loop_merge_on_conditional_branch(
                 merge_block_id,
                 continue_block_id,
                 loop_control,
-                None,
+                loop_control_operands.iter().cloned(),
             )
             .unwrap();
     }
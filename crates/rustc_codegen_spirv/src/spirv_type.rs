@@ -51,6 +51,12 @@ pub enum SpirvType {
         /// Note: vector count is literal.
         count: u32,
     },
+    /// Column-major matrix of `count` column `Vector`s.
+    Matrix {
+        element: Word,
+        /// Note: matrix column count is literal.
+        count: u32,
+    },
     Array {
         element: Word,
         /// Note: array count is ref to constant.
@@ -80,6 +86,7 @@ pub enum SpirvType {
     SampledImage {
         image_type: Word,
     },
+    AccelerationStructureKhr,
 }
 
 impl SpirvType {
@@ -118,10 +125,13 @@ impl SpirvType {
             Self::Float(width) => {
                 let result = cx.emit_global().type_float(width);
                 match width {
+                    16 if !cx.builder.has_capability(Capability::Float16) => {
+                        cx.zombie_with_span(result, def_span, "f16 without OpCapability Float16")
+                    }
                     64 if !cx.builder.has_capability(Capability::Float64) => {
                         cx.zombie_with_span(result, def_span, "f64 without OpCapability Float64")
                     }
-                    32 | 64 => (),
+                    16 | 32 | 64 => (),
                     other => cx
                         .tcx
                         .sess
@@ -138,6 +148,26 @@ impl SpirvType {
                 ref field_names,
                 is_block,
             } => {
+                // The layout of matrices (even in arrays) is decorated on the struct member.
+                let matrix_strides = field_types
+                    .iter()
+                    .enumerate()
+                    .filter_map(|(index, &field_type)| {
+                        let mut field_type = cx.lookup_type(field_type);
+                        loop {
+                            field_type = match field_type {
+                                Self::Array { element, .. } | Self::RuntimeArray { element } => {
+                                    cx.lookup_type(element)
+                                }
+                                Self::Matrix { element, .. } => {
+                                    let stride = cx.lookup_type(element).sizeof(cx)?;
+                                    return Some((index as u32, stride.bytes() as u32));
+                                }
+                                _ => return None,
+                            };
+                        }
+                    })
+                    .collect::<Vec<_>>();
                 let mut emit = cx.emit_global();
                 // Ensure a unique struct is emitted each time, due to possibly having different OpMemberDecorates
                 let id = emit.id();
@@ -155,6 +185,15 @@ impl SpirvType {
                                 .cloned(),
                         );
                     }
+                    for &(index, stride) in &matrix_strides {
+                        emit.member_decorate(result, index, Decoration::ColMajor, iter::empty());
+                        emit.member_decorate(
+                            result,
+                            index,
+                            Decoration::MatrixStride,
+                            iter::once(Operand::LiteralInt32(stride)),
+                        );
+                    }
                 }
                 if let Some(field_names) = field_names {
                     for (index, field_name) in field_names.iter().enumerate() {
@@ -168,6 +207,7 @@ impl SpirvType {
             }
             Self::Opaque { ref name } => cx.emit_global().type_opaque(name),
             Self::Vector { element, count } => cx.emit_global().type_vector(element, count),
+            Self::Matrix { element, count } => cx.emit_global().type_matrix(element, count),
             Self::Array { element, count } => {
                 // ArrayStride decoration wants in *bytes*
                 let element_size = cx
@@ -225,18 +265,89 @@ impl SpirvType {
                 sampled,
                 image_format,
                 access_qualifier,
-            } => cx.emit_global().type_image(
-                sampled_type,
-                dim,
-                depth,
-                arrayed,
-                multisampled,
-                sampled,
-                image_format,
-                access_qualifier,
-            ),
+            } => {
+                let result = cx.emit_global().type_image(
+                    sampled_type,
+                    dim,
+                    depth,
+                    arrayed,
+                    multisampled,
+                    sampled,
+                    image_format,
+                    access_qualifier,
+                );
+                // Some image kinds need an extra capability, which differs between
+                // sampled images and storage images.
+                let capability = match (dim, arrayed) {
+                    (Dim::Dim1D, _) if sampled == 2 => Some(Capability::Image1D),
+                    (Dim::Dim1D, _) => Some(Capability::Sampled1D),
+                    (Dim::DimCube, 1) if sampled == 2 => Some(Capability::ImageCubeArray),
+                    (Dim::DimCube, 1) => Some(Capability::SampledCubeArray),
+                    (Dim::DimRect, _) if sampled == 2 => Some(Capability::ImageRect),
+                    (Dim::DimRect, _) => Some(Capability::SampledRect),
+                    (Dim::DimBuffer, _) if sampled == 2 => Some(Capability::ImageBuffer),
+                    (Dim::DimBuffer, _) => Some(Capability::SampledBuffer),
+                    (Dim::DimSubpassData, _) => Some(Capability::InputAttachment),
+                    _ => None,
+                };
+                if let Some(capability) = capability {
+                    if !cx.builder.has_capability(capability) {
+                        cx.emit_global().capability(capability);
+                    }
+                }
+                // Only the most common formats are available without this capability.
+                let base_format = matches!(
+                    image_format,
+                    ImageFormat::Unknown
+                        | ImageFormat::Rgba32f
+                        | ImageFormat::Rgba16f
+                        | ImageFormat::R32f
+                        | ImageFormat::Rgba8
+                        | ImageFormat::Rgba8Snorm
+                        | ImageFormat::Rgba32i
+                        | ImageFormat::Rgba16i
+                        | ImageFormat::Rgba8i
+                        | ImageFormat::R32i
+                        | ImageFormat::Rgba32ui
+                        | ImageFormat::Rgba16ui
+                        | ImageFormat::Rgba8ui
+                        | ImageFormat::R32ui
+                        | ImageFormat::R64ui
+                        | ImageFormat::R64i
+                );
+                if !base_format
+                    && !cx
+                        .builder
+                        .has_capability(Capability::StorageImageExtendedFormats)
+                {
+                    cx.emit_global()
+                        .capability(Capability::StorageImageExtendedFormats);
+                }
+                // Storage images without a format can only be read from or written to with
+                // these capabilities. The linker removes them again if they end up unused.
+                // Input attachments never have a format, and don't need them.
+                if sampled == 2
+                    && image_format == ImageFormat::Unknown
+                    && dim != Dim::DimSubpassData
+                    && !cx.kernel_mode
+                {
+                    for capability in [
+                        Capability::StorageImageReadWithoutFormat,
+                        Capability::StorageImageWriteWithoutFormat,
+                    ]
+                    .iter()
+                    .copied()
+                    {
+                        if !cx.builder.has_capability(capability) {
+                            cx.emit_global().capability(capability);
+                        }
+                    }
+                }
+                result
+            }
             Self::Sampler => cx.emit_global().type_sampler(),
             Self::SampledImage { image_type } => cx.emit_global().type_sampled_image(image_type),
+            Self::AccelerationStructureKhr => cx.emit_global().type_acceleration_structure_khr(),
         };
         cx.type_cache.def(result, self);
         result
@@ -318,6 +429,7 @@ impl SpirvType {
             Self::Vector { element, count } => {
                 cx.lookup_type(element).sizeof(cx)? * count.next_power_of_two() as u64
             }
+            Self::Matrix { element, count } => cx.lookup_type(element).sizeof(cx)? * count as u64,
             Self::Array { element, count } => {
                 cx.lookup_type(element).sizeof(cx)? * cx.builder.lookup_const_u64(count).unwrap()
             }
@@ -325,6 +437,7 @@ impl SpirvType {
             Self::Image { .. } => Size::from_bytes(4),
             Self::Sampler => Size::from_bytes(4),
             Self::SampledImage { .. } => Size::from_bytes(4),
+            Self::AccelerationStructureKhr => Size::from_bytes(4),
         };
         Some(result)
     }
@@ -347,12 +460,14 @@ impl SpirvType {
                     .bytes(),
             )
             .expect("alignof: Vectors must have power-of-2 size"),
-            Self::Array { element, .. } => cx.lookup_type(element).alignof(cx),
-            Self::RuntimeArray { element } => cx.lookup_type(element).alignof(cx),
+            Self::Matrix { element, .. }
+            | Self::Array { element, .. }
+            | Self::RuntimeArray { element } => cx.lookup_type(element).alignof(cx),
             Self::Pointer { .. } => cx.tcx.data_layout.pointer_align.abi,
             Self::Image { .. } => Align::from_bytes(4).unwrap(),
             Self::Sampler => Align::from_bytes(4).unwrap(),
             Self::SampledImage { .. } => Align::from_bytes(4).unwrap(),
+            Self::AccelerationStructureKhr => Align::from_bytes(4).unwrap(),
         }
     }
 }
@@ -427,6 +542,12 @@ impl fmt::Debug for SpirvTypePrinter<'_, '_> {
                 .field("element", &self.cx.debug_type(element))
                 .field("count", &count)
                 .finish(),
+            SpirvType::Matrix { element, count } => f
+                .debug_struct("Matrix")
+                .field("id", &self.id)
+                .field("element", &self.cx.debug_type(element))
+                .field("count", &count)
+                .finish(),
             SpirvType::Array { element, count } => f
                 .debug_struct("Array")
                 .field("id", &self.id)
@@ -491,6 +612,10 @@ impl fmt::Debug for SpirvTypePrinter<'_, '_> {
                 .field("id", &self.id)
                 .field("image_type", &self.cx.debug_type(image_type))
                 .finish(),
+            SpirvType::AccelerationStructureKhr => f
+                .debug_struct("AccelerationStructureKhr")
+                .field("id", &self.id)
+                .finish(),
         };
         {
             let mut debug_stack = DEBUG_STACK.lock().unwrap();
@@ -588,6 +713,10 @@ impl SpirvTypePrinter<'_, '_> {
                 ty(self.cx, stack, f, element)?;
                 write!(f, "x{}", count)
             }
+            SpirvType::Matrix { element, count } => {
+                ty(self.cx, stack, f, element)?;
+                write!(f, "x{}", count)
+            }
             SpirvType::Array { element, count } => {
                 let len = self.cx.builder.lookup_const_u64(count);
                 let len = len.expect("Array type has invalid count value");
@@ -646,6 +775,7 @@ impl SpirvTypePrinter<'_, '_> {
                 .debug_struct("SampledImage")
                 .field("image_type", &self.cx.debug_type(image_type))
                 .finish(),
+            SpirvType::AccelerationStructureKhr => f.write_str("AccelerationStructureKhr"),
         }
     }
 }
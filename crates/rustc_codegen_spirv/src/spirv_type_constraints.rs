@@ -2,7 +2,8 @@
 //! or for inference purposes.
 //!
 //! Only type/storage-class equality is currently handled here, no concrete
-//! type/storage-class constraints, nor anything involving non-type/storage-class
+//! type constraints (the only concrete storage class is `Generic`, for the
+//! generic pointer casts), nor anything involving non-type/storage-class
 //! operands. While more constraints could be supported, encoding all the possible
 //! rules for them may be challenging.
 //!
@@ -20,7 +21,7 @@
 //! If the "static representation" ends up being required (for performance reasons),
 //! the "dynamic representation" could be generated from it using associated `const`s.
 
-use rspirv::spirv::Op;
+use rspirv::spirv::{Op, StorageClass};
 
 /// Helper trait to allow macros to work uniformly across different pattern types.
 trait Pat {
@@ -38,6 +39,9 @@ pub enum StorageClassPat {
     /// identical storage classes. For convenience, these associated consts are provided:
     /// * `StorageClassPat::S` for `StorageClassPat::Var(0)`
     Var(usize),
+
+    /// Concrete storage class, which must match exactly.
+    Concrete(StorageClass),
 }
 
 impl Pat for StorageClassPat {
@@ -184,6 +188,9 @@ pub fn instruction_signatures(op: Op) -> Option<&'static [InstSig<'static>]> {
     // Restrict the names the `pat!` macro can take as pattern constructors.
     mod pat_ctors {
         pub const S: super::StorageClassPat = super::StorageClassPat::S;
+        #[allow(non_upper_case_globals)]
+        pub const Generic: super::StorageClassPat =
+            super::StorageClassPat::Concrete(super::StorageClass::Generic);
         // NOTE(eddyb) it would be really nice if we could import `TyPat::{* - Any, Var}`,
         // i.e. all but those two variants.
         pub use super::TyPat::{
@@ -424,8 +431,9 @@ pub fn instruction_signatures(op: Op) -> Option<&'static [InstSig<'static>]> {
         | Op::FConvert => {}
         Op::QuantizeToF16 => sig! { (T) -> T },
         Op::ConvertPtrToU | Op::SatConvertSToU | Op::SatConvertUToS | Op::ConvertUToPtr => {}
-        Op::PtrCastToGeneric | Op::GenericCastToPtr => sig! { (Pointer(_, T)) -> Pointer(_, T) },
-        Op::GenericCastToPtrExplicit => sig! { {S} (Pointer(_, T)) -> Pointer(S, T) },
+        Op::PtrCastToGeneric => sig! { (Pointer(_, T)) -> Pointer(Generic, T) },
+        Op::GenericCastToPtr => sig! { (Pointer(Generic, T)) -> Pointer(_, T) },
+        Op::GenericCastToPtrExplicit => sig! { {S} (Pointer(Generic, T)) -> Pointer(S, T) },
         Op::Bitcast => {}
 
         // 3.37.12. Composite Instructions
@@ -722,11 +730,13 @@ pub fn instruction_signatures(op: Op) -> Option<&'static [InstSig<'static>]> {
 
         // 3.37.25. Reserved Instructions
         // SPV_KHR_ray_tracing
+        // `OpReportIntersectionKHR` shares its opcode with `OpReportIntersectionNV`.
         Op::TraceRayKHR
-        | Op::ExecuteCallableKHR
-        | Op::ConvertUToAccelerationStructureKHR
+        | Op::ReportIntersectionNV
         | Op::IgnoreIntersectionKHR
-        | Op::TerminateRayKHR => reserved!(SPV_KHR_ray_tracing),
+        | Op::TerminateRayKHR
+        | Op::ExecuteCallableKHR => {}
+        Op::ConvertUToAccelerationStructureKHR => reserved!(SPV_KHR_ray_tracing),
         // SPV_KHR_ray_query
         Op::TypeRayQueryKHR
         | Op::RayQueryInitializeKHR
@@ -742,8 +752,7 @@ pub fn instruction_signatures(op: Op) -> Option<&'static [InstSig<'static>]> {
         // SPV_NV_mesh_shader
         Op::WritePackedPrimitiveIndices4x8NV => reserved!(SPV_NV_mesh_shader),
         // SPV_NV_ray_tracing
-        Op::ReportIntersectionNV
-        | Op::IgnoreIntersectionNV
+        Op::IgnoreIntersectionNV
         | Op::TerminateRayNV
         | Op::TraceNV
         | Op::TypeAccelerationStructureNV
@@ -1,9 +1,10 @@
 use crate::builder::libm_intrinsics;
 use crate::codegen_cx::CodegenCx;
-use rspirv::spirv::{BuiltIn, ExecutionMode, ExecutionModel, StorageClass};
-use rustc_ast::ast::{AttrKind, Attribute, Lit, LitIntType, LitKind, NestedMetaItem};
+use crate::decorations::{LoopIterationsDecoration, UnrollLoopsDecoration};
+use rspirv::spirv::{BuiltIn, Decoration, ExecutionMode, ExecutionModel, StorageClass};
+use rustc_ast::ast::{AttrKind, Attribute, Lit, LitFloatType, LitIntType, LitKind, NestedMetaItem};
 use rustc_data_structures::captures::Captures;
-use rustc_span::symbol::{Ident, Symbol};
+use rustc_span::symbol::{kw, Ident, Symbol};
 use rustc_span::Span;
 use std::collections::HashMap;
 use std::rc::Rc;
@@ -32,9 +33,24 @@ pub struct Symbols {
     pub spirv14: Symbol,
     pub spirv15: Symbol,
     pub entry_point_name: Symbol,
+    pub orgin_upper_left: Symbol,
     descriptor_set: Symbol,
     binding: Symbol,
     image: Symbol,
+    layout: Symbol,
+    std140: Symbol,
+    std430: Symbol,
+    push_constant_offset: Symbol,
+    component: Symbol,
+    input_attachment_index: Symbol,
+    spec_constant: Symbol,
+    id: Symbol,
+    default: Symbol,
+    unroll_loops: Symbol,
+    count: Symbol,
+    dependency_length: Symbol,
+    min_iterations: Symbol,
+    max_iterations: Symbol,
     attributes: HashMap<Symbol, SpirvAttribute>,
     execution_modes: HashMap<Symbol, (ExecutionMode, ExecutionModeExtraDim)>,
     pub libm_intrinsics: HashMap<Symbol, libm_intrinsics::LibmIntrinsic>,
@@ -199,6 +215,12 @@ const EXECUTION_MODELS: &[(&str, ExecutionModel)] = {
         ("closest_hit_nv", ClosestHitNV),
         ("miss_nv", MissNV),
         ("callable_nv", CallableNV),
+        ("ray_generation_khr", ExecutionModel::RayGenerationKHR),
+        ("intersection_khr", ExecutionModel::IntersectionKHR),
+        ("any_hit_khr", ExecutionModel::AnyHitKHR),
+        ("closest_hit_khr", ExecutionModel::ClosestHitKHR),
+        ("miss_khr", ExecutionModel::MissKHR),
+        ("callable_khr", ExecutionModel::CallableKHR),
     ]
 };
 
@@ -223,8 +245,11 @@ const EXECUTION_MODES: &[(&str, ExecutionMode, ExecutionModeExtraDim)] = {
         ("vertex_order_cw", VertexOrderCw, None),
         ("vertex_order_ccw", VertexOrderCcw, None),
         ("pixel_center_integer", PixelCenterInteger, None),
-        ("orgin_upper_left", OriginUpperLeft, None),
+        ("origin_upper_left", OriginUpperLeft, None),
         ("origin_lower_left", OriginLowerLeft, None),
+        // Deprecated misspelling of `origin_upper_left`, still accepted so that
+        // existing shaders keep compiling (`attr.rs` warns about it).
+        ("orgin_upper_left", OriginUpperLeft, None),
         ("early_fragment_tests", EarlyFragmentTests, None),
         ("point_mode", PointMode, None),
         ("xfb", Xfb, None),
@@ -237,6 +262,7 @@ const EXECUTION_MODES: &[(&str, ExecutionMode, ExecutionModeExtraDim)] = {
         ("local_size_hint_y", LocalSizeHint, Y),
         ("local_size_hint_z", LocalSizeHint, Z),
         ("input_points", InputPoints, None),
+        ("input_triangles", Triangles, None),
         ("input_lines", InputLines, None),
         ("input_lines_adjacency", InputLinesAdjacency, None),
         ("triangles", Triangles, None),
@@ -326,15 +352,74 @@ impl Symbols {
             .map(|&(a, b)| (a, SpirvAttribute::Entry(b.into())));
         let custom_attributes = [
             ("sampler", SpirvAttribute::Sampler),
+            (
+                "acceleration_structure",
+                SpirvAttribute::AccelerationStructure,
+            ),
             ("block", SpirvAttribute::Block),
             ("flat", SpirvAttribute::Flat),
+            ("noperspective", SpirvAttribute::NoPerspective),
+            ("centroid", SpirvAttribute::Centroid),
+            ("sample", SpirvAttribute::Sample),
+            ("patch", SpirvAttribute::Patch),
+            ("invariant", SpirvAttribute::Invariant),
+            (
+                "readonly",
+                SpirvAttribute::MemoryDecoration(Decoration::NonWritable),
+            ),
+            (
+                "writeonly",
+                SpirvAttribute::MemoryDecoration(Decoration::NonReadable),
+            ),
+            (
+                "coherent",
+                SpirvAttribute::MemoryDecoration(Decoration::Coherent),
+            ),
+            (
+                "restrict",
+                SpirvAttribute::MemoryDecoration(Decoration::Restrict),
+            ),
+            (
+                "volatile",
+                SpirvAttribute::MemoryDecoration(Decoration::Volatile),
+            ),
+            (
+                "frag_depth_greater",
+                SpirvAttribute::ConservativeDepth(ExecutionMode::DepthGreater),
+            ),
+            (
+                "frag_depth_less",
+                SpirvAttribute::ConservativeDepth(ExecutionMode::DepthLess),
+            ),
+            (
+                "frag_depth_unchanged",
+                SpirvAttribute::ConservativeDepth(ExecutionMode::DepthUnchanged),
+            ),
             ("sampled_image", SpirvAttribute::SampledImage),
-            ("unroll_loops", SpirvAttribute::UnrollLoops),
+            ("f16", SpirvAttribute::F16),
+            ("matrix", SpirvAttribute::Matrix),
+            (
+                "unroll_loops",
+                SpirvAttribute::UnrollLoops(UnrollLoopsDecoration::Unroll),
+            ),
+            (
+                "dont_unroll_loops",
+                SpirvAttribute::UnrollLoops(UnrollLoopsDecoration::DontUnroll),
+            ),
+            ("no_contraction", SpirvAttribute::NoContraction),
+            ("fast_math", SpirvAttribute::FastMath),
+            ("relaxed_precision", SpirvAttribute::RelaxedPrecision),
             ("bind", SpirvAttribute::Bind),
             (
                 "index_descriptor_array",
                 SpirvAttribute::IndexDescriptorArray,
             ),
+            (
+                "index_descriptor_array_nonuniform",
+                SpirvAttribute::IndexDescriptorArrayNonUniform,
+            ),
+            ("undef", SpirvAttribute::Undef),
+            ("shader_struct", SpirvAttribute::ShaderStruct),
         ]
         .iter()
         .cloned();
@@ -365,6 +450,7 @@ impl Symbols {
             fmt_decimal: Symbol::intern("fmt_decimal"),
 
             entry_point_name: Symbol::intern("entry_point_name"),
+            orgin_upper_left: Symbol::intern("orgin_upper_left"),
             spirv: Symbol::intern("spirv"),
             spirv_std: Symbol::intern("spirv_std"),
             libm: Symbol::intern("libm"),
@@ -382,6 +468,20 @@ impl Symbols {
             descriptor_set: Symbol::intern("descriptor_set"),
             binding: Symbol::intern("binding"),
             image: Symbol::intern("image"),
+            layout: Symbol::intern("layout"),
+            std140: Symbol::intern("std140"),
+            std430: Symbol::intern("std430"),
+            push_constant_offset: Symbol::intern("push_constant_offset"),
+            component: Symbol::intern("component"),
+            input_attachment_index: Symbol::intern("input_attachment_index"),
+            spec_constant: Symbol::intern("spec_constant"),
+            id: Symbol::intern("id"),
+            default: Symbol::intern("default"),
+            unroll_loops: Symbol::intern("unroll_loops"),
+            count: Symbol::intern("count"),
+            dependency_length: Symbol::intern("dependency_length"),
+            min_iterations: Symbol::intern("min_iterations"),
+            max_iterations: Symbol::intern("max_iterations"),
             attributes,
             execution_modes,
             libm_intrinsics,
@@ -406,7 +506,7 @@ pub struct ExecutionModeExtra {
 }
 
 impl ExecutionModeExtra {
-    fn new(args: impl AsRef<[u32]>) -> Self {
+    pub fn new(args: impl AsRef<[u32]>) -> Self {
         let _args = args.as_ref();
         let mut args = [0; 3];
         args[.._args.len()].copy_from_slice(_args);
@@ -426,6 +526,9 @@ pub struct Entry {
     pub execution_model: ExecutionModel,
     pub execution_modes: Vec<(ExecutionMode, ExecutionModeExtra)>,
     pub name: Option<Symbol>,
+    /// `SpecId`s of the `threads` dimensions given as `spec_constant(id = N, default = V)`,
+    /// whose defaults are in the `LocalSize` execution mode.
+    pub local_size_spec_ids: [Option<u32>; 3],
 }
 
 impl From<ExecutionModel> for Entry {
@@ -434,6 +537,7 @@ impl From<ExecutionModel> for Entry {
             execution_model,
             execution_modes: Vec::new(),
             name: None,
+            local_size_spec_ids: [None; 3],
         }
     }
 }
@@ -448,12 +552,70 @@ pub enum SpirvAttribute {
     Binding(u32),
     Image,
     Sampler,
+    AccelerationStructure,
     SampledImage,
+    /// `OpTypeFloat 16`, for a `u16` newtype holding the bits of a half-precision float.
+    F16,
+    /// `OpTypeMatrix`, for a struct of column vectors.
+    Matrix,
     Block,
     Flat,
-    UnrollLoops,
+    NoPerspective,
+    Centroid,
+    /// `Sample` interpolation, which makes fragment shaders run once per sample.
+    Sample,
+    Patch,
+    /// `Invariant` on an output, whose value must be computed the same way by every
+    /// entry point writing it.
+    Invariant,
+    /// `FragDepth` output with one of the `Depth{Greater,Less,Unchanged}` execution modes.
+    ConservativeDepth(ExecutionMode),
+    /// Memory access qualifier of a buffer or image, e.g. `NonWritable` for `readonly`.
+    MemoryDecoration(Decoration),
+    UnrollLoops(UnrollLoopsDecoration),
+    /// `loop(max_iterations = N, ...)`, bounding the iterations of a function's loops.
+    LoopIterations(LoopIterationsDecoration),
+    /// `NoContraction` on all floating-point arithmetic in a function.
+    NoContraction,
+    /// `FPFastMathMode` on all floating-point arithmetic in a function (kernels only).
+    FastMath,
+    /// `RelaxedPrecision` on an entry-point parameter, or on all floating-point
+    /// arithmetic in a function.
+    RelaxedPrecision,
     Bind,
     IndexDescriptorArray,
+    IndexDescriptorArrayNonUniform,
+    /// Calls to the function are replaced with an `OpUndef` of its return type.
+    Undef,
+    /// Marks the `ShaderStruct` trait, whose implementors get their block layout checked.
+    ShaderStruct,
+    Layout(BlockLayout),
+    PushConstantOffset(u32),
+    /// First component of its location used by an `Input`/`Output` parameter.
+    Component(u32),
+    /// Index of the subpass input attachment an `InputAttachment` parameter reads.
+    InputAttachmentIndex(u32),
+    SpecConstant {
+        id: u32,
+        /// Zero of the parameter's type if omitted.
+        default: Option<SpecConstantValue>,
+    },
+}
+
+/// Default value of a `#[spirv(spec_constant(id = N, default = V))]`.
+#[derive(Copy, Clone, Debug, PartialEq)]
+pub enum SpecConstantValue {
+    Int(u32),
+    Float(f32),
+    Bool(bool),
+}
+
+/// Memory layout rules of a `Uniform`/`StorageBuffer`/`PushConstant` block, as selected by
+/// `#[spirv(layout = "std140")]` or `#[spirv(layout = "std430")]`.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum BlockLayout {
+    Std140,
+    Std430,
 }
 
 // FIXME(eddyb) maybe move this to `attr`?
@@ -520,6 +682,23 @@ pub(crate) fn parse_attrs_for_checking<'a>(
                     SpirvAttribute::DescriptorSet(parse_attr_int_value(arg)?)
                 } else if arg.has_name(sym.binding) {
                     SpirvAttribute::Binding(parse_attr_int_value(arg)?)
+                } else if arg.has_name(sym.layout) {
+                    SpirvAttribute::Layout(parse_attr_layout_value(sym, arg)?)
+                } else if arg.has_name(sym.spec_constant) {
+                    let (id, default) = parse_spec_constant_attr(sym, arg)?;
+                    SpirvAttribute::SpecConstant { id, default }
+                } else if arg.has_name(sym.push_constant_offset) {
+                    SpirvAttribute::PushConstantOffset(parse_attr_int_value(arg)?)
+                } else if arg.has_name(sym.component) {
+                    SpirvAttribute::Component(parse_attr_int_value(arg)?)
+                } else if arg.has_name(sym.input_attachment_index) {
+                    SpirvAttribute::InputAttachmentIndex(parse_attr_int_value(arg)?)
+                } else if arg.has_name(sym.unroll_loops) && arg.meta_item_list().is_some() {
+                    SpirvAttribute::UnrollLoops(UnrollLoopsDecoration::PartialCount(
+                        parse_unroll_loops_count(sym, arg)?,
+                    ))
+                } else if arg.has_name(kw::Loop) {
+                    SpirvAttribute::LoopIterations(parse_loop_iterations(sym, arg)?)
                 } else {
                     let name = match arg.ident() {
                         Some(i) => i,
@@ -565,7 +744,137 @@ fn parse_attr_int_value(arg: &NestedMetaItem) -> Result<u32, ParseAttrError> {
     }
 }
 
-fn parse_local_size_attr(arg: &NestedMetaItem) -> Result<[u32; 3], ParseAttrError> {
+/// Parses `unroll_loops(count = N)` into `N`, which must be at least 1.
+fn parse_unroll_loops_count(sym: &Symbols, arg: &NestedMetaItem) -> Result<u32, ParseAttrError> {
+    match arg.meta_item_list().unwrap_or_default() {
+        [item] if item.has_name(sym.count) => match parse_attr_int_value(item)? {
+            0 => Err((
+                item.span(),
+                "unroll_loops count must be at least 1".to_string(),
+            )),
+            count => Ok(count),
+        },
+        _ => Err((
+            arg.span(),
+            "unroll_loops only takes a `count = N` argument".to_string(),
+        )),
+    }
+}
+
+/// Parses `loop(dependency_length = N, min_iterations = N, max_iterations = N)`,
+/// where at least one of the arguments has to be present.
+fn parse_loop_iterations(
+    sym: &Symbols,
+    arg: &NestedMetaItem,
+) -> Result<LoopIterationsDecoration, ParseAttrError> {
+    let mut loop_iterations = LoopIterationsDecoration::default();
+    for item in arg.meta_item_list().unwrap_or_default() {
+        let field = if item.has_name(sym.dependency_length) {
+            &mut loop_iterations.dependency_length
+        } else if item.has_name(sym.min_iterations) {
+            &mut loop_iterations.min_iterations
+        } else if item.has_name(sym.max_iterations) {
+            &mut loop_iterations.max_iterations
+        } else {
+            return Err((
+                item.span(),
+                "loop only takes `dependency_length`, `min_iterations` and `max_iterations` arguments"
+                    .to_string(),
+            ));
+        };
+        if field.is_some() {
+            return Err((item.span(), "duplicate loop argument".to_string()));
+        }
+        *field = Some(parse_attr_int_value(item)?);
+    }
+    match loop_iterations {
+        LoopIterationsDecoration {
+            dependency_length: None,
+            min_iterations: None,
+            max_iterations: None,
+        } => Err((
+            arg.span(),
+            "must be `loop(max_iterations = N)`, or another of `dependency_length` and `min_iterations`"
+                .to_string(),
+        )),
+        LoopIterationsDecoration {
+            min_iterations: Some(min),
+            max_iterations: Some(max),
+            ..
+        } if min > max => Err((
+            arg.span(),
+            "loop `min_iterations` must not be greater than `max_iterations`".to_string(),
+        )),
+        _ => Ok(loop_iterations),
+    }
+}
+
+fn parse_attr_layout_value(
+    sym: &Symbols,
+    arg: &NestedMetaItem,
+) -> Result<BlockLayout, ParseAttrError> {
+    match arg.value_str() {
+        Some(layout) if layout == sym.std140 => Ok(BlockLayout::Std140),
+        Some(layout) if layout == sym.std430 => Ok(BlockLayout::Std430),
+        _ => Err((
+            arg.span(),
+            "layout must be either `\"std140\"` or `\"std430\"`".to_string(),
+        )),
+    }
+}
+
+/// Parses `spec_constant(id = N, default = V)` into `(N, V)`, with `default` being
+/// optional.
+fn parse_spec_constant_attr(
+    sym: &Symbols,
+    arg: &NestedMetaItem,
+) -> Result<(u32, Option<SpecConstantValue>), ParseAttrError> {
+    let mut id = None;
+    let mut default = None;
+    for item in arg.meta_item_list().unwrap_or_default() {
+        if item.has_name(sym.id) {
+            id = Some(parse_attr_int_value(item)?);
+        } else if item.has_name(sym.default) {
+            default = Some(parse_spec_constant_value(item)?);
+        } else {
+            return Err((
+                item.span(),
+                "spec_constant only takes `id` and `default` arguments".to_string(),
+            ));
+        }
+    }
+    match id {
+        Some(id) => Ok((id, default)),
+        None => Err((
+            arg.span(),
+            "must be `spec_constant(id = N, default = V)`".to_string(),
+        )),
+    }
+}
+
+fn parse_spec_constant_value(arg: &NestedMetaItem) -> Result<SpecConstantValue, ParseAttrError> {
+    let lit = arg.meta_item().and_then(|arg| arg.name_value_literal());
+    match lit.map(|lit| &lit.kind) {
+        Some(&LitKind::Int(x, LitIntType::Unsuffixed)) if x <= u32::MAX as u128 => {
+            Ok(SpecConstantValue::Int(x as u32))
+        }
+        Some(&LitKind::Float(x, LitFloatType::Unsuffixed)) => x
+            .as_str()
+            .parse()
+            .map(SpecConstantValue::Float)
+            .map_err(|_| (arg.span(), "invalid float literal".to_string())),
+        Some(&LitKind::Bool(x)) => Ok(SpecConstantValue::Bool(x)),
+        _ => Err((
+            arg.span(),
+            "spec constant default must be an integer, float or bool literal".to_string(),
+        )),
+    }
+}
+
+fn parse_local_size_attr(
+    sym: &Symbols,
+    arg: &NestedMetaItem,
+) -> Result<([u32; 3], [Option<u32>; 3]), ParseAttrError> {
     let arg = match arg.meta_item() {
         Some(arg) => arg,
         None => return Err((arg.span(), "attribute must have value".to_string())),
@@ -573,16 +882,39 @@ fn parse_local_size_attr(arg: &NestedMetaItem) -> Result<[u32; 3], ParseAttrErro
     match arg.meta_item_list() {
         Some(tuple) if !tuple.is_empty() && tuple.len() < 4 => {
             let mut local_size = [1; 3];
+            let mut spec_ids = [None; 3];
             for (idx, lit) in tuple.iter().enumerate() {
                 match lit.literal() {
                     Some(&Lit {
                         kind: LitKind::Int(x, LitIntType::Unsuffixed),
                         ..
                     }) if x <= u32::MAX as u128 => local_size[idx] = x as u32,
-                    _ => return Err((lit.span(), "must be a u32 literal".to_string())),
+                    None if lit.has_name(sym.spec_constant) => {
+                        match parse_spec_constant_attr(sym, lit)? {
+                            (id, None) => spec_ids[idx] = Some(id),
+                            (id, Some(SpecConstantValue::Int(default))) => {
+                                spec_ids[idx] = Some(id);
+                                local_size[idx] = default;
+                            }
+                            _ => {
+                                return Err((
+                                    lit.span(),
+                                    "work group size spec constants must have an integer default"
+                                        .to_string(),
+                                ))
+                            }
+                        }
+                    }
+                    _ => {
+                        return Err((
+                            lit.span(),
+                            "must be a u32 literal or `spec_constant(id = N, default = V)`"
+                                .to_string(),
+                        ))
+                    }
                 }
             }
-            Ok(local_size)
+            Ok((local_size, spec_ids))
         }
         Some(tuple) if tuple.is_empty() => Err((
             arg.span,
@@ -629,11 +961,33 @@ fn parse_entry_attrs(
                     };
                     match execution_mode {
                         OriginUpperLeft | OriginLowerLeft => {
-                            origin_mode.replace(*execution_mode);
+                            if execution_model != Fragment {
+                                return Err((
+                                    attr_name.span,
+                                    format!(
+                                        "`{}` is only valid on `#[spirv(fragment)]` entry points",
+                                        attr_name.name.to_ident_string()
+                                    ),
+                                ));
+                            }
+                            match origin_mode.replace(*execution_mode) {
+                                Some(previous) if previous != *execution_mode => {
+                                    return Err((
+                                        attr_name.span,
+                                        String::from(
+                                            "`origin_upper_left` and `origin_lower_left` \
+                                             conflict, only one of them may be specified",
+                                        ),
+                                    ));
+                                }
+                                _ => {}
+                            }
                         }
                         LocalSize => {
                             if local_size.is_none() {
-                                local_size.replace(parse_local_size_attr(attr)?);
+                                let (size, spec_ids) = parse_local_size_attr(sym, attr)?;
+                                local_size.replace(size);
+                                entry.local_size_spec_ids = spec_ids;
                             } else {
                                 return Err((
                                     attr_name.span,
@@ -740,17 +1094,11 @@ fn parse_entry_attrs(
                 .push((origin_mode, ExecutionModeExtra::new([])));
         }
         GLCompute => {
+            // NOTE: a missing `threads` is reported by `CodegenCx::entry_stub`.
             if let Some(local_size) = local_size {
                 entry
                     .execution_modes
                     .push((LocalSize, ExecutionModeExtra::new(local_size)));
-            } else {
-                return Err((
-                    arg.span(),
-                    String::from(
-                        "The `threads` argument must be specified when using `#[spirv(compute)]`",
-                    ),
-                ));
             }
         }
         Kernel => {
@@ -769,6 +1117,68 @@ fn parse_entry_attrs(
                 entry.execution_modes.push((MaxWorkgroupSizeINTEL, ExecutionModeExtra::new(max_workgroup_size_intel)));
             }*/
         }
+        Geometry => {
+            let count_modes = |modes: &[ExecutionMode]| {
+                entry
+                    .execution_modes
+                    .iter()
+                    .filter(|(execution_mode, _)| modes.contains(execution_mode))
+                    .count()
+            };
+            let input_primitives = count_modes(&[
+                InputPoints,
+                InputLines,
+                InputLinesAdjacency,
+                Triangles,
+                InputTrianglesAdjacency,
+            ]);
+            let output_primitives =
+                count_modes(&[OutputPoints, OutputLineStrip, OutputTriangleStrip]);
+            if input_primitives != 1
+                || output_primitives != 1
+                || count_modes(&[OutputVertices]) != 1
+            {
+                return Err((
+                    arg.span(),
+                    String::from(
+                        "`#[spirv(geometry(..))]` requires exactly one input primitive \
+                         (e.g. `input_triangles`), one output primitive \
+                         (e.g. `output_triangle_strip`) and `output_vertices = N`",
+                    ),
+                ));
+            }
+        }
+        TessellationControl | TessellationEvaluation => {
+            let count_modes = |modes: &[ExecutionMode]| {
+                entry
+                    .execution_modes
+                    .iter()
+                    .filter(|(execution_mode, _)| modes.contains(execution_mode))
+                    .count()
+            };
+            if entry.execution_model == TessellationControl && count_modes(&[OutputVertices]) != 1 {
+                return Err((
+                    arg.span(),
+                    String::from(
+                        "`#[spirv(tessellation_control(..))]` requires `output_vertices = N`",
+                    ),
+                ));
+            }
+            let exclusive_groups: [&[ExecutionMode]; 3] = [
+                &[Triangles, Quads, Isolines],
+                &[SpacingEqual, SpacingFractionalEven, SpacingFractionalOdd],
+                &[VertexOrderCw, VertexOrderCcw],
+            ];
+            if exclusive_groups.iter().any(|&group| count_modes(group) > 1) {
+                return Err((
+                    arg.span(),
+                    String::from(
+                        "tessellation shaders can only specify one primitive type \
+                         (`triangles`/`quads`/`isolines`), one spacing and one vertex order",
+                    ),
+                ));
+            }
+        }
         //TODO: Cover more defaults
         _ => {}
     }
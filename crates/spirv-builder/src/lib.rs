@@ -88,6 +88,8 @@ pub struct SpirvBuilder {
     release: bool,
     spirv_version: Option<(u8, u8)>,
     memory_model: Option<MemoryModel>,
+    max_compute_workgroup_invocations: Option<u32>,
+    strict_block_layout: bool,
 }
 impl SpirvBuilder {
     pub fn new(path_to_crate: impl AsRef<Path>) -> Self {
@@ -97,6 +99,8 @@ impl SpirvBuilder {
             release: true,
             spirv_version: None,
             memory_model: None,
+            max_compute_workgroup_invocations: None,
+            strict_block_layout: false,
         }
     }
 
@@ -124,6 +128,22 @@ impl SpirvBuilder {
         self
     }
 
+    /// Sets the largest number of invocations a compute shader's local work group may have,
+    /// which should be the lowest `maxComputeWorkGroupInvocations` of the devices the module
+    /// targets. Defaults to 128, the minimum that Vulkan guarantees.
+    pub fn max_compute_workgroup_invocations(mut self, max: u32) -> Self {
+        self.max_compute_workgroup_invocations = Some(max);
+        self
+    }
+
+    /// Blocks are always decorated with the std140 or std430 layout the host side expects.
+    /// When enabled, their Rust types have to be laid out that way too, so the host can upload
+    /// values of the same types as-is. Defaults to false.
+    pub fn strict_block_layout(mut self, v: bool) -> Self {
+        self.strict_block_layout = v;
+        self
+    }
+
     /// Builds the module. Returns the path to the built spir-v file. If `print_metadata` is true,
     /// you usually don't have to inspect the path, as the environment variable will already be
     /// set.
@@ -199,10 +219,19 @@ fn invoke_rustc(builder: &SpirvBuilder) -> Result<PathBuf, SpirvBuilderError> {
     } else {
         format!(" -C target-feature={}", target_features.join(","))
     };
+    // these must match codegen_cx/mod.rs
+    let mut llvm_args = match builder.max_compute_workgroup_invocations {
+        Some(max) => format!(" -C llvm-args=--max-compute-workgroup-invocations={}", max),
+        None => String::new(),
+    };
+    if builder.strict_block_layout {
+        llvm_args.push_str(" -C llvm-args=--strict-block-layout");
+    }
     let rustflags = format!(
-        "-Z codegen-backend={} -Z symbol-mangling-version=v0{}",
+        "-Z codegen-backend={} -Z symbol-mangling-version=v0{}{}",
         rustc_codegen_spirv.display(),
         feature_flag,
+        llvm_args,
     );
     let mut cargo = Command::new("cargo");
     cargo.args(&[
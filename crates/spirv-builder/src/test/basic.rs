@@ -1,4 +1,12 @@
-use super::{dis_fn, dis_globals, val, val_vulkan};
+use super::{
+    build, builtins_vulkan, descriptors_vulkan, dis_fn, dis_globals, ext_insts_vulkan, global_lock,
+    load_vulkan, matrix_strides_vulkan, member_names_vulkan, member_offsets_vulkan, ops_vulkan,
+    read_module, val, val_vulkan,
+};
+use rustc_codegen_spirv::rspirv::dr::Operand;
+use rustc_codegen_spirv::rspirv::spirv::{
+    Capability, Decoration, ExecutionMode, LoopControl, Op, StorageClass,
+};
 use std::ffi::OsStr;
 
 struct SetEnvVar<'a> {
@@ -39,6 +47,52 @@ OpName %2 "test_project::main"
     );
 }
 
+// Fragment entry points default to `OriginUpperLeft`. Vulkan doesn't allow `OriginLowerLeft`,
+// so this only runs the baseline validation.
+#[test]
+fn fragment_origins() {
+    let _lock = global_lock();
+    let module = read_module(&build(
+        r#"
+#[spirv(fragment)]
+pub fn default_origin() { }
+
+#[spirv(fragment(origin_upper_left))]
+pub fn upper_left() { }
+
+#[spirv(fragment(origin_lower_left))]
+pub fn lower_left() { }
+"#,
+    ))
+    .unwrap();
+    let execution_modes = |name: &str| {
+        let entry_point = module
+            .entry_points
+            .iter()
+            .find(|inst| inst.operands[2] == Operand::LiteralString(name.to_string()))
+            .unwrap_or_else(|| panic!("no entry point `{}`", name));
+        let id = entry_point.operands[1].unwrap_id_ref();
+        module
+            .execution_modes
+            .iter()
+            .filter(|inst| inst.operands[0].unwrap_id_ref() == id)
+            .map(|inst| inst.operands[1].unwrap_execution_mode())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(
+        execution_modes("default_origin"),
+        [ExecutionMode::OriginUpperLeft]
+    );
+    assert_eq!(
+        execution_modes("upper_left"),
+        [ExecutionMode::OriginUpperLeft]
+    );
+    assert_eq!(
+        execution_modes("lower_left"),
+        [ExecutionMode::OriginLowerLeft]
+    );
+}
+
 #[test]
 // blocked on: https://github.com/EmbarkStudios/rust-gpu/issues/69
 #[ignore]
@@ -74,6 +128,51 @@ OpFunctionEnd"#,
     );
 }
 
+#[test]
+fn zero_extend_into_signed() {
+    dis_fn(
+        r#"
+fn zero_extend(x: u32) -> i64 {
+    x as i64
+}
+#[spirv(fragment)]
+pub fn main() {
+    zero_extend(7);
+}
+"#,
+        "zero_extend",
+        r#"%1 = OpFunction %2 None %3
+%4 = OpFunctionParameter %5
+%6 = OpLabel
+%7 = OpUConvert %8 %4
+%9 = OpBitcast %2 %7
+OpReturnValue %9
+OpFunctionEnd"#,
+    );
+}
+
+#[test]
+fn sign_extend_into_unsigned() {
+    dis_fn(
+        r#"
+fn sign_extend(x: i32) -> u64 {
+    x as u64
+}
+#[spirv(fragment)]
+pub fn main() {
+    sign_extend(-7);
+}
+"#,
+        "sign_extend",
+        r#"%1 = OpFunction %2 None %3
+%4 = OpFunctionParameter %5
+%6 = OpLabel
+%7 = OpSConvert %2 %4
+OpReturnValue %7
+OpFunctionEnd"#,
+    );
+}
+
 #[test]
 fn asm() {
     dis_fn(
@@ -223,6 +322,845 @@ pub fn main(constants: PushConstant<ShaderConstants>) {
     );
 }
 
+#[test]
+fn push_constant_offset_vulkan() {
+    val_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block, push_constant_offset = 16)]
+pub struct FragmentConstants {
+    pub time: f32,
+    pub scale: f32,
+}
+
+#[spirv(fragment)]
+pub fn main(constants: PushConstant<FragmentConstants>) {
+    let _time = constants.time;
+}
+"#,
+    );
+}
+
+// Vulkan validation checks that `Uniform` blocks follow the std140 layout rules.
+#[test]
+fn uniform_array_std140_vulkan() {
+    val_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[repr(C, align(16))]
+pub struct Padded {
+    pub value: f32,
+}
+
+#[derive(Copy, Clone)]
+#[spirv(block)]
+pub struct Constants {
+    pub values: [Padded; 4],
+}
+
+#[spirv(fragment)]
+pub fn main(constants: Bind<Uniform<Constants>, 0, 0>) {
+    let _value = constants.values[1].value;
+}
+"#,
+    );
+}
+
+// A tightly packed Rust array is still decorated with the 16 byte stride of std140,
+// which moves the members after it to where the host puts them.
+#[test]
+fn uniform_packed_array_std140_vulkan() {
+    member_offsets_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block)]
+pub struct Constants {
+    pub values: [f32; 4],
+    pub scale: f32,
+}
+
+#[spirv(fragment)]
+pub fn main(constants: Bind<Uniform<Constants>, 0, 0>) {
+    let _value = constants.values[1] * constants.scale;
+}
+"#,
+        &[("values", 0), ("scale", 64)],
+    );
+}
+
+#[test]
+fn storage_buffer_array_std430_vulkan() {
+    val_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [f32; 4],
+}
+
+#[spirv(fragment)]
+pub fn main(data: Bind<StorageBuffer<Data>, 0, 0>) {
+    let _value = data.values[1];
+}
+"#,
+    );
+}
+
+#[test]
+fn runtime_descriptor_array_vulkan() {
+    val_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block)]
+pub struct ShaderConstants {
+    pub index: u32,
+    pub len: u32,
+}
+
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [f32; 4],
+}
+
+#[spirv(fragment)]
+pub fn main(
+    constants: PushConstant<ShaderConstants>,
+    data: Bind<[StorageBuffer<Data>], 0, 0>,
+) {
+    let _value = data.index_clamped(constants.index as usize, constants.len as usize).values[1];
+}
+"#,
+    );
+}
+
+#[test]
+fn runtime_descriptor_array_nonuniform_vulkan() {
+    val_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [f32; 4],
+}
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(flat)] index: Input<u32>,
+    data: Bind<[StorageBuffer<Data>], 0, 0>,
+) {
+    let _value = data.index_nonuniform(*index as usize).values[1];
+}
+"#,
+    );
+}
+
+// A `#[spirv(workgroup)]` static declared inside the entry point becomes a
+// module-scope `Workgroup` variable, without an initializer.
+#[test]
+fn workgroup_static_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [f32; 64],
+}
+
+#[spirv(compute(threads(64)))]
+pub fn main(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    mut output: Bind<StorageBuffer<Data>, 0, 0>,
+) {
+    #[spirv(workgroup)]
+    static mut TILE: [f32; 64] = [0.0; 64];
+
+    let index = *index as usize;
+    unsafe {
+        TILE[index] = index as f32;
+        spirv_std::arch::workgroup_barrier();
+        output.deref_mut().values[index] = TILE[63 - index];
+    }
+}
+"#,
+    );
+    let workgroup_variables: Vec<_> = module
+        .types_global_values
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Variable
+                && inst.operands[0] == Operand::StorageClass(StorageClass::Workgroup)
+        })
+        .collect();
+    assert_eq!(workgroup_variables.len(), 1);
+    assert_eq!(workgroup_variables[0].operands.len(), 1);
+}
+
+// Inferred locations skip over every location taken up by the previous parameter,
+// i.e. one per matrix column and array element.
+#[test]
+fn inferred_locations_after_matrix_vulkan() {
+    let module = load_vulkan(
+        r#"
+use spirv_std::matrix::Mat4;
+
+#[spirv(vertex)]
+pub fn main(
+    position: Input<glam::Vec4>,
+    mut out_transform: Output<Mat4<glam::Vec4>>,
+    mut out_color: Output<glam::Vec4>,
+    mut out_weights: Output<[f32; 2]>,
+    mut out_uv: Output<glam::Vec2>,
+) {
+    *out_transform = Mat4::from_cols(*position, *position, *position, *position);
+    *out_color = *position;
+    *out_weights = [position.x, position.y];
+    *out_uv = glam::Vec2::new(position.z, position.w);
+}
+"#,
+    );
+    let mut locations: Vec<_> = module
+        .annotations
+        .iter()
+        .filter(|inst| inst.operands[1] == Operand::Decoration(Decoration::Location))
+        .map(|inst| inst.operands[2].unwrap_literal_int32())
+        .collect();
+    locations.sort_unstable();
+    // The input at location 0, then the outputs.
+    assert_eq!(locations, [0, 0, 4, 5, 7]);
+}
+
+// Integer fragment shader inputs are `Flat` without `#[spirv(flat)]`, which Vulkan requires.
+#[test]
+fn implicit_flat_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(index: Input<u32>, uv: Input<glam::Vec2>, mut output: Output<glam::Vec2>) {
+    *output = *uv * *index as f32;
+}
+"#,
+    );
+    let flat = module
+        .annotations
+        .iter()
+        .filter(|inst| inst.operands[1] == Operand::Decoration(Decoration::Flat))
+        .count();
+    assert_eq!(flat, 1);
+}
+
+// A `#[spirv(uniform_constant)]` struct carrying its `Set` and `Binding` numbers
+// is bound directly, without `Bind`.
+#[test]
+fn uniform_constant_descriptor_set_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(uniform_constant)]
+pub struct Bound<'a, T, const SET: usize, const BINDING: usize> {
+    value: &'a T,
+}
+
+#[spirv(fragment)]
+pub fn main(
+    image: Bound<Image2d, 2, 1>,
+    sampler: Bound<Sampler, 2, 0>,
+    mut output: Output<glam::Vec4>,
+) {
+    *output = image.value.sample(*sampler.value, glam::Vec2::new(0.0, 1.0));
+}
+"#,
+    );
+    let mut bindings: Vec<_> = module
+        .annotations
+        .iter()
+        .filter(|inst| inst.operands[1] == Operand::Decoration(Decoration::Binding))
+        .map(|inst| inst.operands[2].unwrap_literal_int32())
+        .collect();
+    bindings.sort_unstable();
+    assert_eq!(bindings, [0, 1]);
+    assert!(module.annotations.iter().all(|inst| {
+        inst.operands[1] != Operand::Decoration(Decoration::DescriptorSet)
+            || inst.operands[2] == Operand::LiteralInt32(2)
+    }));
+}
+
+// Arrays of samplers and images are indexed like any other descriptor array, and
+// indexing them non-uniformly needs `SampledImageArrayNonUniformIndexing` too.
+#[test]
+fn sampler_image_descriptor_array_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(flat)] index: Input<u32>,
+    samplers: Bind<[UniformConstant<Sampler>; 2], 1, 0>,
+    images: Bind<[UniformConstant<Image2d>; 4], 1, 1>,
+    mut output: Output<glam::Vec4>,
+) {
+    let image = images.index_nonuniform(*index as usize);
+    *output = image.sample(samplers[1], glam::Vec2::new(0.0, 1.0));
+}
+"#,
+    );
+    let capabilities: Vec<_> = module
+        .capabilities
+        .iter()
+        .map(|inst| inst.operands[0].unwrap_capability())
+        .collect();
+    assert!(capabilities.contains(&Capability::ShaderNonUniform));
+    assert!(capabilities.contains(&Capability::SampledImageArrayNonUniformIndexing));
+}
+
+// The length of a block's trailing runtime array comes from `OpArrayLength`, for
+// single descriptors and for each element of a descriptor array alike.
+#[test]
+fn runtime_array_len_vulkan() {
+    val_vulkan(
+        r#"
+#[spirv(block, layout = "std430")]
+pub struct Lights {
+    pub count: u32,
+    pub intensities: [f32],
+}
+
+#[spirv(compute(threads(64)))]
+pub fn main(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    lights: Bind<StorageBuffer<Lights>, 0, 0>,
+    shadows: Bind<[StorageBuffer<Lights>; 2], 0, 1>,
+) {
+    let index = *index as usize;
+    if index < lights.intensities.len() {
+        let _intensity = lights.intensities[index];
+    }
+    let shadow = &shadows[1];
+    if index < shadow.intensities.len() {
+        let _intensity = shadow.intensities[index];
+    }
+}
+"#,
+    );
+}
+
+// 8-bit and 16-bit members keep their width, with the storage capabilities
+// matching the storage class of the block they're in.
+#[test]
+fn narrow_buffer_members_vulkan() {
+    val_vulkan(
+        r#"
+#[spirv(block, layout = "std430")]
+pub struct Bytes {
+    pub data: [u8],
+}
+
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std140")]
+pub struct Halves {
+    pub lo: u16,
+    pub hi: i16,
+}
+
+#[spirv(compute(threads(64)))]
+pub fn main(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    bytes: Bind<StorageBuffer<Bytes>, 0, 0>,
+    halves: Bind<Uniform<Halves>, 0, 1>,
+) {
+    let index = *index as usize;
+    if index < bytes.data.len() {
+        let _byte = bytes.data[index];
+    }
+    let _halves = halves.lo as i32 + halves.hi as i32;
+}
+"#,
+    );
+}
+
+static MATRIX_BLOCK_SRC: &str = r#"
+use spirv_std::matrix::{Mat3, Mat4};
+
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std140")]
+pub struct Transforms {
+    pub model: Mat4<glam::Vec4>,
+    pub normal: Mat3<glam::Vec3>,
+    pub tint: glam::Vec4,
+}
+
+#[spirv(vertex)]
+pub fn main(
+    position: Input<glam::Vec4>,
+    normal: Input<glam::Vec3>,
+    transforms: Bind<Uniform<Transforms>, 0, 0>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    mut out_normal: Output<glam::Vec3>,
+) {
+    *out_position = (transforms.model * transforms.model.transpose()) * *position;
+    *out_normal = transforms.normal * *normal + transforms.normal.left_mul(*normal);
+}
+"#;
+
+// Matrices in blocks match the layout of glam's `Mat4` on the host: column-major,
+// with each column taking up 16 bytes (padding the columns of a `Mat3`).
+#[test]
+fn matrix_block_layout_vulkan() {
+    member_offsets_vulkan(
+        MATRIX_BLOCK_SRC,
+        &[("model", 0), ("normal", 64), ("tint", 112)],
+    );
+    matrix_strides_vulkan(MATRIX_BLOCK_SRC, &[("model", 16), ("normal", 16)]);
+}
+
+// `dot` must be the `OpDot` instruction, not multiplies and adds of components.
+#[test]
+fn float_vector_dot_vulkan() {
+    ops_vulkan(
+        r#"
+use spirv_std::vector::FloatVector;
+
+fn lambert<V: FloatVector<f32, 3>>(normal: V, light: V) -> f32 {
+    normal.normalize().dot(light.cross(normal)) / light.length()
+}
+
+#[spirv(fragment)]
+pub fn main(normal: Input<glam::Vec3>, light: Input<glam::Vec3>, mut out: Output<f32>) {
+    *out = lambert(*normal, *light);
+}
+"#,
+        &[Op::Dot, Op::ExtInst],
+        &[Op::FMul, Op::FAdd],
+    );
+}
+
+// `select` must be an `OpSelect`, even where `if` would branch.
+#[test]
+fn select_without_branch_vulkan() {
+    ops_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(#[spirv(flat)] x: Input<u32>, mut out: Output<u32>) {
+    *out = arch::select(*x > 4, *x / 3, *x / 5);
+}
+"#,
+        &[Op::Select],
+        &[Op::BranchConditional],
+    );
+}
+
+// `FloatMath` methods must each be a `GLSL.std.450` instruction, not `libm`'s software versions.
+#[test]
+fn float_math_ext_inst_vulkan() {
+    ext_insts_vulkan(
+        r#"
+use spirv_std::vector::FloatMath;
+
+fn wrap<V: FloatMath<f32, 2>>(uv: V) -> V {
+    uv.fract().floor().ceil()
+}
+
+// Generic, as `num_traits::Float` (imported for all tests) has the same methods for `f32`.
+fn quantize<F: FloatMath<f32, 1>>(t: F) -> [F; 3] {
+    [t.round(), t.trunc(), t.signum()]
+}
+
+#[spirv(fragment)]
+pub fn main(uv: Input<glam::Vec2>, t: Input<f32>, mut out: Output<glam::Vec2>, mut out_t: Output<f32>) {
+    *out = wrap(*uv);
+    let [round, trunc, signum] = quantize(*t);
+    *out_t = round + trunc + signum;
+}
+"#,
+        // `Round`, `Trunc`, `FSign`, `Floor`, `Ceil` and `Fract`.
+        &[1, 3, 6, 8, 9, 10],
+    );
+}
+
+// `min`, `max` and `clamp` must pick the `GLSL.std.450` instruction for their element type.
+#[test]
+fn min_max_clamp_float_vulkan() {
+    ext_insts_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(x: Input<glam::Vec2>, y: Input<f32>, mut out: Output<glam::Vec2>, mut out_y: Output<f32>) {
+    *out = arch::clamp(arch::min(*x, glam::Vec2::new(1.0, 2.0)), glam::Vec2::splat(0.0), *x);
+    *out_y = arch::max(*y, 0.5);
+}
+"#,
+        // `FMin`, `FMax` and `FClamp`.
+        &[37, 40, 43],
+    );
+}
+
+#[test]
+fn min_max_clamp_unsigned_vulkan() {
+    ext_insts_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(#[spirv(flat)] x: Input<glam::UVec2>, #[spirv(flat)] y: Input<u32>, mut out: Output<glam::UVec2>, mut out_y: Output<u32>) {
+    *out = arch::clamp(arch::min(*x, glam::UVec2::new(1, 2)), glam::UVec2::splat(0), *x);
+    *out_y = arch::max(*y, 5);
+}
+"#,
+        // `UMin`, `UMax` and `UClamp`.
+        &[38, 41, 44],
+    );
+}
+
+#[test]
+fn min_max_clamp_signed_vulkan() {
+    ext_insts_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(#[spirv(flat)] x: Input<glam::IVec2>, #[spirv(flat)] y: Input<i32>, mut out: Output<glam::IVec2>, mut out_y: Output<i32>) {
+    *out = arch::clamp(arch::min(*x, glam::IVec2::new(1, 2)), glam::IVec2::new(-1, -1), *x);
+    *out_y = arch::max(*y, -5);
+}
+"#,
+        // `SMin`, `SMax` and `SClamp`.
+        &[39, 42, 45],
+    );
+}
+
+#[test]
+fn mix_step_smoothstep_vulkan() {
+    ext_insts_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(x: Input<glam::Vec3>, mut out: Output<glam::Vec3>) {
+    let edge = glam::Vec3::splat(0.5);
+    *out = arch::fmix(arch::step(edge, *x), *x, arch::smoothstep(glam::Vec3::splat(0.0), edge, *x));
+}
+"#,
+        // `Step`, `FMix` and `SmoothStep`.
+        &[46, 48, 49],
+    );
+}
+
+// Entry points declaring the same spec constant share a single `OpSpecConstant`, and omitted
+// defaults are the zero of the parameter's type.
+#[test]
+fn shared_spec_constants_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main_a(
+    #[spirv(spec_constant(id = 0, default = 0.5))] scale: f32,
+    #[spirv(spec_constant(id = 1))] enabled: bool,
+    mut output: Output<f32>,
+) {
+    *output = if enabled { scale } else { 0.0 };
+}
+
+#[spirv(fragment)]
+pub fn main_b(
+    #[spirv(spec_constant(id = 0, default = 0.5))] scale: f32,
+    #[spirv(spec_constant(id = 2))] bias: f32,
+    mut output: Output<f32>,
+) {
+    *output = scale + bias;
+}
+"#,
+    );
+    let count_ops = |op| {
+        module
+            .types_global_values
+            .iter()
+            .filter(|inst| inst.class.opcode == op)
+            .count()
+    };
+    assert_eq!(count_ops(Op::SpecConstant), 2);
+    assert_eq!(count_ops(Op::SpecConstantFalse), 1);
+    let spec_ids = module
+        .annotations
+        .iter()
+        .filter(|inst| inst.operands[1] == Operand::Decoration(Decoration::SpecId))
+        .count();
+    assert_eq!(spec_ids, 3);
+}
+
+// Reading a spec constant static yields its `OpSpecConstant`, defaulting to the initializer,
+// and integer arithmetic on it is computed by `OpSpecConstantOp`s.
+#[test]
+fn spec_constant_static_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(spec_constant(id = 0))]
+static SIZE: u32 = 64;
+
+#[spirv(fragment)]
+pub fn main(mut output: Output<u32>) {
+    *output = SIZE * 2 + 1;
+}
+"#,
+    );
+    let operands = |op| {
+        module
+            .types_global_values
+            .iter()
+            .filter(|inst| inst.class.opcode == op)
+            .map(|inst| inst.operands[0].clone())
+            .collect::<Vec<_>>()
+    };
+    assert_eq!(operands(Op::SpecConstant), [Operand::LiteralInt32(64)]);
+    assert_eq!(
+        operands(Op::SpecConstantOp),
+        [
+            Operand::LiteralSpecConstantOpInteger(Op::IMul),
+            Operand::LiteralSpecConstantOpInteger(Op::IAdd),
+        ]
+    );
+}
+
+// The result of sampling starts out as an `OpUndef`, instead of a zero vector
+// stored into it only to be overwritten.
+#[test]
+fn sample_result_uninit_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    uv: Input<glam::Vec2>,
+    mut output: Output<glam::Vec4>,
+) {
+    *output = image.sample(*sampler, *uv);
+}
+"#,
+    );
+    let ops: Vec<_> = module
+        .types_global_values
+        .iter()
+        .chain(
+            module
+                .functions
+                .iter()
+                .flat_map(|func| func.all_inst_iter()),
+        )
+        .map(|inst| inst.class.opcode)
+        .collect();
+    assert!(ops.contains(&Op::ImageSampleImplicitLod));
+    assert!(!ops.contains(&Op::ConstantNull));
+    assert!(!ops.contains(&Op::ConstantComposite));
+}
+
+// `sample_by_lod` hands its `lod` to `asm!` through a pointer, but it gets inlined (it takes a
+// pointer to the image), after which mem2reg turns a constant level of detail into a constant
+// `Lod` operand, without a variable to load it from.
+#[test]
+fn sample_by_lod_constant_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::Vec4>,
+) {
+    *output = image.sample_by_lod(*sampler, glam::Vec2::new(0.0, 1.0), 0.0);
+}
+"#,
+    );
+    let sample = module
+        .functions
+        .iter()
+        .flat_map(|func| func.all_inst_iter())
+        .find(|inst| inst.class.opcode == Op::ImageSampleExplicitLod)
+        .expect("no `OpImageSampleExplicitLod`");
+    // Sampled image, coordinate, image operands, and the `Lod` operand.
+    let lod = sample.operands[3].unwrap_id_ref();
+    assert!(
+        module
+            .types_global_values
+            .iter()
+            .any(|inst| inst.class.opcode == Op::Constant && inst.result_id == Some(lod)),
+        "`Lod` operand %{} is not a constant",
+        lod
+    );
+}
+
+#[test]
+fn compute_builtins_vulkan() {
+    builtins_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [u32; 64],
+}
+
+#[spirv(compute(threads(8, 8)))]
+pub fn main(
+    #[spirv(global_invocation_id)] global_invocation_id: Input<glam::UVec3>,
+    #[spirv(local_invocation_id)] local_invocation_id: Input<glam::UVec3>,
+    #[spirv(workgroup_id)] workgroup_id: Input<glam::UVec3>,
+    #[spirv(num_workgroups)] num_workgroups: Input<glam::UVec3>,
+    #[spirv(local_invocation_index)] local_invocation_index: Input<u32>,
+    mut data: Bind<StorageBuffer<Data>, 0, 0>,
+) {
+    let index = *local_invocation_index as usize;
+    unsafe { data.deref_mut() }.values[index] = global_invocation_id.x
+        + local_invocation_id.y
+        + workgroup_id.z
+        + num_workgroups.x;
+}
+"#,
+        &[
+            "GlobalInvocationId Input",
+            "LocalInvocationId Input",
+            "WorkgroupId Input",
+            "NumWorkgroups Input",
+            "LocalInvocationIndex Input",
+        ],
+    );
+}
+
+#[test]
+fn shared_descriptors_vulkan() {
+    descriptors_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [u32; 64],
+}
+
+#[spirv(compute(threads(64)))]
+pub fn clear(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    mut data: Bind<StorageBuffer<Data>, 0, 0>,
+) {
+    unsafe { data.deref_mut() }.values[*index as usize] = 0;
+}
+
+#[spirv(compute(threads(64)))]
+pub fn double(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    mut values: Bind<StorageBuffer<Data>, 0, 0>,
+    mut other: Bind<StorageBuffer<Data>, 0, 1>,
+) {
+    let index = *index as usize;
+    unsafe { values.deref_mut() }.values[index] *= 2;
+    unsafe { other.deref_mut() }.values[index] *= 2;
+}
+"#,
+        &["0 0 StorageBuffer", "0 1 StorageBuffer"],
+    );
+}
+
+#[test]
+fn block_member_names() {
+    member_names_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+pub struct Light {
+    pub intensity: f32,
+    pub range: f32,
+}
+
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Params {
+    pub light: Light,
+    pub exposure: f32,
+}
+
+#[spirv(compute(threads(1)))]
+pub fn main(mut params: Bind<StorageBuffer<Params>, 0, 0>) {
+    let params = unsafe { params.deref_mut() };
+    params.exposure = params.light.intensity * params.light.range;
+}
+"#,
+        &["light", "exposure", "intensity", "range"],
+    );
+}
+
+// Nested structs get their own member offsets, relative to themselves, and are
+// aligned to their most aligned member.
+#[test]
+fn nested_block_offsets() {
+    member_offsets_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct Falloff {
+    pub start: f32,
+    pub end: f32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+pub struct Light {
+    pub intensity: f32,
+    pub falloff: Falloff,
+    pub mask: u64,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+#[spirv(block, layout = "std430")]
+pub struct Scene {
+    pub count: u32,
+    pub light: Light,
+    pub exposure: f32,
+}
+
+#[spirv(compute(threads(1)))]
+pub fn main(mut scene: Bind<StorageBuffer<Scene>, 0, 0>) {
+    let scene = unsafe { scene.deref_mut() };
+    let light = scene.light;
+    scene.exposure = light.intensity * (light.falloff.end - light.falloff.start);
+    scene.count = light.mask as u32;
+}
+"#,
+        &[
+            ("count", 0),
+            ("light", 8),
+            ("exposure", 32),
+            ("intensity", 0),
+            ("falloff", 4),
+            ("mask", 16),
+            ("start", 0),
+            ("end", 4),
+        ],
+    );
+}
+
+// std140 aligns nested structs to 16 bytes, which they have to ask for in Rust.
+#[test]
+fn nested_uniform_std140_vulkan() {
+    val_vulkan(
+        r#"
+#[derive(Copy, Clone)]
+#[repr(C, align(16))]
+pub struct Falloff {
+    pub start: f32,
+    pub end: f32,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C, align(16))]
+pub struct Light {
+    pub intensity: f32,
+    pub falloff: Falloff,
+}
+
+#[derive(Copy, Clone)]
+#[repr(C)]
+#[spirv(block)]
+pub struct Constants {
+    pub count: u32,
+    pub light: Light,
+    pub exposure: f32,
+}
+
+#[spirv(fragment)]
+pub fn main(constants: Bind<Uniform<Constants>, 0, 0>) {
+    let _range = constants.light.falloff.end - constants.light.falloff.start;
+}
+"#,
+    );
+}
+
 #[test]
 fn unroll_loops() {
     dis_fn(
@@ -283,6 +1221,125 @@ OpFunctionEnd"#,
     );
 }
 
+#[test]
+fn dont_unroll_loops_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(dont_unroll_loops)]
+fn java_hash_ten_times(mut x: u32, y: u32) -> u32 {
+    let mut i = 0;
+    while i < 10 {
+        x = 31 * x + y;
+        i += 1;
+    }
+    x
+}
+#[spirv(fragment)]
+pub fn main(mut out: Output<u32>) {
+    *out = java_hash_ten_times(7, 42);
+}
+"#,
+    );
+    let loop_controls = module
+        .functions
+        .iter()
+        .flat_map(|func| func.all_inst_iter())
+        .filter(|inst| inst.class.opcode == Op::LoopMerge)
+        .map(|inst| match inst.operands[2] {
+            Operand::LoopControl(loop_control) => loop_control,
+            ref operand => panic!("expected a loop control, found {:?}", operand),
+        })
+        .collect::<Vec<_>>();
+    assert!(!loop_controls.is_empty(), "no `OpLoopMerge`");
+    for loop_control in loop_controls {
+        assert_eq!(loop_control, LoopControl::DONT_UNROLL);
+    }
+}
+
+#[test]
+fn no_contraction_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(no_contraction)]
+fn kahan_step(sum: f32, compensation: f32, x: f32) -> (f32, f32) {
+    let y = x - compensation;
+    let t = sum + y;
+    (t, (t - sum) - y)
+}
+#[spirv(fragment)]
+pub fn main(x: Input<f32>, mut out: Output<f32>) {
+    let (sum, compensation) = kahan_step(1.0, 0.0, *x);
+    *out = sum * compensation;
+}
+"#,
+    );
+    let no_contraction = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Decorate
+                && inst.operands[1] == Operand::Decoration(Decoration::NoContraction)
+        })
+        .map(|inst| inst.operands[0].unwrap_id_ref())
+        .collect::<Vec<_>>();
+    let arithmetic = module
+        .functions
+        .iter()
+        .flat_map(|func| func.all_inst_iter())
+        .filter(|inst| matches!(inst.class.opcode, Op::FAdd | Op::FSub))
+        .map(|inst| inst.result_id.unwrap())
+        .collect::<Vec<_>>();
+    assert_eq!(arithmetic.len(), 4);
+    for id in arithmetic {
+        assert!(
+            no_contraction.contains(&id),
+            "%{} isn't `NoContraction`",
+            id
+        );
+    }
+    // The multiplication in `main` isn't affected.
+    assert_eq!(no_contraction.len(), 4);
+}
+
+#[test]
+fn relaxed_precision_vulkan() {
+    let module = load_vulkan(
+        r#"
+#[spirv(relaxed_precision)]
+fn tonemap(color: f32, exposure: f32) -> f32 {
+    let exposed = color * exposure;
+    exposed / (exposed + 1.0)
+}
+#[spirv(fragment)]
+pub fn main(#[spirv(relaxed_precision)] mut out: Output<f32>) {
+    *out = tonemap(0.5, 2.0);
+}
+"#,
+    );
+    let relaxed_precision = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Decorate
+                && inst.operands[1] == Operand::Decoration(Decoration::RelaxedPrecision)
+        })
+        .map(|inst| inst.operands[0].unwrap_id_ref())
+        .collect::<Vec<_>>();
+    let decorated = module
+        .types_global_values
+        .iter()
+        .chain(
+            module
+                .functions
+                .iter()
+                .flat_map(|func| func.all_inst_iter()),
+        )
+        .filter(|inst| relaxed_precision.contains(&inst.result_id.unwrap_or(0)))
+        .map(|inst| inst.class.opcode)
+        .collect::<Vec<_>>();
+    assert_eq!(decorated, [Op::Variable, Op::FMul, Op::FAdd, Op::FDiv]);
+}
+
 #[test]
 fn complex_image_sample_inst() {
     dis_fn(
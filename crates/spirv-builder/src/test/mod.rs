@@ -112,6 +112,273 @@ fn val_vulkan(src: &str) {
     }
 }
 
+/// Like `val_vulkan`, but also returns the validated module.
+fn load_vulkan(src: &str) -> rspirv::dr::Module {
+    use rustc_codegen_spirv::{spirv_tools_validate as validate, SpirvToolsTargetEnv as TargetEnv};
+
+    let _lock = global_lock();
+    let bytes = std::fs::read(build(src)).unwrap();
+    if let Err(e) = validate(Some(TargetEnv::Vulkan_1_2), &bytes, None) {
+        panic!("Vulkan validation failed:\n{}", e.to_string());
+    }
+
+    let mut loader = rspirv::dr::Loader::new();
+    rspirv::binary::parse_bytes(&bytes, &mut loader).unwrap();
+    loader.module()
+}
+
+/// The storage class of the global variable `id`.
+fn variable_storage_class(
+    module: &rspirv::dr::Module,
+    id: rspirv::spirv::Word,
+) -> rspirv::spirv::StorageClass {
+    let var = module
+        .types_global_values
+        .iter()
+        .find(|inst| inst.result_id == Some(id))
+        .unwrap();
+    assert_eq!(var.class.opcode, rspirv::spirv::Op::Variable);
+    var.operands[0].unwrap_storage_class()
+}
+
+/// Like `val_vulkan`, but also checks that the module's builtin variables are
+/// exactly `expect`, each given as e.g. `"GlobalInvocationId Input"`.
+fn builtins_vulkan(src: &str, expect: &[&str]) {
+    use rspirv::dr::Operand;
+    use rspirv::spirv::{Decoration, Op};
+
+    let module = load_vulkan(src);
+    let mut builtins = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Decorate
+                && matches!(inst.operands[1], Operand::Decoration(Decoration::BuiltIn))
+        })
+        .map(|inst| {
+            let storage_class = variable_storage_class(&module, inst.operands[0].unwrap_id_ref());
+            let builtin = match inst.operands[2] {
+                Operand::BuiltIn(builtin) => builtin,
+                ref operand => panic!("expected a builtin, found {:?}", operand),
+            };
+            format!("{:?} {:?}", builtin, storage_class)
+        })
+        .collect::<Vec<_>>();
+    builtins.sort();
+    let mut expect = expect.to_vec();
+    expect.sort_unstable();
+    assert_eq!(builtins, expect);
+}
+
+/// Like `val_vulkan`, but also checks that the module's descriptor variables are
+/// exactly `expect`, each given as e.g. `"0 1 StorageBuffer"` for descriptor set 0,
+/// binding 1.
+fn descriptors_vulkan(src: &str, expect: &[&str]) {
+    use rspirv::dr::Operand;
+    use rspirv::spirv::{Decoration, Op};
+
+    let module = load_vulkan(src);
+    let decoration_of = |id, decoration| {
+        module
+            .annotations
+            .iter()
+            .find(|inst| {
+                inst.class.opcode == Op::Decorate
+                    && inst.operands[0].unwrap_id_ref() == id
+                    && inst.operands[1] == Operand::Decoration(decoration)
+            })
+            .map(|inst| inst.operands[2].unwrap_literal_int32())
+    };
+    let mut descriptors = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::Decorate
+                && inst.operands[1] == Operand::Decoration(Decoration::DescriptorSet)
+        })
+        .map(|inst| {
+            let id = inst.operands[0].unwrap_id_ref();
+            format!(
+                "{} {} {:?}",
+                inst.operands[2].unwrap_literal_int32(),
+                decoration_of(id, Decoration::Binding).unwrap(),
+                variable_storage_class(&module, id)
+            )
+        })
+        .collect::<Vec<_>>();
+    descriptors.sort();
+    let mut expect = expect.to_vec();
+    expect.sort_unstable();
+    assert_eq!(descriptors, expect);
+}
+
+/// Like `val_vulkan`, but also checks that each of `expect` is the
+/// `OpMemberName` of some struct member in the module.
+fn member_names_vulkan(src: &str, expect: &[&str]) {
+    let module = load_vulkan(src);
+    let member_names = module
+        .debugs
+        .iter()
+        .filter(|inst| inst.class.opcode == rspirv::spirv::Op::MemberName)
+        .map(|inst| inst.operands[2].unwrap_literal_string())
+        .collect::<Vec<_>>();
+    for name in expect {
+        assert!(
+            member_names.contains(name),
+            "no member named `{}` in {:?}",
+            name,
+            member_names
+        );
+    }
+}
+
+/// Like `val_vulkan`, but also checks that each `(name, offset)` in `expect` is
+/// the `OpMemberName` and `Offset` of some struct member in the module.
+fn member_offsets_vulkan(src: &str, expect: &[(&str, u32)]) {
+    use rspirv::dr::Operand;
+    use rspirv::spirv::{Decoration, Op};
+
+    let module = load_vulkan(src);
+    let offsets = module
+        .annotations
+        .iter()
+        .filter(|inst| {
+            inst.class.opcode == Op::MemberDecorate
+                && inst.operands[2] == Operand::Decoration(Decoration::Offset)
+        })
+        .map(|inst| {
+            let member = (
+                inst.operands[0].unwrap_id_ref(),
+                inst.operands[1].unwrap_literal_int32(),
+            );
+            (member, inst.operands[3].unwrap_literal_int32())
+        })
+        .collect::<std::collections::HashMap<_, _>>();
+    let member_offsets = module
+        .debugs
+        .iter()
+        .filter(|inst| inst.class.opcode == Op::MemberName)
+        .filter_map(|inst| {
+            let member = (
+                inst.operands[0].unwrap_id_ref(),
+                inst.operands[1].unwrap_literal_int32(),
+            );
+            Some((
+                inst.operands[2].unwrap_literal_string(),
+                *offsets.get(&member)?,
+            ))
+        })
+        .collect::<Vec<_>>();
+    for expected in expect {
+        assert!(
+            member_offsets.contains(expected),
+            "no member `{}` at offset {} in {:?}",
+            expected.0,
+            expected.1,
+            member_offsets
+        );
+    }
+}
+
+/// Check that the named struct members are `ColMajor` matrices with the given `MatrixStride`s.
+fn matrix_strides_vulkan(src: &str, expect: &[(&str, u32)]) {
+    use rspirv::dr::Operand;
+    use rspirv::spirv::{Decoration, Op};
+
+    let module = load_vulkan(src);
+    let member_decorations = |decoration| {
+        module
+            .annotations
+            .iter()
+            .filter(move |inst| {
+                inst.class.opcode == Op::MemberDecorate
+                    && inst.operands[2] == Operand::Decoration(decoration)
+            })
+            .map(|inst| {
+                let member = (
+                    inst.operands[0].unwrap_id_ref(),
+                    inst.operands[1].unwrap_literal_int32(),
+                );
+                (
+                    member,
+                    inst.operands.get(3).map(|op| op.unwrap_literal_int32()),
+                )
+            })
+    };
+    let col_major = member_decorations(Decoration::ColMajor)
+        .map(|(member, _)| member)
+        .collect::<std::collections::HashSet<_>>();
+    let strides = member_decorations(Decoration::MatrixStride)
+        .filter(|(member, _)| col_major.contains(member))
+        .collect::<std::collections::HashMap<_, _>>();
+    let member_strides = module
+        .debugs
+        .iter()
+        .filter(|inst| inst.class.opcode == Op::MemberName)
+        .filter_map(|inst| {
+            let member = (
+                inst.operands[0].unwrap_id_ref(),
+                inst.operands[1].unwrap_literal_int32(),
+            );
+            Some((
+                inst.operands[2].unwrap_literal_string(),
+                (*strides.get(&member)?)?,
+            ))
+        })
+        .collect::<Vec<_>>();
+    for expected in expect {
+        assert!(
+            member_strides.contains(expected),
+            "no column-major matrix member `{}` with stride {} in {:?}",
+            expected.0,
+            expected.1,
+            member_strides
+        );
+    }
+}
+
+/// Check that the functions of the module contain instructions with each of the
+/// `expect`ed opcodes, and none with the `unexpected` ones.
+fn ops_vulkan(src: &str, expect: &[rspirv::spirv::Op], unexpected: &[rspirv::spirv::Op]) {
+    let module = load_vulkan(src);
+    let ops = module
+        .functions
+        .iter()
+        .flat_map(|func| func.all_inst_iter())
+        .map(|inst| inst.class.opcode)
+        .collect::<std::collections::HashSet<_>>();
+    for op in expect {
+        assert!(ops.contains(op), "no `Op{:?}` in {:?}", op, ops);
+    }
+    for op in unexpected {
+        assert!(!ops.contains(op), "unexpected `Op{:?}` in {:?}", op, ops);
+    }
+}
+
+/// Check that the `OpExtInst`s in the functions of the module are exactly the
+/// `expect`ed extended instruction numbers, ignoring order and duplicates.
+fn ext_insts_vulkan(src: &str, expect: &[u32]) {
+    use rspirv::dr::Operand;
+    use rspirv::spirv::Op;
+
+    let module = load_vulkan(src);
+    let ext_insts = module
+        .functions
+        .iter()
+        .flat_map(|func| func.all_inst_iter())
+        .filter(|inst| inst.class.opcode == Op::ExtInst)
+        .map(|inst| match inst.operands[1] {
+            Operand::LiteralExtInstInteger(ext_inst) => ext_inst,
+            ref other => panic!("expected an extended instruction number, got {:?}", other),
+        })
+        .collect::<std::collections::BTreeSet<_>>();
+    let expect = expect
+        .iter()
+        .copied()
+        .collect::<std::collections::BTreeSet<_>>();
+    assert_eq!(ext_insts, expect);
+}
+
 fn assert_str_eq(expected: &str, result: &str) {
     let expected = expected
         .split('\n')
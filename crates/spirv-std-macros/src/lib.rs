@@ -113,6 +113,47 @@ pub fn gpu_only(_attr: TokenStream, item: TokenStream) -> TokenStream {
     output.into()
 }
 
+/// Implements `spirv_std::ShaderStruct` for a `#[repr(C)]` struct, which has
+/// its layout checked against the block layout rules when compiling shaders.
+#[proc_macro_derive(ShaderStruct)]
+pub fn shader_struct(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as syn::DeriveInput);
+
+    if !matches!(input.data, syn::Data::Struct(_)) {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "`ShaderStruct` can only be derived for structs",
+        )
+        .to_compile_error()
+        .into();
+    }
+    let is_repr_c = input.attrs.iter().any(|attr| match attr.parse_meta() {
+        Ok(syn::Meta::List(list)) if list.path.is_ident("repr") => {
+            list.nested.iter().any(|meta| match meta {
+                syn::NestedMeta::Meta(syn::Meta::Path(path)) => path.is_ident("C"),
+                _ => false,
+            })
+        }
+        _ => false,
+    });
+    if !is_repr_c {
+        return syn::Error::new_spanned(
+            &input.ident,
+            "`ShaderStruct` requires `#[repr(C)]`, otherwise the host and shader layouts can differ",
+        )
+        .to_compile_error()
+        .into();
+    }
+
+    let name = &input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    let output = quote::quote! {
+        impl #impl_generics ::spirv_std::ShaderStruct for #name #ty_generics #where_clause {}
+    };
+
+    output.into()
+}
+
 /// Accepts a function with an argument named `component`, and outputs the
 /// function plus a vectorized version of the function which accepts a vector
 /// of `component`. This is mostly useful when you have the same impl body for
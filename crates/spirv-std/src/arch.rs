@@ -8,11 +8,33 @@ use crate::{scalar::Scalar, vector::Vector};
 
 #[cfg(feature = "const-generics")]
 mod arithmetic;
+mod atomics;
+mod barrier;
+mod bits;
 mod derivative;
+mod generic_pointer;
+#[cfg(feature = "const-generics")]
+mod glsl_std_450;
+mod memory;
+mod physical_storage_buffer;
+mod primitive;
+mod ray_tracing;
+mod subgroup;
 
 #[cfg(feature = "const-generics")]
 pub use arithmetic::*;
+pub use atomics::*;
+pub use barrier::*;
+pub use bits::*;
 pub use derivative::*;
+pub use generic_pointer::*;
+#[cfg(feature = "const-generics")]
+pub use glsl_std_450::*;
+pub use memory::*;
+pub use physical_storage_buffer::*;
+pub use primitive::*;
+pub use ray_tracing::*;
+pub use subgroup::*;
 
 /// Result is true if any component of `vector` is true, otherwise result is
 /// false.
@@ -82,6 +104,180 @@ pub fn all<V: Vector<bool, N>, const N: usize>(vector: V) -> bool {
     result
 }
 
+// A classification of floats, as a `bool` for a scalar, and as a vector of
+// `bool`s (e.g. glam's `BVec4`) for a vector. The instructions compute the
+// `%bools` of type `%bool_type` from the float(s) `%x`.
+macro_rules! float_class_fn {
+    ($(#[$attr:meta])* $name:ident, $vector_name:ident, $($inst:literal),+) => {
+        $(#[$attr])*
+        #[spirv_std_macros::gpu_only]
+        #[inline]
+        pub fn $name<F: crate::float::Float>(x: F) -> bool {
+            let mut result = false;
+
+            unsafe {
+                asm! {
+                    "%bool_type = OpTypeBool",
+                    "%u8 = OpTypeInt 8 0",
+                    "%u8_0 = OpConstant %u8 0",
+                    "%u8_1 = OpConstant %u8 1",
+                    "%x = OpLoad _ {x}",
+                    $($inst,)+
+                    "%result = OpSelect %u8 %bools %u8_1 %u8_0",
+                    "OpStore {result} %result",
+                    x = in(reg) &x,
+                    result = in(reg) &mut result,
+                }
+            }
+
+            result
+        }
+
+        $(#[$attr])*
+        #[spirv_std_macros::gpu_only]
+        #[inline]
+        #[cfg(feature = "const-generics")]
+        pub fn $vector_name<F, V, B, const N: usize>(x: V) -> B
+        where
+            F: crate::float::Float,
+            V: Vector<F, N>,
+            B: Vector<bool, N>,
+        {
+            let mut result = B::default();
+
+            unsafe {
+                asm! {
+                    "%bool = OpTypeBool",
+                    "%bool_type = OpTypeVector %bool {len}",
+                    "%u8 = OpTypeInt 8 0",
+                    "%glam_vec_type = OpTypeVector %u8 {len}",
+                    "%false_vec = OpConstantNull %glam_vec_type",
+                    // There's no way to splat a constant across `N` components,
+                    // but negating all bits set (i.e. -1) gives all ones.
+                    "%all_bits = OpNot %glam_vec_type %false_vec",
+                    "%true_vec = OpSNegate %glam_vec_type %all_bits",
+                    "%x = OpLoad _ {x}",
+                    $($inst,)+
+                    "%result = OpSelect %glam_vec_type %bools %true_vec %false_vec",
+                    "OpStore {result} %result",
+                    x = in(reg) &x,
+                    len = const N,
+                    result = in(reg) &mut result,
+                }
+            }
+
+            result
+        }
+    };
+}
+
+float_class_fn! {
+    /// Whether `x` is NaN, or for a vector, which of its components are.
+    ///
+    /// Under `#[spirv(fast_math)]`, the driver may assume there are no NaNs,
+    /// so this may always be false.
+    #[doc(alias = "OpIsNan")]
+    is_nan, is_nan_vector,
+    "%bools = OpIsNan %bool_type %x"
+}
+
+float_class_fn! {
+    /// Whether `x` is positive or negative infinity, or for a vector, which
+    /// of its components are.
+    ///
+    /// Under `#[spirv(fast_math)]`, the driver may assume there are no
+    /// infinities, so this may always be false.
+    #[doc(alias = "OpIsInf")]
+    is_inf, is_inf_vector,
+    "%bools = OpIsInf %bool_type %x"
+}
+
+float_class_fn! {
+    /// Whether `x` is neither NaN nor infinite, or for a vector, which of its
+    /// components are, e.g. to sanitize values read from a buffer.
+    ///
+    /// Under `#[spirv(fast_math)]`, the driver may assume there are no NaNs
+    /// or infinities, so this may always be true.
+    is_finite, is_finite_vector,
+    "%nan = OpIsNan %bool_type %x",
+    "%inf = OpIsInf %bool_type %x",
+    "%not_finite = OpLogicalOr %bool_type %nan %inf",
+    "%bools = OpLogicalNot %bool_type %not_finite"
+}
+
+/// `a` if `cond` is true, otherwise `b`, as a single `OpSelect` instead of
+/// the branch `if cond { a } else { b }` may turn into.
+///
+/// Both `a` and `b` are always evaluated. To select between vectors, see
+/// [`select_vector`], as selecting whole vectors with a single boolean
+/// requires SPIR-V 1.4.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpSelect")]
+#[inline]
+pub fn select<T: crate::scalar::Scalar>(cond: bool, a: T, b: T) -> T {
+    let mut result = T::default();
+
+    unsafe {
+        asm! {
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%cond = OpLoad %u8 {cond}",
+            "%cond_bool = OpINotEqual %bool %cond %u8_0",
+            "%a = OpLoad _ {a}",
+            "%b = OpLoad _ {b}",
+            "%result = OpSelect typeof*{result} %cond_bool %a %b",
+            "OpStore {result} %result",
+            cond = in(reg) &cond,
+            a = in(reg) &a,
+            b = in(reg) &b,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Select each component of the result from `a` where the same component of
+/// `mask` is true, otherwise from `b`, as a single `OpSelect`.
+///
+/// Use a splatted `mask` (e.g. `BVec3::splat(cond)`) to select between whole
+/// vectors.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpSelect")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn select_vector<T: Scalar, V: Vector<T, N>, B: Vector<bool, N>, const N: usize>(
+    mask: B,
+    a: V,
+    b: V,
+) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%glam_vec_type = OpTypeVector %u8 {len}",
+            "%bool_vec_type = OpTypeVector %bool {len}",
+            "%false_vec = OpConstantNull %glam_vec_type",
+            "%mask = OpLoad %glam_vec_type {mask}",
+            "%mask_bool = OpINotEqual %bool_vec_type %mask %false_vec",
+            "%a = OpLoad _ {a}",
+            "%b = OpLoad _ {b}",
+            "%result = OpSelect typeof*{result} %mask_bool %a %b",
+            "OpStore {result} %result",
+            mask = in(reg) &mask,
+            a = in(reg) &a,
+            b = in(reg) &b,
+            len = const N,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
 /// Extract a single, dynamically selected, component of a vector.
 ///
 /// # Safety
@@ -139,3 +335,18 @@ pub unsafe fn vector_insert_dynamic<T: Scalar, V: Vector<T, N>, const N: usize>(
 
     result
 }
+
+/// An unspecified value of type `T`, for a result that is about to be
+/// overwritten anyway (e.g. through `asm!`), without first storing
+/// `T::default()` into it.
+///
+/// # Safety
+/// The value is arbitrary and may differ between uses, so it must be
+/// overwritten before it's read.
+#[allow(unused_attributes)]
+#[spirv(undef)]
+#[doc(alias = "OpUndef")]
+pub unsafe fn uninit<T>() -> T {
+    //compiler implemented
+    unimplemented!()
+}
@@ -572,3 +572,29 @@ where
 
     result
 }
+
+/// Dot product of `x` and `y`, the sum of the products of their components.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpDot")]
+#[inline]
+pub fn dot<F, V, const LEN: usize>(x: V, y: V) -> F
+where
+    F: Float,
+    V: Vector<F, LEN>,
+{
+    let mut result = F::default();
+
+    unsafe {
+        asm! {
+            "%x = OpLoad _ {x}",
+            "%y = OpLoad _ {y}",
+            "%result = OpDot typeof*{result} %x %y",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            y = in(reg) &y,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
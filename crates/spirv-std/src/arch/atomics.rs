@@ -0,0 +1,315 @@
+//! Atomic operations on integers in `Workgroup`, `StorageBuffer` or other
+//! shared memory.
+//!
+//! These all use relaxed memory semantics, and `Device` scope, which the
+//! linker narrows to `Workgroup` scope when `ptr` points into `Workgroup`
+//! memory.
+
+use crate::integer::{Integer, SignedInteger, UnsignedInteger};
+
+/// Atomically replace the integer `ptr` points to with `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicExchange")]
+#[inline]
+pub fn atomic_exchange<I: Integer>(ptr: &mut I, value: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicExchange _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the integer `ptr` points to with `value`, if it's equal
+/// to `comparator`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicCompareExchange")]
+#[inline]
+pub fn atomic_compare_exchange<I: Integer>(ptr: &mut I, value: I, comparator: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%comparator = OpLoad _ {comparator}",
+            "%result = OpAtomicCompareExchange _ {ptr} %scope %semantics %semantics %value %comparator",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            comparator = in(reg) &comparator,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically add `value` to the integer `ptr` points to.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicIAdd")]
+#[inline]
+pub fn atomic_i_add<I: Integer>(ptr: &mut I, value: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicIAdd _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically subtract `value` from the integer `ptr` points to.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicISub")]
+#[inline]
+pub fn atomic_i_sub<I: Integer>(ptr: &mut I, value: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicISub _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the signed integer `ptr` points to with the smaller of
+/// it and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicSMin")]
+#[inline]
+pub fn atomic_s_min<S: SignedInteger>(ptr: &mut S, value: S) -> S {
+    let mut result = S::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicSMin _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the unsigned integer `ptr` points to with the smaller
+/// of it and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicUMin")]
+#[inline]
+pub fn atomic_u_min<U: UnsignedInteger>(ptr: &mut U, value: U) -> U {
+    let mut result = U::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicUMin _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the signed integer `ptr` points to with the larger of
+/// it and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicSMax")]
+#[inline]
+pub fn atomic_s_max<S: SignedInteger>(ptr: &mut S, value: S) -> S {
+    let mut result = S::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicSMax _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the unsigned integer `ptr` points to with the larger
+/// of it and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicUMax")]
+#[inline]
+pub fn atomic_u_max<U: UnsignedInteger>(ptr: &mut U, value: U) -> U {
+    let mut result = U::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicUMax _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the integer `ptr` points to with the bitwise AND of it
+/// and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicAnd")]
+#[inline]
+pub fn atomic_and<I: Integer>(ptr: &mut I, value: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicAnd _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the integer `ptr` points to with the bitwise OR of it
+/// and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicOr")]
+#[inline]
+pub fn atomic_or<I: Integer>(ptr: &mut I, value: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicOr _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Atomically replace the integer `ptr` points to with the bitwise XOR of it
+/// and `value`.
+///
+/// Returns the original value.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpAtomicXor")]
+#[inline]
+pub fn atomic_xor<I: Integer>(ptr: &mut I, value: I) -> I {
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            // Device scope, relaxed memory semantics.
+            "%scope = OpConstant %u32 1",
+            "%semantics = OpConstant %u32 0",
+            "%value = OpLoad _ {value}",
+            "%result = OpAtomicXor _ {ptr} %scope %semantics %value",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
@@ -0,0 +1,92 @@
+//! Barriers, for synchronizing invocations and their accesses to memory.
+
+/// Scopes that an execution or memory barrier can apply to, as used by
+/// [`control_barrier`].
+pub mod scope {
+    pub const CROSS_DEVICE: u32 = 0;
+    pub const DEVICE: u32 = 1;
+    pub const WORKGROUP: u32 = 2;
+    pub const SUBGROUP: u32 = 3;
+    pub const INVOCATION: u32 = 4;
+    pub const QUEUE_FAMILY: u32 = 5;
+}
+
+/// Memory semantics flags, which can be combined with `|`, as used by
+/// [`control_barrier`].
+pub mod semantics {
+    pub const NONE: u32 = 0x0;
+    pub const ACQUIRE: u32 = 0x2;
+    pub const RELEASE: u32 = 0x4;
+    pub const ACQUIRE_RELEASE: u32 = 0x8;
+    pub const SEQUENTIALLY_CONSISTENT: u32 = 0x10;
+    pub const UNIFORM_MEMORY: u32 = 0x40;
+    pub const SUBGROUP_MEMORY: u32 = 0x80;
+    pub const WORKGROUP_MEMORY: u32 = 0x100;
+    pub const CROSS_WORKGROUP_MEMORY: u32 = 0x200;
+    pub const ATOMIC_COUNTER_MEMORY: u32 = 0x400;
+    pub const IMAGE_MEMORY: u32 = 0x800;
+}
+
+/// Wait for all active invocations within the `EXECUTION` scope to reach
+/// this point, after which memory accesses within the `MEMORY` scope are
+/// ordered as given by `SEMANTICS` (see the [`scope`] and [`semantics`]
+/// modules for the values these can take).
+///
+/// All invocations within `EXECUTION` must reach the barrier together, i.e.
+/// it must only be used in uniform control flow.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpControlBarrier")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn control_barrier<const EXECUTION: u32, const MEMORY: u32, const SEMANTICS: u32>() {
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            "%execution = OpConstant %u32 {execution}",
+            "%memory = OpConstant %u32 {memory}",
+            "%semantics = OpConstant %u32 {semantics}",
+            "OpControlBarrier %execution %memory %semantics",
+            execution = const EXECUTION,
+            memory = const MEMORY,
+            semantics = const SEMANTICS,
+        }
+    }
+}
+
+/// Wait for all invocations in the workgroup to reach this point, making
+/// their prior writes to `Workgroup` memory visible to each other, like
+/// `barrier()` in GLSL or `GroupMemoryBarrierWithGroupSync()` in HLSL.
+///
+/// It must only be used in uniform control flow.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpControlBarrier")]
+#[inline]
+pub fn workgroup_barrier() {
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            "%workgroup = OpConstant %u32 2",
+            // WorkgroupMemory | AcquireRelease
+            "%semantics = OpConstant %u32 264",
+            "OpControlBarrier %workgroup %workgroup %semantics",
+        }
+    }
+}
+
+/// Order this invocation's prior accesses to `Workgroup` memory before its
+/// following ones, as observed by the other invocations in the workgroup,
+/// without waiting for them.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpMemoryBarrier")]
+#[inline]
+pub fn memory_barrier() {
+    unsafe {
+        asm! {
+            "%u32 = OpTypeInt 32 0",
+            "%workgroup = OpConstant %u32 2",
+            // WorkgroupMemory | AcquireRelease
+            "%semantics = OpConstant %u32 264",
+            "OpMemoryBarrier %workgroup %semantics",
+        }
+    }
+}
@@ -0,0 +1,197 @@
+//! Bit manipulation instructions, on integers or vectors of integers.
+use crate::integer::Integer;
+#[cfg(feature = "const-generics")]
+use crate::vector::Vector;
+
+/// The number of bits set in each component of `value`.
+///
+/// Equivalent to `count_ones`, which already lowers to this instruction.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpBitCount")]
+#[doc(alias = "count_ones")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn bit_count<I: Integer, V: Vector<I, N>, const N: usize>(value: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%value = OpLoad _ {value}",
+            "%result = OpBitCount typeof*{result} %value",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The bits of each component of `value` in reverse order.
+///
+/// Equivalent to `reverse_bits`, which already lowers to this instruction.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpBitReverse")]
+#[doc(alias = "reverse_bits")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn bit_reverse<I: Integer, V: Vector<I, N>, const N: usize>(value: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%value = OpLoad _ {value}",
+            "%result = OpBitReverse typeof*{result} %value",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The bit number of the least significant set bit of each component of
+/// `value`, or `-1` (all bits set) for components that are zero.
+///
+/// Only 32-bit integers are supported.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "FindILsb")]
+#[doc(alias = "trailing_zeros")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn find_lsb<I: Integer, V: Vector<I, N>, const N: usize>(value: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%value = OpLoad _ {value}",
+            "%result = OpExtInst typeof*{result} %glsl 73 %value",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The bit number of the most significant bit of each component of `value`.
+///
+/// For unsigned integers, this is the most significant set bit. For signed
+/// integers, it's the most significant bit differing from the sign bit, so
+/// that negative numbers look for a clear bit instead. Components with no
+/// such bit (`0`, or `-1` when signed) give `-1` (all bits set).
+///
+/// Only 32-bit integers are supported.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "FindUMsb")]
+#[doc(alias = "FindSMsb")]
+#[doc(alias = "leading_zeros")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn find_msb<I: Integer, V: Vector<I, N>, const N: usize>(value: V) -> V {
+    let mut result = V::default();
+
+    if I::SIGNED {
+        unsafe {
+            asm! {
+                "%glsl = OpExtInstImport \"GLSL.std.450\"",
+                "%value = OpLoad _ {value}",
+                "%result = OpExtInst typeof*{result} %glsl 74 %value",
+                "OpStore {result} %result",
+                value = in(reg) &value,
+                result = in(reg) &mut result,
+            }
+        }
+    } else {
+        unsafe {
+            asm! {
+                "%glsl = OpExtInstImport \"GLSL.std.450\"",
+                "%value = OpLoad _ {value}",
+                "%result = OpExtInst typeof*{result} %glsl 75 %value",
+                "OpStore {result} %result",
+                value = in(reg) &value,
+                result = in(reg) &mut result,
+            }
+        }
+    }
+
+    result
+}
+
+/// Extract `count` bits of `base`, starting at bit number `offset`, into the
+/// least significant bits of the result.
+///
+/// The remaining bits of the result are filled with the most significant
+/// extracted bit for signed integers, and with zeros for unsigned ones. The
+/// result is undefined if `offset + count` is greater than the bit width of
+/// `I`, which is checked in debug builds.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpBitFieldSExtract")]
+#[doc(alias = "OpBitFieldUExtract")]
+#[inline]
+pub fn bitfield_extract<I: Integer>(base: I, offset: u32, count: u32) -> I {
+    debug_assert!(offset <= I::WIDTH as u32 && count <= I::WIDTH as u32 - offset);
+
+    let mut result = I::default();
+
+    if I::SIGNED {
+        unsafe {
+            asm! {
+                "%base = OpLoad _ {base}",
+                "%result = OpBitFieldSExtract typeof*{result} %base {offset} {count}",
+                "OpStore {result} %result",
+                base = in(reg) &base,
+                offset = in(reg) offset,
+                count = in(reg) count,
+                result = in(reg) &mut result,
+            }
+        }
+    } else {
+        unsafe {
+            asm! {
+                "%base = OpLoad _ {base}",
+                "%result = OpBitFieldUExtract typeof*{result} %base {offset} {count}",
+                "OpStore {result} %result",
+                base = in(reg) &base,
+                offset = in(reg) offset,
+                count = in(reg) count,
+                result = in(reg) &mut result,
+            }
+        }
+    }
+
+    result
+}
+
+/// Replace `count` bits of `base`, starting at bit number `offset`, with the
+/// least significant bits of `insert`.
+///
+/// The result is undefined if `offset + count` is greater than the bit width
+/// of `I`, which is checked in debug builds.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpBitFieldInsert")]
+#[inline]
+pub fn bitfield_insert<I: Integer>(base: I, insert: I, offset: u32, count: u32) -> I {
+    debug_assert!(offset <= I::WIDTH as u32 && count <= I::WIDTH as u32 - offset);
+
+    let mut result = I::default();
+
+    unsafe {
+        asm! {
+            "%base = OpLoad _ {base}",
+            "%insert = OpLoad _ {insert}",
+            "%result = OpBitFieldInsert typeof*{result} %base %insert {offset} {count}",
+            "OpStore {result} %result",
+            base = in(reg) &base,
+            insert = in(reg) &insert,
+            offset = in(reg) offset,
+            count = in(reg) count,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
@@ -1,5 +1,9 @@
-//! The [`Derivative`] trait for getting derivatives and handling derivative
-//! operations in SPIR-V.
+//! Screen-space derivatives of values in fragment shaders.
+//!
+//! Each function also has a `_vector` variant (e.g. `ddx_vector`) taking a
+//! vector of floats, which requires the `const-generics` feature. The
+//! `_fine` and `_coarse` variants declare the `DerivativeControl`
+//! capability.
 use crate::float::Float;
 
 #[cfg(target_arch = "spirv")]
@@ -28,88 +32,88 @@ macro_rules! deriv_fn {
     };
 }
 
-/// Returns the partial derivative of `Self` with respect to the window's
+/// Returns the partial derivative of `component` with respect to the window's
 /// X coordinate. Returns the same result as either
-/// [`Self::ddx_fine`] or [`Self::ddx_coarse`], selection of which one is
+/// [`ddx_fine`] or [`ddx_coarse`], selection of which one is
 /// dependent on external factors.
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn ddx<F: Float>(component: F) -> F {
+pub fn ddx<F: Float>(component: F) -> F {
     deriv_fn!(component, OpDPdx, false)
 }
 
-/// Returns the partial derivative of `Self` with respect to the window's
-/// X coordinate. Uses local differencing based on the value of `Self` for
+/// Returns the partial derivative of `component` with respect to the window's
+/// X coordinate. Uses local differencing based on the value of `component` for
 /// the current fragment and its immediate neighbor(s).
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn ddx_fine<F: Float>(component: F) -> F {
+pub fn ddx_fine<F: Float>(component: F) -> F {
     deriv_fn!(component, OpDPdxFine, true)
 }
 
-/// Returns the partial derivative of `Self` with respect to the window's
-/// X coordinate. Uses local differencing based on the value of `Self` for
+/// Returns the partial derivative of `component` with respect to the window's
+/// X coordinate. Uses local differencing based on the value of `component` for
 /// the current fragment’s neighbors, and possibly, but not necessarily,
-/// includes the value of `Self` for the current fragment. That is, over a
+/// includes the value of `component` for the current fragment. That is, over a
 /// given area, the implementation can compute X derivatives in fewer
-/// unique locations than would be allowed by [`Self::ddx_fine`].
+/// unique locations than would be allowed by [`ddx_fine`].
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn ddx_coarse<F: Float>(component: F) -> F {
+pub fn ddx_coarse<F: Float>(component: F) -> F {
     deriv_fn!(component, OpDPdxCoarse, true)
 }
 
-/// Returns the partial derivative of `Self` with respect to the window's
-/// Y coordinate. Returns the same result as either [`Self::ddy_fine`] or
-/// [`Self::ddy_coarse`], selection of which one is dependent on
+/// Returns the partial derivative of `component` with respect to the window's
+/// Y coordinate. Returns the same result as either [`ddy_fine`] or
+/// [`ddy_coarse`], selection of which one is dependent on
 /// external factors.
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn ddy<F: Float>(component: F) -> F {
+pub fn ddy<F: Float>(component: F) -> F {
     deriv_fn!(component, OpDPdy, false)
 }
 
-/// Returns the partial derivative of `Self` with respect to the window's
-/// Y coordinate. Uses local differencing based on the value of `Self` for
+/// Returns the partial derivative of `component` with respect to the window's
+/// Y coordinate. Uses local differencing based on the value of `component` for
 /// the current fragment and its immediate neighbor(s).
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn ddy_fine<F: Float>(component: F) -> F {
+pub fn ddy_fine<F: Float>(component: F) -> F {
     deriv_fn!(component, OpDPdyFine, true)
 }
 
-/// Returns the partial derivative of `Self` with respect to the window's
-/// Y coordinate. Uses local differencing based on the value of `Self` for
+/// Returns the partial derivative of `component` with respect to the window's
+/// Y coordinate. Uses local differencing based on the value of `component` for
 /// the current fragment’s neighbors, and possibly, but not necessarily,
-/// includes the value of `Self` for the current fragment. That is, over a
+/// includes the value of `component` for the current fragment. That is, over a
 /// given area, the implementation can compute Y derivatives in fewer
-/// unique locations than would be allowed by [`Derivative::ddy_fine`].
+/// unique locations than would be allowed by [`ddy_fine`].
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn ddy_coarse<F: Float>(component: F) -> F {
+pub fn ddy_coarse<F: Float>(component: F) -> F {
     deriv_fn!(component, OpDPdyCoarse, true)
 }
 
-/// Returns the sum of the absolute values of [`Self::ddx`] and
-/// [`Self::ddy`] as a single operation.
+/// Returns the sum of the absolute values of [`ddx`] and
+/// [`ddy`] as a single operation.
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn fwidth<F: Float>(component: F) -> F {
+pub fn fwidth<F: Float>(component: F) -> F {
     deriv_fn!(component, OpFwidth, false)
 }
 
-/// Returns the sum of the absolute values of [`Self::ddx_fine`] and
-/// [`Self::ddy_fine`] as a single operation.
+/// Returns the sum of the absolute values of [`ddx_fine`] and
+/// [`ddy_fine`] as a single operation.
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn fwidth_fine<F: Float>(component: F) -> F {
+pub fn fwidth_fine<F: Float>(component: F) -> F {
     deriv_fn!(component, OpFwidthFine, true)
 }
 
-/// Returns the sum of the absolute values of [`Self::ddx_coarse`] and
-/// [`Self::ddy_coarse`] as a single operation.
+/// Returns the sum of the absolute values of [`ddx_coarse`] and
+/// [`ddy_coarse`] as a single operation.
 #[spirv_std_macros::vectorized]
 #[spirv_std_macros::gpu_only]
-fn fwidth_coarse<F: Float>(component: F) -> F {
+pub fn fwidth_coarse<F: Float>(component: F) -> F {
     deriv_fn!(component, OpFwidthCoarse, true)
 }
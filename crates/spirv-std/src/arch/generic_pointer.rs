@@ -0,0 +1,100 @@
+//! Casts between pointers into `Function`, `Workgroup` or `CrossWorkgroup`
+//! memory, and `Generic` pointers, which can point into any of them.
+//!
+//! These are OpenCL features, only available when building kernels (i.e. with
+//! the `kernel` target feature).
+
+/// Convert `ptr`, which points into `Function`, `Workgroup` or
+/// `CrossWorkgroup` memory, into a `Generic` pointer.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpPtrCastToGeneric")]
+#[inline]
+pub fn to_generic<T>(ptr: *mut T) -> *mut T {
+    let mut result = core::ptr::null_mut::<T>();
+
+    unsafe {
+        asm! {
+            "OpCapability GenericPointer",
+            "%result = OpPtrCastToGeneric typeof*{result} {ptr}",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Convert the `Generic` pointer `ptr` back into a pointer into the storage
+/// class it points into, which is inferred from how the result is used.
+///
+/// The result is undefined if `ptr` doesn't point into that storage class, see
+/// [`generic_to_function`], [`generic_to_workgroup`] and
+/// [`generic_to_cross_workgroup`] for checked versions.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGenericCastToPtr")]
+#[inline]
+pub fn from_generic<T>(ptr: *mut T) -> *mut T {
+    let mut result = core::ptr::null_mut::<T>();
+
+    unsafe {
+        asm! {
+            "OpCapability GenericPointer",
+            "%result = OpGenericCastToPtr typeof*{result} {ptr}",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+macro_rules! generic_cast_to_ptr_explicit {
+    ($(#[$attr:meta])* $name:ident, $storage_class:literal) => {
+        $(#[$attr])*
+        #[spirv_std_macros::gpu_only]
+        #[doc(alias = "OpGenericCastToPtrExplicit")]
+        #[inline]
+        pub fn $name<T>(ptr: *mut T) -> Option<*mut T> {
+            let mut result = core::ptr::null_mut::<T>();
+
+            unsafe {
+                asm! {
+                    "OpCapability GenericPointer",
+                    concat!(
+                        "%result = OpGenericCastToPtrExplicit typeof*{result} {ptr} ",
+                        $storage_class
+                    ),
+                    "OpStore {result} %result",
+                    ptr = in(reg) ptr,
+                    result = in(reg) &mut result,
+                }
+            }
+
+            if result.is_null() {
+                None
+            } else {
+                Some(result)
+            }
+        }
+    };
+}
+
+generic_cast_to_ptr_explicit! {
+    /// Convert the `Generic` pointer `ptr` into a pointer into `Function`
+    /// memory, or `None` if it doesn't point into `Function` memory.
+    generic_to_function, "Function"
+}
+
+generic_cast_to_ptr_explicit! {
+    /// Convert the `Generic` pointer `ptr` into a pointer into `Workgroup`
+    /// memory, or `None` if it doesn't point into `Workgroup` memory.
+    generic_to_workgroup, "Workgroup"
+}
+
+generic_cast_to_ptr_explicit! {
+    /// Convert the `Generic` pointer `ptr` into a pointer into `CrossWorkgroup`
+    /// memory, or `None` if it doesn't point into `CrossWorkgroup` memory.
+    generic_to_cross_workgroup, "CrossWorkgroup"
+}
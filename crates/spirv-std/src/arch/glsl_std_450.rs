@@ -0,0 +1,676 @@
+//! Extended math functions from the `GLSL.std.450` instruction set.
+//!
+//! The component-wise functions take either a float or a vector of floats,
+//! as a scalar is treated as a single component [`Vector`].
+use crate::{
+    float::{f16, Float},
+    scalar::Scalar,
+    vector::Vector,
+};
+
+macro_rules! glsl_unary_fn {
+    ($(#[$attr:meta])* $name:ident, $op:literal) => {
+        $(#[$attr])*
+        #[spirv_std_macros::gpu_only]
+        #[inline]
+        pub fn $name<F: Float, V: Vector<F, N>, const N: usize>(x: V) -> V {
+            let mut result = V::default();
+
+            unsafe {
+                asm! {
+                    "%glsl = OpExtInstImport \"GLSL.std.450\"",
+                    "%x = OpLoad _ {x}",
+                    concat!("%result = OpExtInst typeof*{result} %glsl ", $op, " %x"),
+                    "OpStore {result} %result",
+                    x = in(reg) &x,
+                    result = in(reg) &mut result,
+                }
+            }
+
+            result
+        }
+    };
+}
+
+glsl_unary_fn! {
+    /// `x` rounded to the nearest integer. Whether a fractional part of 0.5
+    /// rounds up or down is up to the implementation.
+    #[doc(alias = "Round")]
+    round, "1"
+}
+
+glsl_unary_fn! {
+    /// `x` rounded towards zero to the nearest integer.
+    #[doc(alias = "Trunc")]
+    trunc, "3"
+}
+
+glsl_unary_fn! {
+    /// 1 if `x > 0`, 0 if `x == 0` and -1 if `x < 0`.
+    #[doc(alias = "FSign")]
+    sign, "6"
+}
+
+glsl_unary_fn! {
+    /// `x` rounded down to the nearest integer.
+    #[doc(alias = "Floor")]
+    floor, "8"
+}
+
+glsl_unary_fn! {
+    /// `x` rounded up to the nearest integer.
+    #[doc(alias = "Ceil")]
+    ceil, "9"
+}
+
+glsl_unary_fn! {
+    /// The fractional part of `x`, i.e. `x - floor(x)`.
+    #[doc(alias = "Fract")]
+    fract, "10"
+}
+
+glsl_unary_fn! {
+    /// The sine of `x`, in radians.
+    #[doc(alias = "Sin")]
+    sin, "13"
+}
+
+glsl_unary_fn! {
+    /// The cosine of `x`, in radians.
+    #[doc(alias = "Cos")]
+    cos, "14"
+}
+
+glsl_unary_fn! {
+    /// The tangent of `x`, in radians.
+    #[doc(alias = "Tan")]
+    tan, "15"
+}
+
+glsl_unary_fn! {
+    /// The natural exponentiation of `x`, i.e. `e^x`.
+    #[doc(alias = "Exp")]
+    exp, "27"
+}
+
+glsl_unary_fn! {
+    /// The natural logarithm of `x`. The result is undefined if `x <= 0`.
+    #[doc(alias = "Log")]
+    log, "28"
+}
+
+glsl_unary_fn! {
+    /// `2^x`.
+    #[doc(alias = "Exp2")]
+    exp2, "29"
+}
+
+glsl_unary_fn! {
+    /// The base 2 logarithm of `x`. The result is undefined if `x <= 0`.
+    #[doc(alias = "Log2")]
+    log2, "30"
+}
+
+glsl_unary_fn! {
+    /// The square root of `x`. The result is undefined if `x < 0`.
+    #[doc(alias = "Sqrt")]
+    sqrt, "31"
+}
+
+glsl_unary_fn! {
+    /// The reciprocal of the square root of `x`. The result is undefined if
+    /// `x <= 0`.
+    #[doc(alias = "InverseSqrt")]
+    inverse_sqrt, "32"
+}
+
+glsl_unary_fn! {
+    /// `x` scaled to a length of 1, pointing in the same direction.
+    #[doc(alias = "Normalize")]
+    normalize, "69"
+}
+
+/// `x` raised to the power of `y`. The result is undefined if `x < 0`, or if
+/// `x == 0` and `y <= 0`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Pow")]
+#[inline]
+pub fn pow<F: Float, V: Vector<F, N>, const N: usize>(x: V, y: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%y = OpLoad _ {y}",
+            "%result = OpExtInst typeof*{result} %glsl 26 %x %y",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            y = in(reg) &y,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// A scalar type with `GLSL.std.450` minimum, maximum and clamping
+/// instructions, which are different ones for floats, signed integers and
+/// unsigned integers.
+pub unsafe trait MinMax: Scalar {
+    #[doc(hidden)]
+    const MIN: u32;
+    #[doc(hidden)]
+    const MAX: u32;
+    #[doc(hidden)]
+    const CLAMP: u32;
+}
+
+macro_rules! impl_min_max {
+    ($min:literal, $max:literal, $clamp:literal => $($ty:ty),+) => {
+        $(unsafe impl MinMax for $ty {
+            const MIN: u32 = $min;
+            const MAX: u32 = $max;
+            const CLAMP: u32 = $clamp;
+        })+
+    };
+}
+
+impl_min_max!(37, 40, 43 => f16, f32, f64);
+impl_min_max!(38, 41, 44 => u8, u16, u32, u64);
+impl_min_max!(39, 42, 45 => i8, i16, i32, i64);
+
+/// The smaller of `x` and `y`. For floats, if either is NaN, which one is
+/// returned is undefined.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "FMin")]
+#[doc(alias = "UMin")]
+#[doc(alias = "SMin")]
+#[inline]
+pub fn min<T: MinMax, V: Vector<T, N>, const N: usize>(x: V, y: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%y = OpLoad _ {y}",
+            "%result = OpExtInst typeof*{result} %glsl {op} %x %y",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            y = in(reg) &y,
+            op = const T::MIN,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The larger of `x` and `y`. For floats, if either is NaN, which one is
+/// returned is undefined.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "FMax")]
+#[doc(alias = "UMax")]
+#[doc(alias = "SMax")]
+#[inline]
+pub fn max<T: MinMax, V: Vector<T, N>, const N: usize>(x: V, y: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%y = OpLoad _ {y}",
+            "%result = OpExtInst typeof*{result} %glsl {op} %x %y",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            y = in(reg) &y,
+            op = const T::MAX,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// `x` clamped between `min` and `max`, comparing floats, signed integers
+/// or unsigned integers as such. The result is undefined if `min > max`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "FClamp")]
+#[doc(alias = "UClamp")]
+#[doc(alias = "SClamp")]
+#[inline]
+pub fn clamp<T: MinMax, V: Vector<T, N>, const N: usize>(x: V, min: V, max: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%min = OpLoad _ {min}",
+            "%max = OpLoad _ {max}",
+            "%result = OpExtInst typeof*{result} %glsl {op} %x %min %max",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            min = in(reg) &min,
+            max = in(reg) &max,
+            op = const T::CLAMP,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The linear blend of `x` and `y`, i.e. `x * (1 - a) + y * a`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "FMix")]
+#[doc(alias = "mix")]
+#[doc(alias = "lerp")]
+#[inline]
+pub fn fmix<F: Float, V: Vector<F, N>, const N: usize>(x: V, y: V, a: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%y = OpLoad _ {y}",
+            "%a = OpLoad _ {a}",
+            "%result = OpExtInst typeof*{result} %glsl 46 %x %y %a",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            y = in(reg) &y,
+            a = in(reg) &a,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// 0 where `x < edge`, otherwise 1.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Step")]
+#[inline]
+pub fn step<F: Float, V: Vector<F, N>, const N: usize>(edge: V, x: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%edge = OpLoad _ {edge}",
+            "%x = OpLoad _ {x}",
+            "%result = OpExtInst typeof*{result} %glsl 48 %edge %x",
+            "OpStore {result} %result",
+            edge = in(reg) &edge,
+            x = in(reg) &x,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Smooth Hermite interpolation between 0 and 1 as `x` goes from `edge0` to
+/// `edge1`. The result is undefined if `edge0 >= edge1`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "SmoothStep")]
+#[inline]
+pub fn smoothstep<F: Float, V: Vector<F, N>, const N: usize>(edge0: V, edge1: V, x: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%edge0 = OpLoad _ {edge0}",
+            "%edge1 = OpLoad _ {edge1}",
+            "%x = OpLoad _ {x}",
+            "%result = OpExtInst typeof*{result} %glsl 49 %edge0 %edge1 %x",
+            "OpStore {result} %result",
+            edge0 = in(reg) &edge0,
+            edge1 = in(reg) &edge1,
+            x = in(reg) &x,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The length of the vector `x`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Length")]
+#[inline]
+pub fn length<F: Float, V: Vector<F, N>, const N: usize>(x: V) -> F {
+    let mut result = F::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%result = OpExtInst typeof*{result} %glsl 66 %x",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The distance between the points `p0` and `p1`, i.e. `length(p0 - p1)`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Distance")]
+#[inline]
+pub fn distance<F: Float, V: Vector<F, N>, const N: usize>(p0: V, p1: V) -> F {
+    let mut result = F::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%p0 = OpLoad _ {p0}",
+            "%p1 = OpLoad _ {p1}",
+            "%result = OpExtInst typeof*{result} %glsl 67 %p0 %p1",
+            "OpStore {result} %result",
+            p0 = in(reg) &p0,
+            p1 = in(reg) &p1,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The cross product of `x` and `y`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Cross")]
+#[inline]
+pub fn cross<F: Float, V: Vector<F, 3>>(x: V, y: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%x = OpLoad _ {x}",
+            "%y = OpLoad _ {y}",
+            "%result = OpExtInst typeof*{result} %glsl 68 %x %y",
+            "OpStore {result} %result",
+            x = in(reg) &x,
+            y = in(reg) &y,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The reflection of the incident vector `i` off a surface with the normal
+/// `n`, which should be normalized.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Reflect")]
+#[inline]
+pub fn reflect<F: Float, V: Vector<F, N>, const N: usize>(i: V, n: V) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%i = OpLoad _ {i}",
+            "%n = OpLoad _ {n}",
+            "%result = OpExtInst typeof*{result} %glsl 71 %i %n",
+            "OpStore {result} %result",
+            i = in(reg) &i,
+            n = in(reg) &n,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// The refraction of the incident vector `i` through a surface with the
+/// normal `n`, given the ratio `eta` of indices of refraction. Both `i` and
+/// `n` should be normalized. Returns zero on total internal reflection.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "Refract")]
+#[inline]
+pub fn refract<F: Float, V: Vector<F, N>, const N: usize>(i: V, n: V, eta: F) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%i = OpLoad _ {i}",
+            "%n = OpLoad _ {n}",
+            "%eta = OpLoad _ {eta}",
+            "%result = OpExtInst typeof*{result} %glsl 72 %i %n %eta",
+            "OpStore {result} %result",
+            i = in(reg) &i,
+            n = in(reg) &n,
+            eta = in(reg) &eta,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Pack the components of `v`, clamped to `[-1, 1]`, into 8-bit signed
+/// normalized integers, with the first component in the least significant
+/// bits.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "PackSnorm4x8")]
+#[inline]
+pub fn pack_snorm_4x8<V: Vector<f32, 4>>(v: V) -> u32 {
+    let mut result = 0;
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%v = OpLoad _ {v}",
+            "%result = OpExtInst typeof*{result} %glsl 54 %v",
+            "OpStore {result} %result",
+            v = in(reg) &v,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Pack the components of `v`, clamped to `[0, 1]`, into 8-bit unsigned
+/// normalized integers, with the first component in the least significant
+/// bits.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "PackUnorm4x8")]
+#[inline]
+pub fn pack_unorm_4x8<V: Vector<f32, 4>>(v: V) -> u32 {
+    let mut result = 0;
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%v = OpLoad _ {v}",
+            "%result = OpExtInst typeof*{result} %glsl 55 %v",
+            "OpStore {result} %result",
+            v = in(reg) &v,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Pack the components of `v`, clamped to `[-1, 1]`, into 16-bit signed
+/// normalized integers, with the first component in the least significant
+/// bits.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "PackSnorm2x16")]
+#[inline]
+pub fn pack_snorm_2x16<V: Vector<f32, 2>>(v: V) -> u32 {
+    let mut result = 0;
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%v = OpLoad _ {v}",
+            "%result = OpExtInst typeof*{result} %glsl 56 %v",
+            "OpStore {result} %result",
+            v = in(reg) &v,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Pack the components of `v`, clamped to `[0, 1]`, into 16-bit unsigned
+/// normalized integers, with the first component in the least significant
+/// bits.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "PackUnorm2x16")]
+#[inline]
+pub fn pack_unorm_2x16<V: Vector<f32, 2>>(v: V) -> u32 {
+    let mut result = 0;
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%v = OpLoad _ {v}",
+            "%result = OpExtInst typeof*{result} %glsl 57 %v",
+            "OpStore {result} %result",
+            v = in(reg) &v,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Pack the components of `v` into 16-bit floats, with the first component
+/// in the least significant bits.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "PackHalf2x16")]
+#[inline]
+pub fn pack_half_2x16<V: Vector<f32, 2>>(v: V) -> u32 {
+    let mut result = 0;
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%v = OpLoad _ {v}",
+            "%result = OpExtInst typeof*{result} %glsl 58 %v",
+            "OpStore {result} %result",
+            v = in(reg) &v,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Unpack two 16-bit signed normalized integers from `p`, the inverse of
+/// [`pack_snorm_2x16`].
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "UnpackSnorm2x16")]
+#[inline]
+pub fn unpack_snorm_2x16<V: Vector<f32, 2>>(p: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%result = OpExtInst typeof*{result} %glsl 60 {p}",
+            "OpStore {result} %result",
+            p = in(reg) p,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Unpack two 16-bit unsigned normalized integers from `p`, the inverse of
+/// [`pack_unorm_2x16`].
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "UnpackUnorm2x16")]
+#[inline]
+pub fn unpack_unorm_2x16<V: Vector<f32, 2>>(p: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%result = OpExtInst typeof*{result} %glsl 61 {p}",
+            "OpStore {result} %result",
+            p = in(reg) p,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Unpack two 16-bit floats from `p`, the inverse of [`pack_half_2x16`].
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "UnpackHalf2x16")]
+#[inline]
+pub fn unpack_half_2x16<V: Vector<f32, 2>>(p: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%result = OpExtInst typeof*{result} %glsl 62 {p}",
+            "OpStore {result} %result",
+            p = in(reg) p,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Unpack four 8-bit signed normalized integers from `p`, the inverse of
+/// [`pack_snorm_4x8`].
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "UnpackSnorm4x8")]
+#[inline]
+pub fn unpack_snorm_4x8<V: Vector<f32, 4>>(p: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%result = OpExtInst typeof*{result} %glsl 63 {p}",
+            "OpStore {result} %result",
+            p = in(reg) p,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Unpack four 8-bit unsigned normalized integers from `p`, the inverse of
+/// [`pack_unorm_4x8`].
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "UnpackUnorm4x8")]
+#[inline]
+pub fn unpack_unorm_4x8<V: Vector<f32, 4>>(p: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "%glsl = OpExtInstImport \"GLSL.std.450\"",
+            "%result = OpExtInst typeof*{result} %glsl 64 {p}",
+            "OpStore {result} %result",
+            p = in(reg) p,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
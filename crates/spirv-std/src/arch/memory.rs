@@ -0,0 +1,41 @@
+//! Bulk copies between memory of any storage class, e.g. staging a tile of a
+//! `StorageBuffer` into `Workgroup` memory for a tiled compute kernel.
+//!
+//! Other invocations only see what was copied into memory they share once they
+//! synchronize with the copying one, e.g. through
+//! [`workgroup_barrier`](super::workgroup_barrier) after copying into
+//! `Workgroup` memory.
+
+/// Copy the value `src` points to into `dst` with a single instruction, which
+/// the driver may turn into something faster than loading and storing each
+/// element.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpCopyMemory")]
+#[inline]
+pub fn copy_memory<T>(dst: &mut T, src: &T) {
+    unsafe {
+        asm! {
+            "OpCopyMemory {dst} {src}",
+            dst = in(reg) dst,
+            src = in(reg) src,
+        }
+    }
+}
+
+/// Like [`copy_memory`], but with both `dst` and `src` aligned to at least
+/// `ALIGN` bytes, which is required for `PhysicalStorageBuffer` pointers.
+///
+/// # Safety
+/// The addresses of `dst` and `src` must be multiples of `ALIGN`, which must
+/// be a power of two.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpCopyMemory")]
+#[inline]
+pub unsafe fn copy_memory_aligned<T, const ALIGN: u32>(dst: &mut T, src: &T) {
+    asm! {
+        "OpCopyMemory {dst} {src} Aligned {align}",
+        dst = in(reg) dst,
+        src = in(reg) src,
+        align = const ALIGN,
+    }
+}
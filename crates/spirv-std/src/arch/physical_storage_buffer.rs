@@ -0,0 +1,95 @@
+//! Pointers into `PhysicalStorageBuffer` memory, from the
+//! `SPV_KHR_physical_storage_buffer` extension (core in SPIR-V 1.5).
+//!
+//! These are the GPU side of "buffer device addresses": the client API hands
+//! out a `u64` address for a buffer, which [`from_u64`] turns into a pointer.
+//! Memory accessed through such a pointer must state its alignment, so it can
+//! only be read and written with [`load_aligned`] and [`store_aligned`].
+
+/// Convert the buffer device address `addr` into a pointer to a `T` in
+/// `PhysicalStorageBuffer` memory.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpConvertUToPtr")]
+#[inline]
+pub fn from_u64<T>(addr: u64) -> *mut T {
+    let mut result = core::ptr::null_mut::<T>();
+
+    unsafe {
+        asm! {
+            "OpExtension \"SPV_KHR_physical_storage_buffer\"",
+            "OpCapability PhysicalStorageBufferAddresses",
+            "%ptr = OpTypePointer PhysicalStorageBuffer typeof*{pointee}",
+            "%result = OpConvertUToPtr %ptr {addr}",
+            "OpStore {result} %result",
+            pointee = in(reg) result,
+            addr = in(reg) addr,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Convert `ptr`, which points into `PhysicalStorageBuffer` memory, back into
+/// its buffer device address.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpConvertPtrToU")]
+#[inline]
+pub fn to_u64<T>(ptr: *const T) -> u64 {
+    let mut result = 0;
+
+    unsafe {
+        asm! {
+            "OpExtension \"SPV_KHR_physical_storage_buffer\"",
+            "OpCapability PhysicalStorageBufferAddresses",
+            "%u64 = OpTypeInt 64 0",
+            "%result = OpConvertPtrToU %u64 {ptr}",
+            "OpStore {result} %result",
+            ptr = in(reg) ptr,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Read the value `ptr` points to, which is aligned to at least `ALIGN` bytes.
+///
+/// # Safety
+/// `ptr` must point to a valid `T` in `PhysicalStorageBuffer` memory, and be a
+/// multiple of `ALIGN`, which must be a power of two.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpLoad")]
+#[inline]
+pub unsafe fn load_aligned<T: Copy + Default, const ALIGN: u32>(ptr: *const T) -> T {
+    let mut result = T::default();
+
+    asm! {
+        "%value = OpLoad _ {ptr} Aligned {align}",
+        "OpStore {result} %value",
+        ptr = in(reg) ptr,
+        align = const ALIGN,
+        result = in(reg) &mut result,
+    }
+
+    result
+}
+
+/// Write `value` to where `ptr` points to, which is aligned to at least
+/// `ALIGN` bytes.
+///
+/// # Safety
+/// `ptr` must point to a valid `T` in `PhysicalStorageBuffer` memory, and be a
+/// multiple of `ALIGN`, which must be a power of two.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpStore")]
+#[inline]
+pub unsafe fn store_aligned<T: Copy, const ALIGN: u32>(ptr: *mut T, value: T) {
+    asm! {
+        "%value = OpLoad _ {value}",
+        "OpStore {ptr} %value Aligned {align}",
+        ptr = in(reg) ptr,
+        value = in(reg) &value,
+        align = const ALIGN,
+    }
+}
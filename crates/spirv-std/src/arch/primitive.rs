@@ -0,0 +1,67 @@
+//! Building the output primitives of geometry shaders, out of the vertices
+//! given by the values of their `Output`s at each [`emit_vertex`].
+//!
+//! These are only valid in the `Geometry` execution model.
+
+/// Emit the current values of all outputs as a vertex of the current output
+/// primitive. The values of the outputs are undefined afterwards, so they
+/// need to be written again before emitting the next vertex.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpEmitVertex")]
+#[inline]
+pub fn emit_vertex() {
+    unsafe {
+        asm!("OpEmitVertex");
+    }
+}
+
+/// Finish the current output primitive, starting a new one with the next
+/// [`emit_vertex`]. Returning from the entry point ends the last primitive
+/// implicitly.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpEndPrimitive")]
+#[inline]
+pub fn end_primitive() {
+    unsafe {
+        asm!("OpEndPrimitive");
+    }
+}
+
+/// Emit the current values of all outputs as a vertex of the current output
+/// primitive of vertex stream `STREAM`, like [`emit_vertex`] but for shaders
+/// writing to multiple streams (which requires the `GeometryStreams`
+/// capability).
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpEmitStreamVertex")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn emit_stream_vertex<const STREAM: u32>() {
+    unsafe {
+        asm! {
+            "OpCapability GeometryStreams",
+            "%u32 = OpTypeInt 32 0",
+            "%stream = OpConstant %u32 {stream}",
+            "OpEmitStreamVertex %stream",
+            stream = const STREAM,
+        }
+    }
+}
+
+/// Finish the current output primitive of vertex stream `STREAM`, like
+/// [`end_primitive`] but for shaders writing to multiple streams (which
+/// requires the `GeometryStreams` capability).
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpEndStreamPrimitive")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn end_stream_primitive<const STREAM: u32>() {
+    unsafe {
+        asm! {
+            "OpCapability GeometryStreams",
+            "%u32 = OpTypeInt 32 0",
+            "%stream = OpConstant %u32 {stream}",
+            "OpEndStreamPrimitive %stream",
+            stream = const STREAM,
+        }
+    }
+}
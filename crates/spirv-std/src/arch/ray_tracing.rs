@@ -0,0 +1,309 @@
+//! Ray tracing instructions, from the `SPV_KHR_ray_tracing` extension.
+
+use crate::storage_class::CallableDataKHR;
+#[cfg(feature = "const-generics")]
+use crate::{storage_class::RayPayloadKHR, vector::Vector, AccelerationStructure};
+
+/// Flags controlling how a ray is traced, which can be combined with `|`, as
+/// used by [`trace_ray`].
+pub mod ray_flags {
+    pub const NONE: u32 = 0x0;
+    pub const OPAQUE: u32 = 0x1;
+    pub const NO_OPAQUE: u32 = 0x2;
+    pub const TERMINATE_ON_FIRST_HIT: u32 = 0x4;
+    pub const SKIP_CLOSEST_HIT_SHADER: u32 = 0x8;
+    pub const CULL_BACK_FACING_TRIANGLES: u32 = 0x10;
+    pub const CULL_FRONT_FACING_TRIANGLES: u32 = 0x20;
+    pub const CULL_OPAQUE: u32 = 0x40;
+    pub const CULL_NO_OPAQUE: u32 = 0x80;
+    pub const SKIP_TRIANGLES: u32 = 0x100;
+    pub const SKIP_AABBS: u32 = 0x200;
+}
+
+/// Trace a ray through `acceleration_structure`, from `ray_origin` in
+/// `ray_direction`, considering only hits between `ray_tmin` and `ray_tmax`.
+///
+/// The hit and miss shaders invoked along the way communicate their results
+/// through `payload`. Which of them are invoked is selected from the shader
+/// binding table by `sbt_offset`, `sbt_stride` and `miss_index`, and which
+/// instances are considered by `cull_mask`. `ray_flags` can be any
+/// combination of the [`ray_flags`] constants.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpTraceRayKHR")]
+#[inline]
+#[allow(clippy::too_many_arguments)]
+#[cfg(feature = "const-generics")]
+pub fn trace_ray<V: Vector<f32, 3>, T>(
+    acceleration_structure: &AccelerationStructure,
+    ray_flags: u32,
+    cull_mask: u32,
+    sbt_offset: u32,
+    sbt_stride: u32,
+    miss_index: u32,
+    ray_origin: V,
+    ray_tmin: f32,
+    ray_direction: V,
+    ray_tmax: f32,
+    payload: &mut RayPayloadKHR<'_, T>,
+) {
+    unsafe {
+        asm! {
+            "OpExtension \"SPV_KHR_ray_tracing\"",
+            "OpCapability RayTracingKHR",
+            "%acceleration_structure = OpLoad _ {acceleration_structure}",
+            "%ray_origin = OpLoad _ {ray_origin}",
+            "%ray_direction = OpLoad _ {ray_direction}",
+            "OpTraceRayKHR %acceleration_structure {ray_flags} {cull_mask} {sbt_offset} {sbt_stride} {miss_index} %ray_origin {ray_tmin} %ray_direction {ray_tmax} {payload}",
+            acceleration_structure = in(reg) acceleration_structure,
+            ray_flags = in(reg) ray_flags,
+            cull_mask = in(reg) cull_mask,
+            sbt_offset = in(reg) sbt_offset,
+            sbt_stride = in(reg) sbt_stride,
+            miss_index = in(reg) miss_index,
+            ray_origin = in(reg) &ray_origin,
+            ray_tmin = in(reg) ray_tmin,
+            ray_direction = in(reg) &ray_direction,
+            ray_tmax = in(reg) ray_tmax,
+            payload = in(reg) &mut **payload,
+        }
+    }
+}
+
+/// Report a hit at parametric distance `t_hit` along the current ray, with
+/// `hit_kind` made available to the hit shaders through [`hit_kind`].
+///
+/// Returns `true` if the hit was accepted, i.e. it was within the ray's
+/// interval and wasn't ignored by the any-hit shader. Only valid in
+/// intersection shaders.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpReportIntersectionKHR")]
+#[inline]
+pub fn report_intersection(t_hit: f32, hit_kind: u32) -> bool {
+    let mut result = false;
+
+    unsafe {
+        asm! {
+            "OpExtension \"SPV_KHR_ray_tracing\"",
+            "OpCapability RayTracingKHR",
+            // Types & Constants
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%u8_1 = OpConstant %u8 1",
+            // Code
+            "%result = OpReportIntersectionKHR %bool {t_hit} {hit_kind}",
+            "%boolean = OpSelect %u8 %result %u8_1 %u8_0",
+            "OpStore {result} %boolean",
+            t_hit = in(reg) t_hit,
+            hit_kind = in(reg) hit_kind,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Ignore the current hit, terminating the any-hit shader and continuing the
+/// traversal as if the hit never happened. Only valid in any-hit shaders.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpIgnoreIntersectionKHR")]
+#[inline]
+pub fn ignore_intersection() -> ! {
+    unsafe {
+        asm!(
+            "OpExtension \"SPV_KHR_ray_tracing\"",
+            "OpCapability RayTracingKHR",
+            "OpIgnoreIntersectionKHR",
+            "%unused = OpLabel"
+        );
+        core::hint::unreachable_unchecked()
+    }
+}
+
+/// Accept the current hit and stop the traversal, terminating the any-hit
+/// shader and invoking the closest-hit shader for the closest hit found so
+/// far. Only valid in any-hit shaders.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpTerminateRayKHR")]
+#[inline]
+pub fn terminate_ray() -> ! {
+    unsafe {
+        asm!(
+            "OpExtension \"SPV_KHR_ray_tracing\"",
+            "OpCapability RayTracingKHR",
+            "OpTerminateRayKHR",
+            "%unused = OpLabel"
+        );
+        core::hint::unreachable_unchecked()
+    }
+}
+
+/// Invoke the callable shader at `sbt_index` in the shader binding table,
+/// which receives `callable_data` as its [`IncomingCallableDataKHR`].
+///
+/// [`IncomingCallableDataKHR`]: crate::storage_class::IncomingCallableDataKHR
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpExecuteCallableKHR")]
+#[inline]
+pub fn execute_callable<T>(sbt_index: u32, callable_data: &mut CallableDataKHR<'_, T>) {
+    unsafe {
+        asm! {
+            "OpExtension \"SPV_KHR_ray_tracing\"",
+            "OpCapability RayTracingKHR",
+            "OpExecuteCallableKHR {sbt_index} {callable_data}",
+            sbt_index = in(reg) sbt_index,
+            callable_data = in(reg) &mut **callable_data,
+        }
+    }
+}
+
+// The builtins below are declared under their `NV` names, which are the same
+// values as the `KHR` ones but are what the assembler understands.
+macro_rules! ray_tracing_builtin {
+    ($(#[$attr:meta])* $name:ident, $builtin:literal, $ty:ty, $spirv_ty:literal) => {
+        $(#[$attr])*
+        #[spirv_std_macros::gpu_only]
+        #[inline]
+        pub fn $name() -> $ty {
+            let mut result = <$ty>::default();
+
+            unsafe {
+                asm! {
+                    "OpExtension \"SPV_KHR_ray_tracing\"",
+                    "OpCapability RayTracingKHR",
+                    concat!("OpDecorate %builtin BuiltIn ", $builtin),
+                    concat!("%ty = ", $spirv_ty),
+                    "%ptr = OpTypePointer Generic %ty",
+                    "%builtin = OpVariable %ptr Input",
+                    "%value = OpLoad %ty %builtin",
+                    "OpStore {result} %value",
+                    result = in(reg) &mut result,
+                }
+            }
+
+            result
+        }
+    };
+}
+
+macro_rules! ray_tracing_vector_builtin {
+    ($(#[$attr:meta])* $name:ident, $builtin:literal, $elem:ty, $spirv_elem:literal) => {
+        $(#[$attr])*
+        #[spirv_std_macros::gpu_only]
+        #[inline]
+        #[cfg(feature = "const-generics")]
+        pub fn $name<V: Vector<$elem, 3>>() -> V {
+            let mut result = V::default();
+
+            unsafe {
+                asm! {
+                    "OpExtension \"SPV_KHR_ray_tracing\"",
+                    "OpCapability RayTracingKHR",
+                    concat!("OpDecorate %builtin BuiltIn ", $builtin),
+                    concat!("%elem = ", $spirv_elem),
+                    "%ty = OpTypeVector %elem 3",
+                    "%ptr = OpTypePointer Generic %ty",
+                    "%builtin = OpVariable %ptr Input",
+                    "%value = OpLoad %ty %builtin",
+                    "OpStore {result} %value",
+                    result = in(reg) &mut result,
+                }
+            }
+
+            result
+        }
+    };
+}
+
+ray_tracing_vector_builtin! {
+    /// The index of the current work item within the launch grid.
+    ///
+    /// Valid in ray generation, intersection, any-hit, closest-hit, miss and
+    /// callable shaders.
+    launch_id, "LaunchIdNV", u32, "OpTypeInt 32 0"
+}
+
+ray_tracing_vector_builtin! {
+    /// The dimensions of the launch grid.
+    ///
+    /// Valid in ray generation, intersection, any-hit, closest-hit, miss and
+    /// callable shaders.
+    launch_size, "LaunchSizeNV", u32, "OpTypeInt 32 0"
+}
+
+ray_tracing_vector_builtin! {
+    /// The origin of the current ray, in world space.
+    ///
+    /// Valid in intersection, any-hit, closest-hit and miss shaders.
+    world_ray_origin, "WorldRayOriginNV", f32, "OpTypeFloat 32"
+}
+
+ray_tracing_vector_builtin! {
+    /// The direction of the current ray, in world space.
+    ///
+    /// Valid in intersection, any-hit, closest-hit and miss shaders.
+    world_ray_direction, "WorldRayDirectionNV", f32, "OpTypeFloat 32"
+}
+
+ray_tracing_vector_builtin! {
+    /// The origin of the current ray, in the object space of the instance
+    /// being intersected.
+    ///
+    /// Valid in intersection, any-hit and closest-hit shaders.
+    object_ray_origin, "ObjectRayOriginNV", f32, "OpTypeFloat 32"
+}
+
+ray_tracing_vector_builtin! {
+    /// The direction of the current ray, in the object space of the instance
+    /// being intersected.
+    ///
+    /// Valid in intersection, any-hit and closest-hit shaders.
+    object_ray_direction, "ObjectRayDirectionNV", f32, "OpTypeFloat 32"
+}
+
+ray_tracing_builtin! {
+    /// The `ray_tmin` the current ray was traced with.
+    ///
+    /// Valid in intersection, any-hit, closest-hit and miss shaders.
+    ray_t_min, "RayTminNV", f32, "OpTypeFloat 32"
+}
+
+ray_tracing_builtin! {
+    /// The parametric distance of the closest hit found so far, or the
+    /// `ray_tmax` the ray was traced with if there hasn't been one.
+    ///
+    /// Valid in intersection, any-hit, closest-hit and miss shaders.
+    ray_t_current, "RayTmaxNV", f32, "OpTypeFloat 32"
+}
+
+ray_tracing_builtin! {
+    /// The custom index of the instance being intersected, as given when
+    /// building the acceleration structure.
+    ///
+    /// Valid in intersection, any-hit and closest-hit shaders.
+    instance_custom_index, "InstanceCustomIndexNV", u32, "OpTypeInt 32 0"
+}
+
+ray_tracing_builtin! {
+    /// The index of the instance being intersected within the acceleration
+    /// structure.
+    ///
+    /// Valid in intersection, any-hit and closest-hit shaders.
+    instance_id, "InstanceId", u32, "OpTypeInt 32 0"
+}
+
+ray_tracing_builtin! {
+    /// The hit kind reported for the current hit, either by
+    /// `OpReportIntersectionKHR` or as one of the front/back face values for
+    /// triangles.
+    ///
+    /// Valid in any-hit and closest-hit shaders.
+    hit_kind, "HitKindNV", u32, "OpTypeInt 32 0"
+}
+
+ray_tracing_builtin! {
+    /// The [`ray_flags`] the current ray was traced with.
+    ///
+    /// Valid in intersection, any-hit, closest-hit and miss shaders.
+    incoming_ray_flags, "IncomingRayFlagsNV", u32, "OpTypeInt 32 0"
+}
@@ -0,0 +1,406 @@
+//! Subgroup operations, which share data between the invocations of a
+//! subgroup, always using the `Subgroup` scope.
+// The new preferred style is still to use `unsafe` blocks in `unsafe` functions
+// but the compiler/clippy hasn't caught up to that style yet, so we just
+// disable the lint.
+#![allow(unused_unsafe)]
+
+#[cfg(feature = "const-generics")]
+use crate::{scalar::Scalar, vector::Vector};
+
+/// Result is true only in the active invocation with the lowest id in the
+/// subgroup, otherwise result is false.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformElect")]
+#[inline]
+pub fn subgroup_elect() -> bool {
+    let mut result = false;
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniform",
+            // Types & Constants
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%u8_1 = OpConstant %u8 1",
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%result = OpGroupNonUniformElect %bool %subgroup",
+            "%boolean = OpSelect %u8 %result %u8_1 %u8_0",
+            "OpStore {result} %boolean",
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Evaluates `predicate` for all active invocations in the subgroup, result
+/// is true if `predicate` is true for all of them, otherwise result is false.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformAll")]
+#[inline]
+pub fn subgroup_all(predicate: bool) -> bool {
+    let mut result = false;
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformVote",
+            // Types & Constants
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%u8_1 = OpConstant %u8 1",
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%result = OpGroupNonUniformAll %bool %subgroup {predicate}",
+            "%boolean = OpSelect %u8 %result %u8_1 %u8_0",
+            "OpStore {result} %boolean",
+            predicate = in(reg) predicate,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Evaluates `predicate` for all active invocations in the subgroup, result
+/// is true if `predicate` is true for any of them, otherwise result is false.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformAny")]
+#[inline]
+pub fn subgroup_any(predicate: bool) -> bool {
+    let mut result = false;
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformVote",
+            // Types & Constants
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%u8_1 = OpConstant %u8 1",
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%result = OpGroupNonUniformAny %bool %subgroup {predicate}",
+            "%boolean = OpSelect %u8 %result %u8_1 %u8_0",
+            "OpStore {result} %boolean",
+            predicate = in(reg) predicate,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Result is a bitfield of `predicate` as evaluated by every active
+/// invocation in the subgroup, where bit `i` of the whole 128 bits (starting
+/// at the least significant bit of the first component) is set if
+/// `predicate` is true in the invocation with id `i`.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformBallot")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_ballot<V: Vector<u32, 4>>(predicate: bool) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformBallot",
+            // Types & Constants
+            "%u32 = OpTypeInt 32 0",
+            "%uvec4 = OpTypeVector %u32 4",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%result = OpGroupNonUniformBallot %uvec4 %subgroup {predicate}",
+            "OpStore {result} %result",
+            predicate = in(reg) predicate,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Result is the `value` of the invocation with subgroup id `id`, which must
+/// be active, to all active invocations in the subgroup.
+///
+/// `id` must be the same for all invocations in the subgroup, and before
+/// SPIR-V 1.5 it must also be a constant.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformBroadcast")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_broadcast<T: Scalar, V: Vector<T, N>, const N: usize>(value: V, id: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformBallot",
+            // Types & Constants
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%value = OpLoad _ {value}",
+            "%result = OpGroupNonUniformBroadcast _ %subgroup %value {id}",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            id = in(reg) id,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Result is the `value` of the invocation with subgroup id `id`. The result
+/// is undefined if that invocation is inactive, or `id` is out of range.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformShuffle")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_shuffle<T: Scalar, V: Vector<T, N>, const N: usize>(value: V, id: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformShuffle",
+            // Types & Constants
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%value = OpLoad _ {value}",
+            "%result = OpGroupNonUniformShuffle _ %subgroup %value {id}",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            id = in(reg) id,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Result is the `value` of the invocation whose subgroup id is the current
+/// invocation's id xor `mask`. The result is undefined if that invocation is
+/// inactive, or the id is out of range.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformShuffleXor")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_shuffle_xor<T: Scalar, V: Vector<T, N>, const N: usize>(value: V, mask: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformShuffle",
+            // Types & Constants
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%value = OpLoad _ {value}",
+            "%result = OpGroupNonUniformShuffleXor _ %subgroup %value {mask}",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            mask = in(reg) mask,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Result is the `value` of the invocation whose subgroup id is the current
+/// invocation's id minus `delta`. The result is undefined if that invocation
+/// is inactive, or the id is out of range.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformShuffleUp")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_shuffle_up<T: Scalar, V: Vector<T, N>, const N: usize>(value: V, delta: u32) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformShuffleRelative",
+            // Types & Constants
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%value = OpLoad _ {value}",
+            "%result = OpGroupNonUniformShuffleUp _ %subgroup %value {delta}",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            delta = in(reg) delta,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Result is the `value` of the invocation whose subgroup id is the current
+/// invocation's id plus `delta`. The result is undefined if that invocation
+/// is inactive, or the id is out of range.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpGroupNonUniformShuffleDown")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_shuffle_down<T: Scalar, V: Vector<T, N>, const N: usize>(
+    value: V,
+    delta: u32,
+) -> V {
+    let mut result = V::default();
+
+    unsafe {
+        asm! {
+            "OpCapability GroupNonUniformShuffleRelative",
+            // Types & Constants
+            "%u32 = OpTypeInt 32 0",
+            "%subgroup = OpConstant %u32 3",
+            // Code
+            "%value = OpLoad _ {value}",
+            "%result = OpGroupNonUniformShuffleDown _ %subgroup %value {delta}",
+            "OpStore {result} %result",
+            value = in(reg) &value,
+            delta = in(reg) delta,
+            result = in(reg) &mut result,
+        }
+    }
+
+    result
+}
+
+/// Scalar types that the subgroup arithmetic operations can be performed on,
+/// picking the signed integer, unsigned integer, or floating-point
+/// instruction for each operation.
+#[cfg(feature = "const-generics")]
+pub unsafe trait SubgroupArithmetic: Scalar {
+    #[doc(hidden)]
+    fn subgroup_reduce_add<V: Vector<Self, N>, const N: usize>(value: V) -> V;
+    #[doc(hidden)]
+    fn subgroup_inclusive_add<V: Vector<Self, N>, const N: usize>(value: V) -> V;
+    #[doc(hidden)]
+    fn subgroup_exclusive_add<V: Vector<Self, N>, const N: usize>(value: V) -> V;
+    #[doc(hidden)]
+    fn subgroup_reduce_min<V: Vector<Self, N>, const N: usize>(value: V) -> V;
+    #[doc(hidden)]
+    fn subgroup_reduce_max<V: Vector<Self, N>, const N: usize>(value: V) -> V;
+}
+
+#[cfg(feature = "const-generics")]
+macro_rules! subgroup_arithmetic_fn {
+    ($name:ident, $inst:literal, $operation:literal) => {
+        #[spirv_std_macros::gpu_only]
+        #[inline]
+        fn $name<V: Vector<Self, N>, const N: usize>(value: V) -> V {
+            let mut result = V::default();
+
+            unsafe {
+                asm! {
+                    "OpCapability GroupNonUniformArithmetic",
+                    // Types & Constants
+                    "%u32 = OpTypeInt 32 0",
+                    "%subgroup = OpConstant %u32 3",
+                    // Code
+                    "%value = OpLoad _ {value}",
+                    concat!("%result = ", $inst, " _ %subgroup ", $operation, " %value"),
+                    "OpStore {result} %result",
+                    value = in(reg) &value,
+                    result = in(reg) &mut result,
+                }
+            }
+
+            result
+        }
+    };
+}
+
+#[cfg(feature = "const-generics")]
+macro_rules! impl_subgroup_arithmetic {
+    ($($ty:ty => ($add:literal, $min:literal, $max:literal);)+) => {
+        $(unsafe impl SubgroupArithmetic for $ty {
+            subgroup_arithmetic_fn!(subgroup_reduce_add, $add, "Reduce");
+            subgroup_arithmetic_fn!(subgroup_inclusive_add, $add, "InclusiveScan");
+            subgroup_arithmetic_fn!(subgroup_exclusive_add, $add, "ExclusiveScan");
+            subgroup_arithmetic_fn!(subgroup_reduce_min, $min, "Reduce");
+            subgroup_arithmetic_fn!(subgroup_reduce_max, $max, "Reduce");
+        })+
+    };
+}
+
+#[cfg(feature = "const-generics")]
+impl_subgroup_arithmetic! {
+    u8 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformUMin", "OpGroupNonUniformUMax");
+    u16 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformUMin", "OpGroupNonUniformUMax");
+    u32 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformUMin", "OpGroupNonUniformUMax");
+    u64 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformUMin", "OpGroupNonUniformUMax");
+    i8 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformSMin", "OpGroupNonUniformSMax");
+    i16 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformSMin", "OpGroupNonUniformSMax");
+    i32 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformSMin", "OpGroupNonUniformSMax");
+    i64 => ("OpGroupNonUniformIAdd", "OpGroupNonUniformSMin", "OpGroupNonUniformSMax");
+    f32 => ("OpGroupNonUniformFAdd", "OpGroupNonUniformFMin", "OpGroupNonUniformFMax");
+    f64 => ("OpGroupNonUniformFAdd", "OpGroupNonUniformFMin", "OpGroupNonUniformFMax");
+}
+
+/// Result is the sum of `value` over all active invocations in the subgroup.
+/// Results are computed per component.
+#[doc(alias = "OpGroupNonUniformIAdd")]
+#[doc(alias = "OpGroupNonUniformFAdd")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_reduce_add<T: SubgroupArithmetic, V: Vector<T, N>, const N: usize>(value: V) -> V {
+    T::subgroup_reduce_add(value)
+}
+
+/// Result is the sum of `value` over the active invocations in the subgroup
+/// with an id less than or equal to the current invocation's id. Results are
+/// computed per component.
+#[doc(alias = "OpGroupNonUniformIAdd")]
+#[doc(alias = "OpGroupNonUniformFAdd")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_inclusive_add<T: SubgroupArithmetic, V: Vector<T, N>, const N: usize>(
+    value: V,
+) -> V {
+    T::subgroup_inclusive_add(value)
+}
+
+/// Result is the sum of `value` over the active invocations in the subgroup
+/// with an id less than the current invocation's id, which is zero for the
+/// first of them. Results are computed per component.
+#[doc(alias = "OpGroupNonUniformIAdd")]
+#[doc(alias = "OpGroupNonUniformFAdd")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_exclusive_add<T: SubgroupArithmetic, V: Vector<T, N>, const N: usize>(
+    value: V,
+) -> V {
+    T::subgroup_exclusive_add(value)
+}
+
+/// Result is the minimum `value` over all active invocations in the
+/// subgroup. Results are computed per component.
+#[doc(alias = "OpGroupNonUniformSMin")]
+#[doc(alias = "OpGroupNonUniformUMin")]
+#[doc(alias = "OpGroupNonUniformFMin")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_reduce_min<T: SubgroupArithmetic, V: Vector<T, N>, const N: usize>(value: V) -> V {
+    T::subgroup_reduce_min(value)
+}
+
+/// Result is the maximum `value` over all active invocations in the
+/// subgroup. Results are computed per component.
+#[doc(alias = "OpGroupNonUniformSMax")]
+#[doc(alias = "OpGroupNonUniformUMax")]
+#[doc(alias = "OpGroupNonUniformFMax")]
+#[inline]
+#[cfg(feature = "const-generics")]
+pub fn subgroup_reduce_max<T: SubgroupArithmetic, V: Vector<T, N>, const N: usize>(value: V) -> V {
+    T::subgroup_reduce_max(value)
+}
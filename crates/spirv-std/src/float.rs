@@ -10,3 +10,241 @@ unsafe impl Float for f32 {
 unsafe impl Float for f64 {
     const WIDTH: usize = 64;
 }
+
+/// A half-precision floating point number, `OpTypeFloat 16` in SPIR-V.
+///
+/// Rust has no native `f16`, so this wraps its bits, but arithmetic and
+/// comparisons on it are done at half precision by the GPU, and using it
+/// requires the `Float16` capability (and `StorageBuffer16BitAccess` etc. to
+/// store it in buffers). Convert from and to `f32` with [`From`], e.g. to do
+/// half precision math on the `f32` texels read from an `Rgba16f` image.
+#[allow(non_camel_case_types)]
+#[spirv(f16)]
+#[repr(transparent)]
+#[derive(Copy, Clone)]
+pub struct f16 {
+    _bits: u16,
+}
+
+impl f16 {
+    pub const ZERO: Self = Self { _bits: 0x0000 };
+    pub const ONE: Self = Self { _bits: 0x3c00 };
+    pub const INFINITY: Self = Self { _bits: 0x7c00 };
+    pub const NEG_INFINITY: Self = Self { _bits: 0xfc00 };
+
+    /// Reinterpret the bits of an IEEE 754 binary16 value as an `f16`.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpBitcast")]
+    #[inline]
+    pub fn from_bits(bits: u16) -> Self {
+        let mut result = Self::ZERO;
+
+        unsafe {
+            asm! {
+                "%bits = OpLoad _ {bits}",
+                "%result = OpBitcast typeof*{result} %bits",
+                "OpStore {result} %result",
+                bits = in(reg) &bits,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+
+    /// The bits of this value as an IEEE 754 binary16 value.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpBitcast")]
+    #[inline]
+    pub fn to_bits(self) -> u16 {
+        let mut result = 0;
+
+        unsafe {
+            asm! {
+                "%this = OpLoad _ {this}",
+                "%result = OpBitcast typeof*{result} %this",
+                "OpStore {result} %result",
+                this = in(reg) &self,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+}
+
+impl Default for f16 {
+    #[inline]
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
+unsafe impl crate::scalar::Scalar for f16 {}
+
+impl From<f32> for f16 {
+    #[spirv_std_macros::gpu_only]
+    #[inline]
+    fn from(value: f32) -> Self {
+        let mut result = Self::ZERO;
+
+        unsafe {
+            asm! {
+                "%value = OpLoad _ {value}",
+                "%result = OpFConvert typeof*{result} %value",
+                "OpStore {result} %result",
+                value = in(reg) &value,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+}
+
+impl From<f16> for f32 {
+    #[spirv_std_macros::gpu_only]
+    #[inline]
+    fn from(value: f16) -> Self {
+        let mut result = 0.0;
+
+        unsafe {
+            asm! {
+                "%value = OpLoad _ {value}",
+                "%result = OpFConvert typeof*{result} %value",
+                "OpStore {result} %result",
+                value = in(reg) &value,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+}
+
+impl core::ops::Neg for f16 {
+    type Output = Self;
+
+    #[spirv_std_macros::gpu_only]
+    #[inline]
+    fn neg(self) -> Self {
+        let mut result = Self::ZERO;
+
+        unsafe {
+            asm! {
+                "%this = OpLoad _ {this}",
+                "%result = OpFNegate typeof*{result} %this",
+                "OpStore {result} %result",
+                this = in(reg) &self,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+}
+
+macro_rules! impl_f16_binary_op {
+    ($($trait:ident::$method:ident, $assign_trait:ident::$assign_method:ident => $op:literal;)+) => {
+        $(
+            impl core::ops::$trait for f16 {
+                type Output = Self;
+
+                #[spirv_std_macros::gpu_only]
+                #[inline]
+                fn $method(self, rhs: Self) -> Self {
+                    let mut result = Self::ZERO;
+
+                    unsafe {
+                        asm! {
+                            "%lhs = OpLoad _ {lhs}",
+                            "%rhs = OpLoad _ {rhs}",
+                            concat!("%result = ", $op, " typeof*{result} %lhs %rhs"),
+                            "OpStore {result} %result",
+                            lhs = in(reg) &self,
+                            rhs = in(reg) &rhs,
+                            result = in(reg) &mut result,
+                        }
+                    }
+
+                    result
+                }
+            }
+
+            impl core::ops::$assign_trait for f16 {
+                #[inline]
+                fn $assign_method(&mut self, rhs: Self) {
+                    *self = core::ops::$trait::$method(*self, rhs);
+                }
+            }
+        )+
+    };
+}
+
+impl_f16_binary_op! {
+    Add::add, AddAssign::add_assign => "OpFAdd";
+    Sub::sub, SubAssign::sub_assign => "OpFSub";
+    Mul::mul, MulAssign::mul_assign => "OpFMul";
+    Div::div, DivAssign::div_assign => "OpFDiv";
+    Rem::rem, RemAssign::rem_assign => "OpFRem";
+}
+
+macro_rules! f16_comparison {
+    ($($method:ident => $op:literal;)+) => {
+        $(
+            #[spirv_std_macros::gpu_only]
+            #[inline]
+            fn $method(&self, other: &Self) -> bool {
+                let mut result = false;
+
+                unsafe {
+                    asm! {
+                        "%bool = OpTypeBool",
+                        "%u8 = OpTypeInt 8 0",
+                        "%u8_0 = OpConstant %u8 0",
+                        "%u8_1 = OpConstant %u8 1",
+                        "%lhs = OpLoad _ {lhs}",
+                        "%rhs = OpLoad _ {rhs}",
+                        concat!("%result = ", $op, " %bool %lhs %rhs"),
+                        "%boolean = OpSelect %u8 %result %u8_1 %u8_0",
+                        "OpStore {result} %boolean",
+                        lhs = in(reg) self,
+                        rhs = in(reg) other,
+                        result = in(reg) &mut result,
+                    }
+                }
+
+                result
+            }
+        )+
+    };
+}
+
+impl PartialEq for f16 {
+    f16_comparison! {
+        eq => "OpFOrdEqual";
+        ne => "OpFUnordNotEqual";
+    }
+}
+
+impl PartialOrd for f16 {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<core::cmp::Ordering> {
+        if self < other {
+            Some(core::cmp::Ordering::Less)
+        } else if self > other {
+            Some(core::cmp::Ordering::Greater)
+        } else if self == other {
+            Some(core::cmp::Ordering::Equal)
+        } else {
+            None
+        }
+    }
+
+    f16_comparison! {
+        lt => "OpFOrdLessThan";
+        le => "OpFOrdLessThanEqual";
+        gt => "OpFOrdGreaterThan";
+        ge => "OpFOrdGreaterThanEqual";
+    }
+}
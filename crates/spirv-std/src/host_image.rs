@@ -0,0 +1,219 @@
+//! Software sampling of images on the host, with the `host-images` feature,
+//! to test shader functions that sample images without a GPU.
+//!
+//! Only [`Image2d`](crate::Image2d)s of `f32` can be backed by texels on the
+//! host, with [`Image2d::from_host`](crate::Image2d::from_host), and only
+//! their `sample`, `sample_by_lod` and `fetch` methods use them. Samplers
+//! made with [`Sampler::host_nearest`](crate::Sampler::host_nearest) or
+//! [`Sampler::host_linear`](crate::Sampler::host_linear) clamp coordinates
+//! to the edge of the image.
+
+use crate::{integer::Integer, scalar::Scalar, vector::Vector};
+use alloc::vec::Vec;
+use core::mem::{size_of, transmute_copy};
+// Unused when tests link `std`, whose inherent `f32` methods take precedence.
+#[cfg_attr(test, allow(unused_imports))]
+use num_traits::Float;
+
+/// How a host sampler filters the texels it samples.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub(crate) enum Filter {
+    Nearest,
+    Linear,
+}
+
+/// The texels backing an image on the host, row by row from the top left.
+#[derive(Copy, Clone)]
+pub(crate) struct HostTexels {
+    texels: &'static [[f32; 4]],
+    width: usize,
+    height: usize,
+}
+
+impl HostTexels {
+    pub(crate) fn new(texels: Vec<[f32; 4]>, width: usize, height: usize) -> Self {
+        assert!(
+            width > 0 && height > 0 && texels.len() == width * height,
+            "expected {}x{} texels, got {}",
+            width,
+            height,
+            texels.len()
+        );
+        Self {
+            texels: Vec::leak(texels),
+            width,
+            height,
+        }
+    }
+
+    fn texel_clamped(&self, x: i64, y: i64) -> [f32; 4] {
+        let x = x.max(0).min(self.width as i64 - 1) as usize;
+        let y = y.max(0).min(self.height as i64 - 1) as usize;
+        self.texels[y * self.width + x]
+    }
+
+    /// The texel at `coordinate`, which (like on the GPU, without robustness
+    /// features) must be within the image.
+    pub(crate) fn fetch<I: Integer, const N: usize>(
+        &self,
+        coordinate: impl Vector<I, N>,
+    ) -> [f32; 4] {
+        let [x, y] = match components(coordinate)[..] {
+            [x, y] => [x.to_usize(), y.to_usize()],
+            _ => unreachable!("2D images take 2 coordinates"),
+        };
+        match (x, y) {
+            (Some(x), Some(y)) if x < self.width && y < self.height => {
+                self.texels[y * self.width + x]
+            }
+            _ => panic!(
+                "fetched texel outside of {}x{} image",
+                self.width, self.height
+            ),
+        }
+    }
+
+    /// Sample at the normalized `coordinate`, from the nearest texel or
+    /// interpolating between the 4 nearest ones.
+    pub(crate) fn sample(&self, filter: Filter, coordinate: impl Vector<f32, 2>) -> [f32; 4] {
+        let [u, v] = components(coordinate);
+        let (x, y) = (u * self.width as f32, v * self.height as f32);
+        if filter == Filter::Nearest {
+            return self.texel_clamped(x.floor() as i64, y.floor() as i64);
+        }
+
+        // Texel centers are at half-integer coordinates.
+        let (x, y) = (x - 0.5, y - 0.5);
+        let (x0, y0) = (x.floor(), y.floor());
+        let (fx, fy) = (x - x0, y - y0);
+        let (x0, y0) = (x0 as i64, y0 as i64);
+        let lerp = |a: [f32; 4], b: [f32; 4], t: f32| {
+            let mut result = a;
+            for (result, b) in result.iter_mut().zip(&b) {
+                *result += (b - *result) * t;
+            }
+            result
+        };
+        let top = lerp(
+            self.texel_clamped(x0, y0),
+            self.texel_clamped(x0 + 1, y0),
+            fx,
+        );
+        let bottom = lerp(
+            self.texel_clamped(x0, y0 + 1),
+            self.texel_clamped(x0 + 1, y0 + 1),
+            fx,
+        );
+        lerp(top, bottom, fy)
+    }
+}
+
+/// The components of `vector`, which has to be laid out like an array of them
+/// (as glam's vector types other than `Vec3A` are).
+pub(crate) fn components<T: Scalar, V: Vector<T, N>, const N: usize>(vector: V) -> [T; N] {
+    assert_eq!(
+        size_of::<V>(),
+        size_of::<[T; N]>(),
+        "unsupported vector layout"
+    );
+    unsafe { transmute_copy(&vector) }
+}
+
+/// A vector of the components of `texel`, which must have been read from an
+/// image of `f32`s (as only those can be backed by host texels), so `T` is
+/// `f32`.
+pub(crate) fn texel_vector<T: Scalar, V: Vector<T, 4>>(texel: [f32; 4]) -> V {
+    assert_eq!(size_of::<T>(), size_of::<f32>());
+    assert_eq!(
+        size_of::<V>(),
+        size_of::<[f32; 4]>(),
+        "unsupported vector layout"
+    );
+    unsafe { transmute_copy(&texel) }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Filter, HostTexels};
+    use crate::vector::Vector;
+    use alloc::vec;
+
+    #[derive(Copy, Clone, Default)]
+    #[repr(C)]
+    struct Vec2(f32, f32);
+    unsafe impl Vector<f32, 2> for Vec2 {}
+
+    #[derive(Copy, Clone, Default)]
+    #[repr(C)]
+    struct UVec2(u32, u32);
+    unsafe impl Vector<u32, 2> for UVec2 {}
+
+    const BLACK: [f32; 4] = [0.0, 0.0, 0.0, 1.0];
+    const RED: [f32; 4] = [1.0, 0.0, 0.0, 1.0];
+    const GREEN: [f32; 4] = [0.0, 1.0, 0.0, 1.0];
+    const YELLOW: [f32; 4] = [1.0, 1.0, 0.0, 1.0];
+
+    /// A 2x2 image, with red to the right and green at the bottom.
+    fn image() -> HostTexels {
+        HostTexels::new(vec![BLACK, RED, GREEN, YELLOW], 2, 2)
+    }
+
+    #[test]
+    fn nearest() {
+        let image = image();
+        assert_eq!(image.sample(Filter::Nearest, Vec2(0.25, 0.25)), BLACK);
+        assert_eq!(image.sample(Filter::Nearest, Vec2(0.75, 0.25)), RED);
+        assert_eq!(image.sample(Filter::Nearest, Vec2(0.25, 0.75)), GREEN);
+        assert_eq!(image.sample(Filter::Nearest, Vec2(0.6, 0.9)), YELLOW);
+    }
+
+    #[test]
+    fn linear() {
+        let image = image();
+        // Texel centers sample just that texel.
+        assert_eq!(image.sample(Filter::Linear, Vec2(0.75, 0.75)), YELLOW);
+        // The center of the image is halfway between all 4 texels.
+        assert_eq!(
+            image.sample(Filter::Linear, Vec2(0.5, 0.5)),
+            [0.5, 0.5, 0.0, 1.0]
+        );
+        // A quarter of the way from the center of black to the center of red.
+        assert_eq!(
+            image.sample(Filter::Linear, Vec2(0.375, 0.25)),
+            [0.25, 0.0, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn clamp_to_edge() {
+        let image = image();
+        assert_eq!(image.sample(Filter::Nearest, Vec2(-1.0, 2.0)), GREEN);
+        assert_eq!(image.sample(Filter::Nearest, Vec2(1.0, 1.0)), YELLOW);
+        // Outside of the texel centers, linear filtering only blends texels
+        // along the edge, instead of wrapping around to the other side.
+        assert_eq!(image.sample(Filter::Linear, Vec2(0.0, 0.0)), BLACK);
+        assert_eq!(
+            image.sample(Filter::Linear, Vec2(1.5, 0.5)),
+            [1.0, 0.5, 0.0, 1.0]
+        );
+    }
+
+    #[test]
+    fn fetch() {
+        let image = image();
+        assert_eq!(image.fetch(UVec2(1, 0)), RED);
+        assert_eq!(image.fetch(UVec2(0, 1)), GREEN);
+    }
+
+    #[test]
+    #[should_panic(expected = "fetched texel outside of 2x2 image")]
+    fn fetch_out_of_range() {
+        image().fetch(UVec2(2, 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "expected 2x2 texels, got 3")]
+    fn wrong_texel_count() {
+        HostTexels::new(vec![BLACK, RED, GREEN], 2, 2);
+    }
+}
@@ -69,20 +69,37 @@
 #[cfg(not(target_arch = "spirv"))]
 pub extern crate spirv_std_macros as macros;
 
+// Host images own their texels.
+#[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+extern crate alloc;
+
 pub mod arch;
 pub mod float;
+#[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+mod host_image;
 pub mod integer;
+#[cfg(feature = "const-generics")]
+pub mod matrix;
+pub mod prelude;
+mod ray_tracing;
 pub mod scalar;
 pub(crate) mod sealed;
+mod shader_struct;
 pub mod storage_class;
 mod textures;
 pub mod vector;
 
 pub use num_traits;
+pub use ray_tracing::*;
+pub use shader_struct::*;
 pub use textures::*;
 
 /// Calls the `OpDemoteToHelperInvocationEXT` instruction, which corresponds to discard() in HLSL
+///
+/// Unlike [`discard`], the invocation keeps running as a helper invocation whose
+/// writes are discarded, so derivatives in its neighbours stay well-defined.
 #[spirv_std_macros::gpu_only]
+#[doc(alias = "demote")]
 pub fn demote_to_helper_invocation() {
     unsafe {
         asm!(
@@ -93,11 +110,42 @@ pub fn demote_to_helper_invocation() {
     }
 }
 
+/// Calls the `OpIsHelperInvocationEXT` instruction, which corresponds to
+/// `IsHelperLane()` in HLSL.
+///
+/// Helper invocations only run to compute derivatives for their neighbours, so
+/// side effects like buffer writes and atomics should be skipped in them. Unlike
+/// the `#[spirv(helper_invocation)]` builtin, this is also true for invocations
+/// demoted by [`demote_to_helper_invocation`].
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "gl_HelperInvocation")]
+pub fn is_helper_invocation() -> bool {
+    let mut result = false;
+
+    unsafe {
+        asm!(
+            "OpExtension \"SPV_EXT_demote_to_helper_invocation\"",
+            "OpCapability DemoteToHelperInvocationEXT",
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%u8_1 = OpConstant %u8 1",
+            "%result = OpIsHelperInvocationEXT %bool",
+            "%boolean = OpSelect %u8 %result %u8_1 %u8_0",
+            "OpStore {result} %boolean",
+            result = in(reg) &mut result,
+        );
+    }
+
+    result
+}
+
 /// Calls the `OpKill` instruction, which corresponds to discard() in GLSL
 #[spirv_std_macros::gpu_only]
-pub fn discard() {
+pub fn discard() -> ! {
     unsafe {
         asm!("OpKill", "%unused = OpLabel");
+        core::hint::unreachable_unchecked()
     }
 }
 
@@ -0,0 +1,145 @@
+//! Column-major matrices of `f32`s, `OpTypeMatrix` in SPIR-V.
+//!
+//! The matrices are generic over their column vector type, e.g.
+//! `Mat4<glam::Vec4>` has the same layout as `glam::Mat4`, so the host can
+//! write a `glam::Mat4` into a buffer that the shader reads as a `Mat4`.
+//!
+//! In `Uniform`, `StorageBuffer` and `PushConstant` blocks, matrix members are
+//! decorated `ColMajor`, with a `MatrixStride` of the size of their columns
+//! (16 bytes for 3 and 4 component `f32` columns). std140 blocks require that
+//! stride to be a multiple of 16 bytes, ruling out `Mat2<glam::Vec2>`.
+
+use crate::vector::Vector;
+
+macro_rules! matrix {
+    ($(#[$attr:meta])* $name:ident, $len:literal, $($column:ident),+) => {
+        $(#[$attr])*
+        #[spirv(matrix)]
+        #[repr(C)]
+        #[derive(Copy, Clone, Default)]
+        pub struct $name<V> {
+            $(pub $column: V,)+
+        }
+
+        impl<V: Vector<f32, $len>> $name<V> {
+            /// Create a matrix from its columns.
+            #[inline]
+            pub fn from_cols($($column: V),+) -> Self {
+                Self { $($column),+ }
+            }
+
+            /// The transpose of this matrix, swapping its rows and columns.
+            #[spirv_std_macros::gpu_only]
+            #[doc(alias = "OpTranspose")]
+            #[inline]
+            pub fn transpose(self) -> Self {
+                let mut result = Self::default();
+
+                unsafe {
+                    asm! {
+                        "%matrix = OpLoad _ {matrix}",
+                        "%result = OpTranspose typeof*{result} %matrix",
+                        "OpStore {result} %result",
+                        matrix = in(reg) &self,
+                        result = in(reg) &mut result,
+                    }
+                }
+
+                result
+            }
+
+            /// Multiply the row vector `vector` by this matrix, i.e.
+            /// `vector * self` in GLSL, which is also `self.transpose() * vector`.
+            #[spirv_std_macros::gpu_only]
+            #[doc(alias = "OpVectorTimesMatrix")]
+            #[inline]
+            pub fn left_mul(self, vector: V) -> V {
+                let mut result = V::default();
+
+                unsafe {
+                    asm! {
+                        "%vector = OpLoad _ {vector}",
+                        "%matrix = OpLoad _ {matrix}",
+                        "%result = OpVectorTimesMatrix typeof*{result} %vector %matrix",
+                        "OpStore {result} %result",
+                        vector = in(reg) &vector,
+                        matrix = in(reg) &self,
+                        result = in(reg) &mut result,
+                    }
+                }
+
+                result
+            }
+        }
+
+        impl<V: Vector<f32, $len>> core::ops::Mul<V> for $name<V> {
+            type Output = V;
+
+            /// Transform the column vector `vector` by this matrix.
+            #[spirv_std_macros::gpu_only]
+            #[doc(alias = "OpMatrixTimesVector")]
+            #[inline]
+            fn mul(self, vector: V) -> V {
+                let mut result = V::default();
+
+                unsafe {
+                    asm! {
+                        "%matrix = OpLoad _ {matrix}",
+                        "%vector = OpLoad _ {vector}",
+                        "%result = OpMatrixTimesVector typeof*{result} %matrix %vector",
+                        "OpStore {result} %result",
+                        matrix = in(reg) &self,
+                        vector = in(reg) &vector,
+                        result = in(reg) &mut result,
+                    }
+                }
+
+                result
+            }
+        }
+
+        impl<V: Vector<f32, $len>> core::ops::Mul for $name<V> {
+            type Output = Self;
+
+            /// Compose two transforms, `other` being applied first.
+            #[spirv_std_macros::gpu_only]
+            #[doc(alias = "OpMatrixTimesMatrix")]
+            #[inline]
+            fn mul(self, other: Self) -> Self {
+                let mut result = Self::default();
+
+                unsafe {
+                    asm! {
+                        "%lhs = OpLoad _ {lhs}",
+                        "%rhs = OpLoad _ {rhs}",
+                        "%result = OpMatrixTimesMatrix typeof*{result} %lhs %rhs",
+                        "OpStore {result} %result",
+                        lhs = in(reg) &self,
+                        rhs = in(reg) &other,
+                        result = in(reg) &mut result,
+                    }
+                }
+
+                result
+            }
+        }
+    };
+}
+
+matrix! {
+    /// A 2x2 column-major matrix, e.g. `Mat2<glam::Vec2>`.
+    Mat2, 2, x_axis, y_axis
+}
+
+matrix! {
+    /// A 3x3 column-major matrix, e.g. `Mat3<glam::Vec3>`.
+    ///
+    /// Its `glam::Vec3` columns are padded to 16 bytes in SPIR-V, unlike the
+    /// tightly packed columns of a host `glam::Mat3`.
+    Mat3, 3, x_axis, y_axis, z_axis
+}
+
+matrix! {
+    /// A 4x4 column-major matrix, e.g. `Mat4<glam::Vec4>`.
+    Mat4, 4, x_axis, y_axis, z_axis, w_axis
+}
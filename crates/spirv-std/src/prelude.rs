@@ -0,0 +1,31 @@
+//! The types and traits most shaders need, to import them all at once with
+//! `use spirv_std::prelude::*;`.
+//!
+//! This leaves out names that would clash with each other or with common
+//! imports: the generic [`Image`](crate::Image) type (whose aliases such as
+//! [`Image2d`] are included instead) and the `Image` storage class, and the
+//! extension traits whose methods share names with those of `glam` or
+//! `num_traits` ([`Swizzle`](crate::vector::Swizzle) and
+//! [`FloatMath`](crate::vector::FloatMath)). Math and other instructions are
+//! reached through the [`arch`] module, e.g. `arch::clamp`.
+
+pub use crate::arch;
+pub use crate::float::{f16, Float};
+pub use crate::integer::{Integer, SignedInteger, UnsignedInteger};
+pub use crate::scalar::Scalar;
+#[cfg(feature = "const-generics")]
+pub use crate::vector::{FloatVector, Vector};
+pub use crate::{demote_to_helper_invocation, discard, is_helper_invocation, ShaderStruct};
+
+pub use crate::storage_class::{
+    AtomicCounter, Bind, CallableDataKHR, CrossWorkgroup, Function, Generic, HitAttributeKHR,
+    IncomingCallableDataKHR, IncomingRayPayloadKHR, Input, Location, Output, PhysicalStorageBuffer,
+    Private, PushConstant, RayPayloadKHR, ReadOnlyStorageBuffer, ShaderRecordBufferKHR,
+    StorageBuffer, Uniform, UniformConstant, Workgroup,
+};
+
+pub use crate::{
+    AccelerationStructure, ComparisonSampler, Cubemap, CubemapArray, Image1d, Image2d,
+    Image2dArray, Image2dDepth, Image2dMS, Image3d, ImageRect, InputAttachment, SampledImage,
+    Sampler, StorageImage2d, StorageImage2dArray, StorageTexelBuffer, TexelBuffer,
+};
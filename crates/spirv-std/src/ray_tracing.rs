@@ -0,0 +1,9 @@
+/// An acceleration structure, i.e. the scene geometry that rays are traced
+/// against with [`crate::arch::trace_ray`]. Usually a top-level acceleration
+/// structure, bound as a `UniformConstant` descriptor.
+#[allow(unused_attributes)]
+#[spirv(acceleration_structure)]
+#[derive(Copy, Clone)]
+pub struct AccelerationStructure {
+    _x: u32,
+}
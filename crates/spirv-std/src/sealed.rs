@@ -3,6 +3,7 @@
 pub trait Sealed {}
 
 impl Sealed for bool {}
+impl Sealed for crate::float::f16 {}
 impl Sealed for f32 {}
 impl Sealed for f64 {}
 impl Sealed for u8 {}
@@ -0,0 +1,14 @@
+pub use spirv_std_macros::ShaderStruct;
+
+/// A struct that is laid out the same way in a shader as on the host, so the
+/// host can upload it as plain bytes (e.g. with `bytemuck`).
+///
+/// Implement it with `#[derive(ShaderStruct)]` on a `#[repr(C)]` struct. When
+/// the shader crate is compiled, the offsets `#[repr(C)]` gives its fields are
+/// then checked against the std140 block layout rules, or std430 with
+/// `#[spirv(layout = "std430")]` on the struct, and every field that the GPU
+/// would read from a different offset is a compile error. Generic structs are
+/// only checked when used as a block of an entry point.
+#[allow(unused_attributes)]
+#[spirv(shader_struct)]
+pub trait ShaderStruct {}
@@ -6,6 +6,10 @@
 //! points to a `f32` in the GPU's global memory. Intermediate values do not
 //! form a storage class, and unless stated otherwise, storage class-based
 //! restrictions are not restrictions on intermediate objects and their types.
+//!
+//! Off the GPU, the storage classes that wrap a reference can be constructed
+//! from one (e.g. [`Input::from_ref`] and [`Output::from_mut`]), to call
+//! shader functions that take them from host-side tests.
 
 use core::{
     marker::PhantomData,
@@ -16,7 +20,9 @@ use core::{
 ///
 /// Shared externally, visible across all functions in all invocations in
 /// all work groups. Requires "Shader" capability.
-/// Slices/runtime arrays are not supported yet.
+/// Slices/runtime arrays are not supported yet. 8-bit and 16-bit members need
+/// the `uniformAndStorageBuffer8BitAccess` or
+/// `uniformAndStorageBuffer16BitAccess` device feature.
 #[allow(unused_attributes)]
 #[spirv(uniform)]
 pub struct Uniform<T: ?Sized>(PhantomData<T>);
@@ -31,7 +37,10 @@ impl<T: ?Sized> StorageClassMut for Uniform<T> {}
 ///
 /// Shared externally, readable and writable, visible across all functions
 /// in all invocations in all work groups.
-/// Slices/runtime arrays are not supported yet.
+/// A block may end in a runtime array (e.g. `items: [u32]`), whose `len()` is
+/// the length of the bound buffer's runtime array.
+/// 8-bit and 16-bit members (e.g. `bytes: [u8]`) keep their width, and need
+/// the `storageBuffer8BitAccess` or `storageBuffer16BitAccess` device feature.
 #[allow(unused_attributes)]
 #[spirv(storage_buffer)]
 pub struct StorageBuffer<T: ?Sized>(PhantomData<T>);
@@ -42,6 +51,19 @@ impl<T: ?Sized> StorageClass for StorageBuffer<T> {
 
 impl<T: ?Sized> StorageClassMut for StorageBuffer<T> {}
 
+/// Graphics storage buffers (buffer blocks) that are only read from.
+///
+/// Like [`StorageBuffer`], but decorated `NonWritable`, and so not writable
+/// through [`Bind::deref_mut`] either. May end in a runtime array, like
+/// [`StorageBuffer`].
+#[allow(unused_attributes)]
+#[spirv(storage_buffer, readonly)]
+pub struct ReadOnlyStorageBuffer<T: ?Sized>(PhantomData<T>);
+
+impl<T: ?Sized> StorageClass for ReadOnlyStorageBuffer<T> {
+    type Target = T;
+}
+
 /// Graphics uniform memory. OpenCL constant memory.
 ///
 /// Shared externally, visible across all functions in all invocations in
@@ -63,11 +85,23 @@ impl<T: ?Sized> StorageClass for UniformConstant<T> {
 /// have initializers.
 #[allow(unused_attributes)]
 #[spirv(input)]
+#[repr(transparent)]
 pub struct Input<'a, T: ?Sized, Binding: sealed::InputBinding = sealed::CompilerInferred> {
     ptr: &'a T,
     binding: PhantomData<Binding>,
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, T: ?Sized, Binding: sealed::InputBinding> Input<'a, T, Binding> {
+    /// Wrap a reference, to pass it to a shader function on the host.
+    pub fn from_ref(ptr: &'a T) -> Self {
+        Self {
+            ptr,
+            binding: PhantomData,
+        }
+    }
+}
+
 impl<'a, T: ?Sized, Binding: sealed::InputBinding> Deref for Input<'a, T, Binding> {
     type Target = T;
 
@@ -81,11 +115,23 @@ impl<'a, T: ?Sized, Binding: sealed::InputBinding> Deref for Input<'a, T, Bindin
 /// Visible across all functions in the current invocation.
 #[allow(unused_attributes)]
 #[spirv(output)]
+#[repr(transparent)]
 pub struct Output<'a, T: ?Sized, Binding: sealed::OutputBinding = sealed::CompilerInferred> {
     ptr: &'a mut T,
     binding: PhantomData<Binding>,
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, T: ?Sized, Binding: sealed::OutputBinding> Output<'a, T, Binding> {
+    /// Wrap a mutable reference, to pass it to a shader function on the host.
+    pub fn from_mut(ptr: &'a mut T) -> Self {
+        Self {
+            ptr,
+            binding: PhantomData,
+        }
+    }
+}
+
 impl<'a, T: ?Sized, Binding: sealed::OutputBinding> Deref for Output<'a, T, Binding> {
     type Target = T;
 
@@ -100,6 +146,13 @@ impl<'a, T: ?Sized, Binding: sealed::OutputBinding> DerefMut for Output<'a, T, B
     }
 }
 
+/// An explicit location for an [`Input`] or [`Output`], e.g.
+/// `Output<glam::Vec4, Location<2>>`.
+///
+/// Types too large for one location take up consecutive ones: a matrix takes
+/// one per column, an array one per element, and 64-bit vectors of more than
+/// two components two per vector. Parameters without an explicit location are
+/// given the next free location after the highest one taken so far.
 pub struct Location<const LOCATION: usize>;
 impl<const LOCATION: usize> Location<LOCATION> {
     pub const LOCATION: usize = LOCATION;
@@ -118,10 +171,20 @@ mod sealed {
 macro_rules! storage_class {
     ($(#[$($meta:meta)+])* storage_class $name:ident ; $($tt:tt)*) => {
         $(#[$($meta)+])*
+        #[repr(transparent)]
         pub struct $name<'value, T: ?Sized> {
             reference: &'value mut T,
         }
 
+        #[cfg(not(target_arch = "spirv"))]
+        impl<'value, T: ?Sized> $name<'value, T> {
+            /// Wrap a mutable reference, to pass it to a shader function on
+            /// the host.
+            pub fn from_mut(reference: &'value mut T) -> Self {
+                Self { reference }
+            }
+        }
+
         impl<T: ?Sized> Deref for $name<'_, T> {
             type Target = T;
             fn deref(&self) -> &T {
@@ -175,7 +238,8 @@ storage_class! {
     /// The OpenGL "shared" storage qualifier. OpenCL local memory.
     ///
     /// Shared across all invocations within a work group. Visible across
-    /// all functions.
+    /// all functions. Can also be declared as a `#[spirv(workgroup)] static mut`,
+    /// e.g. inside the function using it.
     #[spirv(workgroup)] writeable storage_class Workgroup;
 
     /// OpenCL global memory.
@@ -206,12 +270,6 @@ storage_class! {
     /// have initializers.
     #[spirv(push_constant)] storage_class PushConstant;
 
-    /// Atomic counter-specific memory.
-    ///
-    /// For holding atomic counters. Visible across all functions of the
-    /// current invocation.
-    #[spirv(atomic_counter)] writeable storage_class AtomicCounter;
-
     /// Image memory.
     ///
     /// Holds a pointer to a single texel, obtained via OpImageTexelPointer. Use of a pointer
@@ -290,18 +348,124 @@ storage_class! {
     #[spirv(physical_storage_buffer)] writeable storage_class PhysicalStorageBuffer;
 }
 
+/// Atomic counter-specific memory.
+///
+/// For holding atomic counters. Visible across all functions of the
+/// current invocation. Atomic counters can only be accessed through their
+/// atomic operations.
+#[allow(unused_attributes)]
+#[spirv(atomic_counter)]
+#[repr(transparent)]
+pub struct AtomicCounter<'value, T: ?Sized> {
+    reference: &'value mut T,
+}
+
+#[cfg(not(target_arch = "spirv"))]
+impl<'value, T: ?Sized> AtomicCounter<'value, T> {
+    /// Wrap a mutable reference, to pass it to a shader function on the host.
+    pub fn from_mut(reference: &'value mut T) -> Self {
+        Self { reference }
+    }
+}
+
+impl AtomicCounter<'_, u32> {
+    /// Atomically read the value of the counter.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpAtomicLoad")]
+    pub fn load(&self) -> u32 {
+        let mut result = 0;
+        unsafe {
+            asm! {
+                "OpCapability AtomicStorage",
+                "%u32 = OpTypeInt 32 0",
+                // Device scope, `AtomicCounterMemory` memory semantics.
+                "%scope = OpConstant %u32 1",
+                "%semantics = OpConstant %u32 1024",
+                "%result = OpAtomicLoad _ {counter} %scope %semantics",
+                "OpStore {result} %result",
+                counter = in(reg) &*self.reference,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+
+    /// Atomically increment the counter.
+    ///
+    /// Returns the value of the counter before the increment.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpAtomicIIncrement")]
+    pub fn increment(&self) -> u32 {
+        let mut result = 0;
+        unsafe {
+            asm! {
+                "OpCapability AtomicStorage",
+                "%u32 = OpTypeInt 32 0",
+                // Device scope, `AtomicCounterMemory` memory semantics.
+                "%scope = OpConstant %u32 1",
+                "%semantics = OpConstant %u32 1024",
+                "%result = OpAtomicIIncrement _ {counter} %scope %semantics",
+                "OpStore {result} %result",
+                counter = in(reg) &*self.reference,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+
+    /// Atomically decrement the counter.
+    ///
+    /// Returns the value of the counter before the decrement.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpAtomicIDecrement")]
+    pub fn decrement(&self) -> u32 {
+        let mut result = 0;
+        unsafe {
+            asm! {
+                "OpCapability AtomicStorage",
+                "%u32 = OpTypeInt 32 0",
+                // Device scope, `AtomicCounterMemory` memory semantics.
+                "%scope = OpConstant %u32 1",
+                "%semantics = OpConstant %u32 1024",
+                "%result = OpAtomicIDecrement _ {counter} %scope %semantics",
+                "OpStore {result} %result",
+                counter = in(reg) &*self.reference,
+                result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+}
+
 /// A descriptor set binding.
 ///
-/// The first paramter is the data parameter. It allows DSTs, but they are not supported yet.
+/// The first paramter is the data parameter. It allows DSTs, i.e. `StorageBuffer` blocks ending in a runtime array.
 /// The second parameter is the storage class or an array or slice of storage class.
 /// The last two const parameters are the `Set` then `Binding` numbers.
+///
+/// Arrays of samplers and images are bound the same way as arrays of buffers, e.g.
+/// `Bind<[UniformConstant<Sampler>; 4], 1, 0>` or `Bind<[UniformConstant<Image2d>], 1, 1>`,
+/// and indexing them gives the `Sampler` or `Image2d` itself.
 #[allow(unused_attributes)]
 #[spirv(bind)]
+#[repr(transparent)]
 pub struct Bind<'a, S: StorageClassOrStorageClassArray + ?Sized, const SET: usize, const BINDING: usize>
 {
     ptr: &'a mut S::Target,
 }
 
+#[cfg(not(target_arch = "spirv"))]
+impl<'a, S: StorageClassOrStorageClassArray + ?Sized, const SET: usize, const BINDING: usize>
+    Bind<'a, S, SET, BINDING>
+{
+    /// Wrap a mutable reference to the data of the binding (e.g. the `T` of
+    /// a `Bind<Uniform<T>, SET, BINDING>`), to pass it to a shader function
+    /// on the host.
+    pub fn from_mut(ptr: &'a mut S::Target) -> Self {
+        Self { ptr }
+    }
+}
+
 impl<'a, S: StorageClass + StorageClassMut, const SET: usize, const BINDING: usize>
     Bind<'a, S, SET, BINDING>
 {
@@ -320,6 +484,42 @@ impl<'a, S: StorageClassArray + StorageClassMut + ?Sized, const SET: usize, cons
     }
 }
 
+impl<'a, S: StorageClassArray + ?Sized, const SET: usize, const BINDING: usize> Bind<'a, S, SET, BINDING> {
+    /// Index into the descriptor array with an index that may differ between invocations.
+    ///
+    /// Indexing with `Index` assumes the index is dynamically uniform, which is undefined
+    /// behaviour otherwise. This instead decorates the access with `NonUniform`.
+    #[allow(unused_attributes)]
+    #[spirv(index_descriptor_array_nonuniform)]
+    #[allow(unused_variables)]
+    pub fn index_nonuniform(&self, index: usize) -> &S::Target {
+        //compiler implemented
+        unimplemented!()
+    }
+}
+
+impl<'a, S: StorageClassArray + StorageClassMut + ?Sized, const SET: usize, const BINDING: usize> Bind<'a, S, SET, BINDING> {
+    /// Like [`Self::index_nonuniform`], but mutable.
+    #[allow(unused_attributes)]
+    #[spirv(index_descriptor_array_nonuniform)]
+    #[allow(unused_variables)]
+    pub unsafe fn index_nonuniform_mut(&mut self, index: usize) -> &'a mut S::Target {
+        //compiler implemented
+        unimplemented!()
+    }
+}
+
+impl<'a, S: StorageClass, const SET: usize, const BINDING: usize> Bind<'a, [S], SET, BINDING> {
+    /// Index into a runtime descriptor array, clamping `index` to `len - 1`.
+    ///
+    /// The length of a runtime descriptor array can't be queried from the shader, so it has to
+    /// be provided separately, e.g. through a push constant or a `#[spirv(spec_constant)]`.
+    /// `len` must not be zero.
+    pub fn index_clamped(&self, index: usize, len: usize) -> &S::Target {
+        &self[if index < len { index } else { len - 1 }]
+    }
+}
+
 impl<'a, S: StorageClass, const SET: usize, const BINDING: usize> Deref for Bind<'a, S, SET, BINDING> {
     type Target = S::Target;
     fn deref(&self) -> &S::Target {
@@ -384,3 +584,39 @@ impl<S: StorageClass, const N: usize> StorageClassArray for [S; N] {}
 //        unimplemented!()
 //    }
 //}
+
+#[cfg(test)]
+mod tests {
+    use super::{Bind, Input, Output, Private, StorageBuffer, Workgroup};
+
+    #[test]
+    fn from_ref() {
+        let value = [1.0_f32, 2.0];
+        let input: Input<'_, [f32; 2]> = Input::from_ref(&value);
+        assert_eq!(input[1], 2.0);
+    }
+
+    #[test]
+    fn from_mut() {
+        let mut value = [0_u32; 4];
+        let mut output: Output<'_, [u32; 4]> = Output::from_mut(&mut value);
+        output[2] = 7;
+        assert_eq!(*output, [0, 0, 7, 0]);
+        assert_eq!(value, [0, 0, 7, 0]);
+
+        let mut private: Private<'_, [u32; 4]> = Private::from_mut(&mut value);
+        private[0] += 1;
+        let mut workgroup: Workgroup<'_, [u32; 4]> = Workgroup::from_mut(&mut value);
+        workgroup[3] = 9;
+        assert_eq!(value, [1, 0, 7, 9]);
+    }
+
+    #[test]
+    fn bind_from_mut() {
+        let mut value = [0_u32; 4];
+        let mut buffer: Bind<'_, StorageBuffer<[u32; 4]>, 0, 0> = Bind::from_mut(&mut value);
+        unsafe { buffer.deref_mut()[1] = 5 };
+        assert_eq!(buffer[1], 5);
+        assert_eq!(value, [0, 5, 0, 0]);
+    }
+}
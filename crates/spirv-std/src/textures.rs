@@ -1,5 +1,7 @@
 use core::marker::PhantomData;
 
+use crate::scalar::Scalar;
+
 #[cfg(feature = "const-generics")]
 use crate::{integer::Integer, vector::Vector};
 
@@ -7,6 +9,40 @@ use crate::{integer::Integer, vector::Vector};
 #[derive(Copy, Clone)]
 pub struct Sampler {
     _x: u32,
+    #[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+    filter: crate::host_image::Filter,
+}
+
+#[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+impl Sampler {
+    /// A sampler for images backed by host texels (see
+    /// [`Image2d::from_host`]), which samples the nearest texel.
+    pub fn host_nearest() -> Self {
+        Self {
+            _x: 0,
+            filter: crate::host_image::Filter::Nearest,
+        }
+    }
+
+    /// A sampler for images backed by host texels (see
+    /// [`Image2d::from_host`]), which interpolates between the 4 nearest
+    /// texels.
+    pub fn host_linear() -> Self {
+        Self {
+            _x: 0,
+            filter: crate::host_image::Filter::Linear,
+        }
+    }
+}
+
+/// A sampler with a comparison function, the only kind of sampler that
+/// images can be sampled with a depth reference (`Dref`) with. It's the
+/// same `OpTypeSampler` as [`Sampler`] in SPIR-V, but using a sampler of
+/// the wrong kind is a validation error.
+#[spirv(sampler)]
+#[derive(Copy, Clone)]
+pub struct ComparisonSampler {
+    _x: u32,
 }
 
 #[allow(unused_attributes)]
@@ -35,104 +71,1503 @@ pub struct Image<
 > {
     _opaque: u32,
     marker: PhantomData<(T, Dims, Depth, Sampled, Format, Arrayed, Multisampled)>,
+    #[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+    host: crate::host_image::HostTexels,
+}
+
+impl<
+        T: sealed_traits::SampledType + Copy,
+        Dims: sealed_traits::ImageDims,
+        Depth: sealed_traits::ImageDepth,
+        Sampled: sealed_traits::ImageSampled,
+        Format: sealed_traits::ImageFormat,
+        Arrayed: sealed_traits::ImageArrayed,
+        Multisampled: sealed_traits::ImageMultisampled,
+    > Image<T, Dims, Depth, Sampled, Format, Arrayed, Multisampled>
+{
+    /// Query the dimensions of the image, with the number of array layers as
+    /// the last component for arrayed images.
+    ///
+    /// Only available on storage images, multisampled images, and rect or
+    /// buffer images; use [`Self::size_lod`] for other sampled images.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageQuerySize")]
+    pub fn size<V: Vector<u32, N>, const N: usize>(&self) -> V
+    where
+        Self: sealed_traits::QuerySize,
+        (Dims, Arrayed): sealed_traits::SizeComponents<N>,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm!(
+                "OpCapability ImageQuery",
+                "%image = OpLoad _ {this}",
+                "%result = OpImageQuerySize typeof*{result} %image",
+                "OpStore {result} %result",
+                this = in(reg) self,
+                result = in(reg) &mut result,
+            );
+        }
+        result
+    }
+
+    /// Query the dimensions of the given mip level of the image, with the
+    /// number of array layers as the last component for arrayed images.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageQuerySizeLod")]
+    pub fn size_lod<V: Vector<u32, N>, const N: usize>(&self, lod: u32) -> V
+    where
+        Self: sealed_traits::Mipmapped,
+        (Dims, Arrayed): sealed_traits::SizeComponents<N>,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm!(
+                "OpCapability ImageQuery",
+                "%image = OpLoad _ {this}",
+                "%lod = OpLoad _ {lod}",
+                "%result = OpImageQuerySizeLod typeof*{result} %image %lod",
+                "OpStore {result} %result",
+                this = in(reg) self,
+                lod = in(reg) &lod,
+                result = in(reg) &mut result,
+            );
+        }
+        result
+    }
+
+    /// Query the number of mipmap levels of the image.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpImageQueryLevels")]
+    pub fn query_levels(&self) -> u32
+    where
+        Self: sealed_traits::Mipmapped,
+    {
+        let mut result = 0;
+        unsafe {
+            asm!(
+                "OpCapability ImageQuery",
+                "%image = OpLoad _ {this}",
+                "%result = OpImageQueryLevels typeof*{result} %image",
+                "OpStore {result} %result",
+                this = in(reg) self,
+                result = in(reg) &mut result,
+            );
+        }
+        result
+    }
 }
 
-pub type Image2d =
-    Image<f32, dims::D2, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+/// A 2D sampled image. Sampling or fetching from it gives 4 component vectors
+/// of `T`, e.g. a `u32` vector for an `Image2d<u32>`; the same goes for the
+/// other sampled image types.
+pub type Image2d<T = f32> =
+    Image<T, dims::D2, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+pub type Image2dArray<T = f32> =
+    Image<T, dims::D2, depth::No, sample::Yes, format::Unknown, array::Yes, multisample::No>;
+
+/// A 2D depth image, suitable for depth-comparison ("shadow") sampling.
+pub type Image2dDepth =
+    Image<f32, dims::D2, depth::Yes, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+#[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+impl Image2d<f32> {
+    /// An image backed by `width * height` texels on the host, row by row
+    /// from the top left, for testing shader functions on the CPU. Images
+    /// are `Copy`, so the texels are leaked to be shared by every copy.
+    ///
+    /// Only [`Self::sample`], [`Self::sample_by_lod`] (which ignores the
+    /// level of detail, as there's only one) and [`Self::fetch`] can be used
+    /// on it, see also [`Sampler::host_nearest`] and [`Sampler::host_linear`].
+    pub fn from_host(texels: alloc::vec::Vec<[f32; 4]>, width: usize, height: usize) -> Self {
+        Self {
+            _opaque: 0,
+            marker: PhantomData,
+            host: crate::host_image::HostTexels::new(texels, width, height),
+        }
+    }
+}
 
-pub type Image2dArray =
-    Image<f32, dims::D2, depth::No, sample::Yes, format::Unknown, array::Yes, multisample::No>;
+impl<T: sealed_traits::SampledType + Scalar + Copy> Image2d<T> {
+    #[cfg(feature = "const-generics")]
+    #[cfg_attr(
+        all(not(target_arch = "spirv"), not(feature = "host-images")),
+        allow(unused_variables)
+    )]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, coordinate: impl Vector<f32, 2>) -> V {
+        #[cfg(target_arch = "spirv")]
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+            result
+        }
+        #[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+        {
+            let texel = self.host.sample(sampler.filter, coordinate);
+            crate::host_image::texel_vector(texel)
+        }
+        #[cfg(all(not(target_arch = "spirv"), not(feature = "host-images")))]
+        {
+            unimplemented!("`sample` is only available on SPIR-V platforms.")
+        }
+    }
+    /// Sample the image with a bias added to the implicit level of detail.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample_bias<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+        bias: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%bias = OpLoad _ {bias}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate Bias %bias",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                bias = in(reg) &bias,
+            );
+        }
+        result
+    }
+    /// Sample the image like [`Self::sample`], but never from a level of
+    /// detail below `min_lod`, e.g. to keep a streamed texture from accessing
+    /// high resolution mips that aren't resident yet.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "MinLod")]
+    pub fn sample_min_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+        min_lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "OpCapability MinLod",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%min_lod = OpLoad _ {min_lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate MinLod %min_lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                min_lod = in(reg) &min_lod,
+            );
+        }
+        result
+    }
+    /// Sample the image with a bias added to the implicit level of detail,
+    /// like [`Self::sample_bias`], but never from a level of detail below
+    /// `min_lod`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "MinLod")]
+    pub fn sample_bias_min_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+        bias: f32,
+        min_lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "OpCapability MinLod",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%bias = OpLoad _ {bias}",
+                "%min_lod = OpLoad _ {min_lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate Bias|MinLod %bias %min_lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                bias = in(reg) &bias,
+                min_lod = in(reg) &min_lod,
+            );
+        }
+        result
+    }
+    #[cfg(feature = "const-generics")]
+    #[cfg_attr(not(target_arch = "spirv"), allow(unused_variables))]
+    /// Sample the image at a coordinate by a lod
+    pub fn sample_by_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+        lod: f32,
+    ) -> V {
+        #[cfg(target_arch = "spirv")]
+        {
+            let mut result = unsafe { crate::arch::uninit() };
+            unsafe {
+                asm!(
+                    "%image = OpLoad _ {this}",
+                    "%sampler = OpLoad _ {sampler}",
+                    "%coordinate = OpLoad _ {coordinate}",
+                    "%lod = OpLoad _ {lod}",
+                    "%sampledImage = OpSampledImage _ %image %sampler",
+                    "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Lod %lod",
+                    "OpStore {result} %result",
+                    result = in(reg) &mut result,
+                    this = in(reg) self,
+                    sampler = in(reg) &sampler,
+                    coordinate = in(reg) &coordinate,
+                    lod = in(reg) &lod
+                );
+            }
+            result
+        }
+        // There's only one level of detail on the host.
+        #[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+        {
+            let texel = self.host.sample(sampler.filter, coordinate);
+            crate::host_image::texel_vector(texel)
+        }
+        #[cfg(all(not(target_arch = "spirv"), not(feature = "host-images")))]
+        {
+            unimplemented!("`sample_by_lod` is only available on SPIR-V platforms.")
+        }
+    }
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    /// Sample the image based on a gradient formed by (dx, dy). Specifically, ([du/dx, dv/dx], [du/dy, dv/dy])
+    pub fn sample_by_gradient<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+        gradient_dx: impl Vector<f32, 2>,
+        gradient_dy: impl Vector<f32, 2>,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%gradient_dx = OpLoad _ {gradient_dx}",
+                "%gradient_dy = OpLoad _ {gradient_dy}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Grad %gradient_dx %gradient_dy",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                gradient_dx = in(reg) &gradient_dx,
+                gradient_dy = in(reg) &gradient_dy,
+            );
+        }
+        result
+    }
+    /// Sample the image like [`Self::sample`], also returning a residency
+    /// code as `(residency_code, texel)`. Pass the code to
+    /// [`texels_resident`] to find out whether the texels needed for the
+    /// sample were resident in a sparse image.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSparseSampleImplicitLod")]
+    pub fn sample_sparse<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+    ) -> (u32, V) {
+        let mut residency_code = 0;
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "OpCapability SparseResidency",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%sparse = OpImageSparseSampleImplicitLod _ %sampledImage %coordinate",
+                "%residency_code = OpCompositeExtract typeof*{residency_code} %sparse 0",
+                "%result = OpCompositeExtract typeof*{result} %sparse 1",
+                "OpStore {residency_code} %residency_code",
+                "OpStore {result} %result",
+                residency_code = in(reg) &mut residency_code,
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+        }
+        (residency_code, result)
+    }
+    /// Sample the image with a projective coordinate, i.e. at
+    /// `(coordinate.x, coordinate.y) / coordinate.z`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleProjImplicitLod")]
+    pub fn sample_proj<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 3>,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleProjImplicitLod _ %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+        }
+        result
+    }
+    /// Sample the image with a projective coordinate by a lod, see
+    /// [`Self::sample_proj`].
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleProjExplicitLod")]
+    pub fn sample_proj_by_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 3>,
+        lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleProjExplicitLod _ %sampledImage %coordinate Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                lod = in(reg) &lod,
+            );
+        }
+        result
+    }
+    /// Sample the image at a coordinate, offset by the constant texel offset
+    /// `(X, Y)`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample_with_offset<V: Vector<T, 4>, C: Vector<f32, 2>, const X: i32, const Y: i32>(
+        &self,
+        sampler: Sampler,
+        coordinate: C,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%int = OpTypeInt 32 1",
+                "%v2int = OpTypeVector %int 2",
+                "%x = OpConstant %int {x}",
+                "%y = OpConstant %int {y}",
+                "%offset = OpConstantComposite %v2int %x %y",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate ConstOffset %offset",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                x = const X,
+                y = const Y,
+            );
+        }
+        result
+    }
+
+    /// Sample the image at a coordinate by a lod, offset by the constant
+    /// texel offset `(X, Y)`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample_by_lod_with_offset<
+        V: Vector<T, 4>,
+        C: Vector<f32, 2>,
+        const X: i32,
+        const Y: i32,
+    >(
+        &self,
+        sampler: Sampler,
+        coordinate: C,
+        lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%int = OpTypeInt 32 1",
+                "%v2int = OpTypeVector %int 2",
+                "%x = OpConstant %int {x}",
+                "%y = OpConstant %int {y}",
+                "%offset = OpConstantComposite %v2int %x %y",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Lod|ConstOffset %lod %offset",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                lod = in(reg) &lod,
+                x = const X,
+                y = const Y,
+            );
+        }
+        result
+    }
+
+    /// Sample the image at a coordinate, offset by a texel offset that is
+    /// only known at runtime. Prefer [`Self::sample_with_offset`] when the
+    /// offset is a constant.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample_with_dynamic_offset<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+        offset: impl Vector<i32, 2>,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "OpCapability ImageGatherExtended",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%offset = OpLoad _ {offset}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate Offset %offset",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                offset = in(reg) &offset,
+            );
+        }
+        result
+    }
+    /// Query the mipmap level and the level of detail that would be used when
+    /// sampling the image at a coordinate, returned as `(mip, lod)`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageQueryLod")]
+    pub fn query_lod<V: Vector<f32, 2>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 2>,
+    ) -> V {
+        let mut result = V::default();
+        unsafe {
+            asm!(
+                "OpCapability ImageQuery",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageQueryLod typeof*{result} %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+        }
+        result
+    }
+
+    /// Gather component `COMPONENT` (0..=3 selecting R, G, B or A) from the
+    /// four texels that would be used by a bilinear sample.
+    ///
+    /// The component is a constant, as Vulkan shaders require it to be one,
+    /// and any other value fails to compile.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageGather")]
+    pub fn gather<V, C, const COMPONENT: u32>(&self, sampler: Sampler, coordinate: C) -> V
+    where
+        V: Vector<T, 4>,
+        C: Vector<f32, 2>,
+    {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%u32 = OpTypeInt 32 0",
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%component = OpConstant %u32 {component}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageGather typeof*{result} %sampledImage %coordinate %component",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                component = const GatherComponent::<COMPONENT>::VALID,
+            );
+        }
+        result
+    }
+    /// Fetch a single texel with a sampler set at compile time
+    #[cfg(feature = "const-generics")]
+    #[cfg_attr(
+        all(not(target_arch = "spirv"), not(feature = "host-images")),
+        allow(unused_variables)
+    )]
+    pub fn fetch<V, I, const N: usize>(&self, coordinate: impl Vector<I, N>) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        #[cfg(target_arch = "spirv")]
+        {
+            let mut result = V::default();
+            unsafe {
+                asm! {
+                    "%image = OpLoad _ {this}",
+                    "%coordinate = OpLoad _ {coordinate}",
+                    "%result = OpImageFetch typeof*{result} %image %coordinate",
+                    "OpStore {result} %result",
+                    result = in(reg) &mut result,
+                    this = in(reg) self,
+                    coordinate = in(reg) &coordinate,
+                }
+            }
+
+            result
+        }
+        #[cfg(all(not(target_arch = "spirv"), feature = "host-images"))]
+        {
+            crate::host_image::texel_vector(self.host.fetch(coordinate))
+        }
+        #[cfg(all(not(target_arch = "spirv"), not(feature = "host-images")))]
+        {
+            unimplemented!("`fetch` is only available on SPIR-V platforms.")
+        }
+    }
+
+    /// Fetch a single texel from the mip level `lod`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageFetch")]
+    pub fn fetch_lod<V, I, const N: usize>(&self, coordinate: impl Vector<I, N>, lod: u32) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%lod = OpLoad _ {lod}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                lod = in(reg) &lod,
+            }
+        }
+
+        result
+    }
+
+    /// Fetch a single texel, offset by the constant texel offset `(X, Y)`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageFetch")]
+    pub fn fetch_with_offset<V, I, C, const X: i32, const Y: i32>(&self, coordinate: C) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+        C: Vector<I, 2>,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%int = OpTypeInt 32 1",
+                "%v2int = OpTypeVector %int 2",
+                "%x = OpConstant %int {x}",
+                "%y = OpConstant %int {y}",
+                "%offset = OpConstantComposite %v2int %x %y",
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate ConstOffset %offset",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                x = const X,
+                y = const Y,
+            }
+        }
+
+        result
+    }
+}
+
+/// The component selected by `Image2d::gather`, which has to be one of the
+/// four components of a texel.
+#[cfg(all(target_arch = "spirv", feature = "const-generics"))]
+struct GatherComponent<const COMPONENT: u32>;
+
+#[cfg(all(target_arch = "spirv", feature = "const-generics"))]
+impl<const COMPONENT: u32> GatherComponent<COMPONENT> {
+    /// `COMPONENT` itself, failing const evaluation (and so compilation)
+    /// through an out of bounds index if it's not in 0..=3.
+    const VALID: u32 = [0, 1, 2, 3][COMPONENT as usize];
+}
+
+impl<Depth: sealed_traits::DepthComparison>
+    Image<f32, dims::D2, Depth, sample::Yes, format::Unknown, array::No, multisample::No>
+{
+    /// Sample the image with a depth reference, comparing the sampled depth
+    /// against `depth_reference` using the sampler's comparison function.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleDrefImplicitLod")]
+    pub fn sample_depth_reference(
+        &self,
+        sampler: ComparisonSampler,
+        coordinate: impl Vector<f32, 2>,
+        depth_reference: f32,
+    ) -> f32 {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%depth_reference = OpLoad _ {depth_reference}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleDrefImplicitLod _ %sampledImage %coordinate %depth_reference",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                depth_reference = in(reg) &depth_reference,
+            );
+        }
+        result
+    }
+
+    /// Sample the image with a projective coordinate and depth reference,
+    /// i.e. at `(coordinate.x, coordinate.y) / coordinate.z` and comparing
+    /// against `depth_reference / coordinate.z`, as used for projected shadows.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleProjDrefImplicitLod")]
+    pub fn sample_proj_depth_reference(
+        &self,
+        sampler: ComparisonSampler,
+        coordinate: impl Vector<f32, 3>,
+        depth_reference: f32,
+    ) -> f32 {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%depth_reference = OpLoad _ {depth_reference}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleProjDrefImplicitLod _ %sampledImage %coordinate %depth_reference",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                depth_reference = in(reg) &depth_reference,
+            );
+        }
+        result
+    }
+
+    /// Gather the depth comparison results of the four texels that would be
+    /// used by a bilinear sample.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageDrefGather")]
+    pub fn gather_depth_compare<V: Vector<f32, 4>>(
+        &self,
+        sampler: ComparisonSampler,
+        coordinate: impl Vector<f32, 2>,
+        depth_reference: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%depth_reference = OpLoad _ {depth_reference}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageDrefGather typeof*{result} %sampledImage %coordinate %depth_reference",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                depth_reference = in(reg) &depth_reference,
+            );
+        }
+        result
+    }
+
+    /// Sample the image with a depth reference at an explicit lod.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleDrefExplicitLod")]
+    pub fn sample_depth_reference_by_lod(
+        &self,
+        sampler: ComparisonSampler,
+        coordinate: impl Vector<f32, 2>,
+        depth_reference: f32,
+        lod: f32,
+    ) -> f32 {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%depth_reference = OpLoad _ {depth_reference}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleDrefExplicitLod _ %sampledImage %coordinate %depth_reference Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                depth_reference = in(reg) &depth_reference,
+                lod = in(reg) &lod,
+            );
+        }
+        result
+    }
+}
+
+/// Result is true if all the texels accessed by the sparse image operation
+/// that returned `residency_code` were resident, otherwise result is false.
+#[spirv_std_macros::gpu_only]
+#[doc(alias = "OpImageSparseTexelsResident")]
+#[inline]
+pub fn texels_resident(residency_code: u32) -> bool {
+    let mut result = false;
+    unsafe {
+        asm!(
+            "OpCapability SparseResidency",
+            "%bool = OpTypeBool",
+            "%u8 = OpTypeInt 8 0",
+            "%u8_0 = OpConstant %u8 0",
+            "%u8_1 = OpConstant %u8 1",
+            "%resident = OpImageSparseTexelsResident %bool {residency_code}",
+            "%result = OpSelect %u8 %resident %u8_1 %u8_0",
+            "OpStore {result} %result",
+            residency_code = in(reg) residency_code,
+            result = in(reg) &mut result,
+        );
+    }
+    result
+}
+
+pub type Image1d<T = f32> =
+    Image<T, dims::D1, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> Image1d<T> {
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, coordinate: f32) -> V {
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+            result
+        }
+    }
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    /// Sample the image at a coordinate by a lod
+    pub fn sample_by_lod<V: Vector<T, 4>>(&self, sampler: Sampler, coordinate: f32, lod: f32) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                lod = in(reg) &lod
+            );
+        }
+        result
+    }
+    /// Fetch a single texel with a sampler set at compile time
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn fetch<V, I>(&self, coordinate: I) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+            }
+        }
+
+        result
+    }
+}
+
+pub type Image3d<T = f32> =
+    Image<T, dims::D3, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> Image3d<T> {
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, coordinate: impl Vector<f32, 3>) -> V {
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+            result
+        }
+    }
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    /// Sample the image at a coordinate by a lod
+    pub fn sample_by_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        coordinate: impl Vector<f32, 3>,
+        lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+                lod = in(reg) &lod
+            );
+        }
+        result
+    }
+    /// Fetch a single texel with a sampler set at compile time
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn fetch<V, I>(&self, coordinate: impl Vector<I, 3>) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+            }
+        }
+
+        result
+    }
+}
+
+/// A rectangle image ("texture rectangle"), addressed with unnormalized
+/// texel coordinates, e.g. for lookups into data laid out in 2D.
+///
+/// Rectangle images have no mipmaps, so they can't be sampled by a level of
+/// detail, nor fetched from a mip level.
+pub type ImageRect<T = f32> =
+    Image<T, dims::Rect, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> ImageRect<T> {
+    /// Sample the image at an unnormalized coordinate, i.e. in texels.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleImplicitLod")]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, coordinate: impl Vector<f32, 2>) -> V {
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+            result
+        }
+    }
+
+    /// Fetch a single texel, without a sampler.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageFetch")]
+    pub fn fetch<V, I>(&self, coordinate: impl Vector<I, 2>) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+            }
+        }
+
+        result
+    }
+}
+
+/// A cube map, sampled with a direction vector.
+pub type Cubemap<T = f32> =
+    Image<T, dims::Cube, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> Cubemap<T> {
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, direction: impl Vector<f32, 3>) -> V {
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%direction = OpLoad _ {direction}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %direction",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                direction = in(reg) &direction,
+            );
+            result
+        }
+    }
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    /// Sample the image in a direction by a lod
+    pub fn sample_by_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        direction: impl Vector<f32, 3>,
+        lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%direction = OpLoad _ {direction}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %direction Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                direction = in(reg) &direction,
+                lod = in(reg) &lod
+            );
+        }
+        result
+    }
+}
+
+/// An array of cube maps, sampled with a direction vector in `xyz` and the
+/// array layer in `w`.
+pub type CubemapArray<T = f32> =
+    Image<T, dims::Cube, depth::No, sample::Yes, format::Unknown, array::Yes, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> CubemapArray<T> {
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, direction: impl Vector<f32, 4>) -> V {
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%direction = OpLoad _ {direction}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %direction",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                direction = in(reg) &direction,
+            );
+            result
+        }
+    }
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    /// Sample the image in a direction by a lod
+    pub fn sample_by_lod<V: Vector<T, 4>>(
+        &self,
+        sampler: Sampler,
+        direction: impl Vector<f32, 4>,
+        lod: f32,
+    ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%direction = OpLoad _ {direction}",
+                "%lod = OpLoad _ {lod}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %direction Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                direction = in(reg) &direction,
+                lod = in(reg) &lod
+            );
+        }
+        result
+    }
+}
+
+/// A multisampled 2D image, whose individual samples are accessed with
+/// [`Image::fetch_multisample`].
+pub type Image2dMS<T = f32> =
+    Image<T, dims::D2, depth::No, sample::Yes, format::Unknown, array::No, multisample::Yes>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy, Multisampled: sealed_traits::Multisampled>
+    Image<T, dims::D2, depth::No, sample::Yes, format::Unknown, array::No, Multisampled>
+{
+    /// Fetch a single sample of a texel of a multisampled image.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn fetch_multisample<V, I>(&self, coordinate: impl Vector<I, 2>, sample: u32) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sample = OpLoad _ {sample}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate Sample %sample",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                sample = in(reg) &sample,
+            }
+        }
+        result
+    }
+
+    /// Query the number of samples per texel of a multisampled image.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpImageQuerySamples")]
+    pub fn sample_count(&self) -> u32 {
+        let mut result = 0;
+        unsafe {
+            asm! {
+                "OpCapability ImageQuery",
+                "%image = OpLoad _ {this}",
+                "%result = OpImageQuerySamples typeof*{result} %image",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+            }
+        }
+        result
+    }
+}
+
+/// A 2D storage image, read from and written to without a sampler.
+///
+/// The texel type is determined by `Format`: e.g. reading a
+/// `StorageImage2d<format::R32f>` gives an `f32`, and reading a
+/// `StorageImage2d<format::Rgba8ui>` gives a 4 component `u32` vector. Images
+/// with an `Unknown` format can be read as any number of `f32` components.
+pub type StorageImage2d<Format = format::Unknown> = Image<
+    <Format as sealed_traits::StorageFormat>::Component,
+    dims::D2,
+    depth::No,
+    sample::No,
+    Format,
+    array::No,
+    multisample::No,
+>;
+
+impl<Format: sealed_traits::StorageFormat> StorageImage2d<Format> {
+    /// Read a texel from an image without a sampler.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn read<I, V, const N: usize>(&self, coordinate: impl Vector<I, 2>) -> V
+    where
+        I: Integer,
+        V: Vector<Format::Component, N>,
+        Format: sealed_traits::FormatChannels<N>,
+    {
+        let mut result = V::default();
+
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageRead typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+
+    /// Write a texel to an image without a sampler.
+    ///
+    /// The texel must have the component type and number of components of
+    /// the image's `Format` (e.g. a `u32` for `format::R32ui`), so writing
+    /// anything else doesn't compile. Images with an `Unknown` format take
+    /// any number of `f32` components, but the driver needs at least as many
+    /// as the format of the image view bound to them, so write 4 if in doubt.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageWrite")]
+    pub unsafe fn write<I, const N: usize>(
+        &self,
+        coordinate: impl Vector<I, 2>,
+        texels: impl Vector<Format::Component, N>,
+    ) where
+        I: Integer,
+        Format: sealed_traits::FormatChannels<N>,
+    {
+        asm! {
+            "%image = OpLoad _ {this}",
+            "%coordinate = OpLoad _ {coordinate}",
+            "%texels = OpLoad _ {texels}",
+            "OpImageWrite %image %coordinate %texels",
+            this = in(reg) self,
+            coordinate = in(reg) &coordinate,
+            texels = in(reg) &texels,
+        }
+    }
 
-impl Image2d {
+    /// Atomically replace a texel of a single channel integer image with `value`.
+    ///
+    /// Returns the original value of the texel.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    pub fn sample<V: Vector<f32, 4>>(
+    #[doc(alias = "OpAtomicExchange")]
+    pub fn atomic_exchange<I: Integer>(
         &self,
-        sampler: Sampler,
-        coordinate: impl Vector<f32, 2>,
-    ) -> V {
+        coordinate: impl Vector<I, 2>,
+        sample: u32,
+        value: Format::Component,
+    ) -> Format::Component
+    where
+        Format: sealed_traits::FormatChannels<1>,
+        Format::Component: Integer,
+    {
+        let mut result = Default::default();
         unsafe {
-            let mut result = Default::default();
-            asm!(
-                "%image = OpLoad _ {this}",
-                "%sampler = OpLoad _ {sampler}",
+            asm! {
+                "OpCapability VulkanMemoryModelDeviceScope",
+                "%u32 = OpTypeInt 32 0",
+                // Device scope, relaxed memory semantics.
+                "%scope = OpConstant %u32 1",
+                "%semantics = OpConstant %u32 0",
+                "%texel_ptr_ty = OpTypePointer Image typeof*{value}",
                 "%coordinate = OpLoad _ {coordinate}",
-                "%sampledImage = OpSampledImage _ %image %sampler",
-                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "%sample = OpLoad _ {sample}",
+                "%value = OpLoad _ {value}",
+                "%texel = OpImageTexelPointer %texel_ptr_ty {this} %coordinate %sample",
+                "%result = OpAtomicExchange typeof*{result} %texel %scope %semantics %value",
                 "OpStore {result} %result",
-                result = in(reg) &mut result,
                 this = in(reg) self,
-                sampler = in(reg) &sampler,
                 coordinate = in(reg) &coordinate,
-            );
-            result
+                sample = in(reg) &sample,
+                value = in(reg) &value,
+                result = in(reg) &mut result,
+            }
         }
+        result
     }
+
+    /// Atomically add `value` to a texel of a single channel integer image.
+    ///
+    /// Returns the original value of the texel.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    /// Sample the image at a coordinate by a lod
-    pub fn sample_by_lod<V: Vector<f32, 4>>(
+    #[doc(alias = "OpAtomicIAdd")]
+    pub fn atomic_add<I: Integer>(
         &self,
-        sampler: Sampler,
-        coordinate: impl Vector<f32, 2>,
-        lod: f32,
-    ) -> V {
+        coordinate: impl Vector<I, 2>,
+        sample: u32,
+        value: Format::Component,
+    ) -> Format::Component
+    where
+        Format: sealed_traits::FormatChannels<1>,
+        Format::Component: Integer,
+    {
         let mut result = Default::default();
         unsafe {
-            asm!(
-                "%image = OpLoad _ {this}",
-                "%sampler = OpLoad _ {sampler}",
+            asm! {
+                "OpCapability VulkanMemoryModelDeviceScope",
+                "%u32 = OpTypeInt 32 0",
+                // Device scope, relaxed memory semantics.
+                "%scope = OpConstant %u32 1",
+                "%semantics = OpConstant %u32 0",
+                "%texel_ptr_ty = OpTypePointer Image typeof*{value}",
                 "%coordinate = OpLoad _ {coordinate}",
-                "%lod = OpLoad _ {lod}",
-                "%sampledImage = OpSampledImage _ %image %sampler",
-                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Lod %lod",
+                "%sample = OpLoad _ {sample}",
+                "%value = OpLoad _ {value}",
+                "%texel = OpImageTexelPointer %texel_ptr_ty {this} %coordinate %sample",
+                "%result = OpAtomicIAdd typeof*{result} %texel %scope %semantics %value",
                 "OpStore {result} %result",
-                result = in(reg) &mut result,
                 this = in(reg) self,
-                sampler = in(reg) &sampler,
                 coordinate = in(reg) &coordinate,
-                lod = in(reg) &lod
-            );
+                sample = in(reg) &sample,
+                value = in(reg) &value,
+                result = in(reg) &mut result,
+            }
         }
         result
     }
+
+    /// Atomically replace a texel of a single channel integer image with
+    /// `value`, if the texel is equal to `comparator`.
+    ///
+    /// Returns the original value of the texel.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    /// Sample the image based on a gradient formed by (dx, dy). Specifically, ([du/dx, dv/dx], [du/dy, dv/dy])
-    pub fn sample_by_gradient<V: Vector<f32, 4>>(
+    #[doc(alias = "OpAtomicCompareExchange")]
+    pub fn atomic_compare_exchange<I: Integer>(
         &self,
-        sampler: Sampler,
-        coordinate: impl Vector<f32, 2>,
-        gradient_dx: impl Vector<f32, 2>,
-        gradient_dy: impl Vector<f32, 2>,
-    ) -> V {
+        coordinate: impl Vector<I, 2>,
+        sample: u32,
+        value: Format::Component,
+        comparator: Format::Component,
+    ) -> Format::Component
+    where
+        Format: sealed_traits::FormatChannels<1>,
+        Format::Component: Integer,
+    {
         let mut result = Default::default();
         unsafe {
-            asm!(
-                "%image = OpLoad _ {this}",
-                "%sampler = OpLoad _ {sampler}",
+            asm! {
+                "OpCapability VulkanMemoryModelDeviceScope",
+                "%u32 = OpTypeInt 32 0",
+                // Device scope, relaxed memory semantics.
+                "%scope = OpConstant %u32 1",
+                "%semantics = OpConstant %u32 0",
+                "%texel_ptr_ty = OpTypePointer Image typeof*{value}",
                 "%coordinate = OpLoad _ {coordinate}",
-                "%gradient_dx = OpLoad _ {gradient_dx}",
-                "%gradient_dy = OpLoad _ {gradient_dy}",
-                "%sampledImage = OpSampledImage _ %image %sampler",
-                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Grad %gradient_dx %gradient_dy",
+                "%sample = OpLoad _ {sample}",
+                "%value = OpLoad _ {value}",
+                "%comparator = OpLoad _ {comparator}",
+                "%texel = OpImageTexelPointer %texel_ptr_ty {this} %coordinate %sample",
+                "%result = OpAtomicCompareExchange typeof*{result} %texel %scope %semantics %semantics %value %comparator",
                 "OpStore {result} %result",
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                sample = in(reg) &sample,
+                value = in(reg) &value,
+                comparator = in(reg) &comparator,
                 result = in(reg) &mut result,
+            }
+        }
+        result
+    }
+}
+
+/// An arrayed 2D storage image, read from and written to without a sampler,
+/// with the array layer as the last coordinate component.
+///
+/// The texel type is determined by `Format`, as for [`StorageImage2d`].
+pub type StorageImage2dArray<Format = format::Unknown> = Image<
+    <Format as sealed_traits::StorageFormat>::Component,
+    dims::D2,
+    depth::No,
+    sample::No,
+    Format,
+    array::Yes,
+    multisample::No,
+>;
+
+impl<Format: sealed_traits::StorageFormat> StorageImage2dArray<Format> {
+    /// Read a texel from the layer `coordinate.z` of an image without a sampler.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageRead")]
+    pub fn read<I, V, const N: usize>(&self, coordinate: impl Vector<I, 3>) -> V
+    where
+        I: Integer,
+        V: Vector<Format::Component, N>,
+        Format: sealed_traits::FormatChannels<N>,
+    {
+        let mut result = V::default();
+
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageRead typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
                 this = in(reg) self,
-                sampler = in(reg) &sampler,
                 coordinate = in(reg) &coordinate,
-                gradient_dx = in(reg) &gradient_dx,
-                gradient_dy = in(reg) &gradient_dy,
-            );
+                result = in(reg) &mut result,
+            }
         }
+
         result
     }
-    /// Fetch a single texel with a sampler set at compile time
+
+    /// Write a texel to the layer `coordinate.z` of an image without a sampler.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    pub fn fetch<V, I, const N: usize>(&self, coordinate: impl Vector<I, N>) -> V
+    #[doc(alias = "OpImageWrite")]
+    pub unsafe fn write<I, const N: usize>(
+        &self,
+        coordinate: impl Vector<I, 3>,
+        texels: impl Vector<Format::Component, N>,
+    ) where
+        I: Integer,
+        Format: sealed_traits::FormatChannels<N>,
+    {
+        asm! {
+            "%image = OpLoad _ {this}",
+            "%coordinate = OpLoad _ {coordinate}",
+            "%texels = OpLoad _ {texels}",
+            "OpImageWrite %image %coordinate %texels",
+            this = in(reg) self,
+            coordinate = in(reg) &coordinate,
+            texels = in(reg) &texels,
+        }
+    }
+}
+
+/// A uniform texel buffer, i.e. a buffer accessed as a one-dimensional image
+/// of formatted texels, indexed by a single integer.
+pub type TexelBuffer<T = f32> =
+    Image<T, dims::Buffer, depth::No, sample::Yes, format::Unknown, array::No, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> TexelBuffer<T> {
+    /// Fetch the texel at `coordinate`.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageFetch")]
+    pub fn fetch<V, I>(&self, coordinate: I) -> V
     where
-        V: Vector<f32, 4>,
+        V: Vector<T, 4>,
         I: Integer,
     {
         let mut result = V::default();
@@ -152,17 +1587,30 @@ impl Image2d {
     }
 }
 
-pub type StorageImage2d =
-    Image<f32, dims::D2, depth::No, sample::No, format::Unknown, array::No, multisample::No>;
+/// A storage texel buffer, i.e. a buffer read from and written to as a
+/// one-dimensional storage image, indexed by a single integer.
+///
+/// The texel type is determined by `Format`, as for [`StorageImage2d`].
+pub type StorageTexelBuffer<Format = format::Unknown> = Image<
+    <Format as sealed_traits::StorageFormat>::Component,
+    dims::Buffer,
+    depth::No,
+    sample::No,
+    Format,
+    array::No,
+    multisample::No,
+>;
 
-impl StorageImage2d {
-    /// Read a texel from an image without a sampler.
+impl<Format: sealed_traits::StorageFormat> StorageTexelBuffer<Format> {
+    /// Read the texel at `coordinate`.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    pub fn read<I, V, const N: usize>(&self, coordinate: impl Vector<I, 2>) -> V
+    #[doc(alias = "OpImageRead")]
+    pub fn read<I, V, const N: usize>(&self, coordinate: I) -> V
     where
         I: Integer,
-        V: Vector<f32, N>,
+        V: Vector<Format::Component, N>,
+        Format: sealed_traits::FormatChannels<N>,
     {
         let mut result = V::default();
 
@@ -181,15 +1629,17 @@ impl StorageImage2d {
         result
     }
 
-    /// Write a texel to an image without a sampler.
+    /// Write the texel at `coordinate`.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageWrite")]
     pub unsafe fn write<I, const N: usize>(
         &self,
-        coordinate: impl Vector<I, 2>,
-        texels: impl Vector<f32, N>,
+        coordinate: I,
+        texels: impl Vector<Format::Component, N>,
     ) where
         I: Integer,
+        Format: sealed_traits::FormatChannels<N>,
     {
         asm! {
             "%image = OpLoad _ {this}",
@@ -203,41 +1653,99 @@ impl StorageImage2d {
     }
 }
 
-impl Image2dArray {
+/// The input attachment of a Vulkan subpass, i.e. the output of an earlier
+/// subpass, read at the current fragment's location (e.g. the G-buffer in
+/// deferred shading).
+///
+/// Entry point parameters of this type must also have an
+/// `#[spirv(input_attachment_index = N)]` attribute.
+pub type InputAttachment<T = f32> =
+    Image<T, dims::Subpass, depth::No, sample::No, format::Unknown, array::No, multisample::No>;
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> InputAttachment<T> {
+    /// Read the texel at the offset `coordinate` from the current fragment's
+    /// location, which is usually `(0, 0)` (as GLSL's `subpassLoad` does).
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageRead")]
+    pub fn load<V: Vector<T, 4>>(&self, coordinate: impl Vector<i32, 2>) -> V {
+        let mut result = V::default();
+
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageRead typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+}
+
+impl<T: sealed_traits::SampledType + Scalar + Copy> Image2dArray<T> {
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    pub fn sample<V: Vector<T, 4>>(&self, sampler: Sampler, coordinate: impl Vector<f32, 3>) -> V {
+        unsafe {
+            let mut result = crate::arch::uninit();
+            asm!(
+                "%image = OpLoad _ {this}",
+                "%sampler = OpLoad _ {sampler}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%sampledImage = OpSampledImage _ %image %sampler",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                sampler = in(reg) &sampler,
+                coordinate = in(reg) &coordinate,
+            );
+            result
+        }
+    }
+    /// Sample the image with a bias added to the implicit level of detail.
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    pub fn sample<V: Vector<f32, 4>>(
+    pub fn sample_bias<V: Vector<T, 4>>(
         &self,
         sampler: Sampler,
         coordinate: impl Vector<f32, 3>,
+        bias: f32,
     ) -> V {
+        let mut result = unsafe { crate::arch::uninit() };
         unsafe {
-            let mut result = V::default();
             asm!(
                 "%image = OpLoad _ {this}",
                 "%sampler = OpLoad _ {sampler}",
                 "%coordinate = OpLoad _ {coordinate}",
+                "%bias = OpLoad _ {bias}",
                 "%sampledImage = OpSampledImage _ %image %sampler",
-                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate",
+                "%result = OpImageSampleImplicitLod _ %sampledImage %coordinate Bias %bias",
                 "OpStore {result} %result",
                 result = in(reg) &mut result,
                 this = in(reg) self,
                 sampler = in(reg) &sampler,
                 coordinate = in(reg) &coordinate,
+                bias = in(reg) &bias,
             );
-            result
         }
+        result
     }
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
     /// Sample the image at a coordinate by a lod
-    pub fn sample_by_lod<V: Vector<f32, 4>>(
+    pub fn sample_by_lod<V: Vector<T, 4>>(
         &self,
         sampler: Sampler,
         coordinate: impl Vector<f32, 3>,
         lod: f32,
     ) -> V {
-        let mut result = Default::default();
+        let mut result = unsafe { crate::arch::uninit() };
         unsafe {
             asm!(
                 "%image = OpLoad _ {this}",
@@ -259,14 +1767,14 @@ impl Image2dArray {
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
     /// Sample the image based on a gradient formed by (dx, dy). Specifically, ([du/dx, dv/dx], [du/dy, dv/dy])
-    pub fn sample_by_gradient<V: Vector<f32, 4>>(
+    pub fn sample_by_gradient<V: Vector<T, 4>>(
         &self,
         sampler: Sampler,
         coordinate: impl Vector<f32, 3>,
         gradient_dx: impl Vector<f32, 2>,
         gradient_dy: impl Vector<f32, 2>,
     ) -> V {
-        let mut result = Default::default();
+        let mut result = unsafe { crate::arch::uninit() };
         unsafe {
             asm!(
                 "%image = OpLoad _ {this}",
@@ -287,14 +1795,53 @@ impl Image2dArray {
         }
         result
     }
+    /// Fetch a single texel from the layer `coordinate.z`, with a sampler set
+    /// at compile time.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageFetch")]
+    pub fn fetch<V, I>(&self, coordinate: impl Vector<I, 3>) -> V
+    where
+        V: Vector<T, 4>,
+        I: Integer,
+    {
+        let mut result = V::default();
+        unsafe {
+            asm! {
+                "%image = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%result = OpImageFetch typeof*{result} %image %coordinate",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+            }
+        }
+
+        result
+    }
 }
 
-impl SampledImage<Image2d> {
+impl<
+        T: sealed_traits::SampledType + Scalar + Copy,
+        Dims: sealed_traits::ImageDims + Copy,
+        Depth: sealed_traits::ImageDepth + Copy,
+        Format: sealed_traits::ImageFormat + Copy,
+        Arrayed: sealed_traits::ImageArrayed + Copy,
+    > SampledImage<Image<T, Dims, Depth, sample::Yes, Format, Arrayed, multisample::No>>
+{
+    /// Sample the combined image and sampler at a coordinate, with as many
+    /// components as the image has dimensions (plus one for the layer of
+    /// arrayed images).
     #[spirv_std_macros::gpu_only]
     #[cfg(feature = "const-generics")]
-    pub fn sample<V: Vector<f32, 4>>(&self, coordinate: impl Vector<f32, 2>) -> V {
+    #[doc(alias = "OpImageSampleImplicitLod")]
+    pub fn sample<V: Vector<T, 4>, const N: usize>(&self, coordinate: impl Vector<f32, N>) -> V
+    where
+        (Dims, Arrayed): sealed_traits::SampleCoordinates<N>,
+    {
         unsafe {
-            let mut result = Default::default();
+            let mut result = crate::arch::uninit();
             asm!(
                 "%sampledImage = OpLoad _ {this}",
                 "%coordinate = OpLoad _ {coordinate}",
@@ -307,6 +1854,38 @@ impl SampledImage<Image2d> {
             result
         }
     }
+
+    /// Sample the combined image and sampler at a coordinate by a lod, see
+    /// [`Self::sample`]. Not available for images without mipmaps, i.e.
+    /// [`ImageRect`]s.
+    #[spirv_std_macros::gpu_only]
+    #[cfg(feature = "const-generics")]
+    #[doc(alias = "OpImageSampleExplicitLod")]
+    pub fn sample_by_lod<V: Vector<T, 4>, const N: usize>(
+        &self,
+        coordinate: impl Vector<f32, N>,
+        lod: f32,
+    ) -> V
+    where
+        Dims: sealed_traits::MipmappedDims,
+        (Dims, Arrayed): sealed_traits::SampleCoordinates<N>,
+    {
+        let mut result = unsafe { crate::arch::uninit() };
+        unsafe {
+            asm!(
+                "%sampledImage = OpLoad _ {this}",
+                "%coordinate = OpLoad _ {coordinate}",
+                "%lod = OpLoad _ {lod}",
+                "%result = OpImageSampleExplicitLod _ %sampledImage %coordinate Lod %lod",
+                "OpStore {result} %result",
+                result = in(reg) &mut result,
+                this = in(reg) self,
+                coordinate = in(reg) &coordinate,
+                lod = in(reg) &lod,
+            );
+        }
+        result
+    }
 }
 
 use image_options::*;
@@ -420,6 +1999,8 @@ mod sealed_structs {
 }
 
 mod sealed_traits {
+    use super::image_options::*;
+
     pub trait Image {}
     impl<
             'a,
@@ -437,6 +2018,73 @@ mod sealed_traits {
     pub trait ImageFormat {}
     impl<const FORMAT: usize> ImageFormat for super::sealed_structs::ImageFormat<FORMAT> {}
 
+    /// The component type and channel count of the texels of storage images
+    /// with a given format.
+    pub trait StorageFormat: ImageFormat {
+        type Component: SampledType + crate::scalar::Scalar;
+    }
+    pub trait FormatChannels<const N: usize>: StorageFormat {}
+
+    impl StorageFormat for format::Unknown {
+        type Component = f32;
+    }
+    impl<const N: usize> FormatChannels<N> for format::Unknown {}
+
+    macro_rules! storage_formats {
+        ($($format:ident => $component:ty, $channels:literal;)+) => {
+            $(
+                impl StorageFormat for format::$format {
+                    type Component = $component;
+                }
+                impl FormatChannels<$channels> for format::$format {}
+            )+
+        };
+    }
+
+    storage_formats! {
+        Rgba32f => f32, 4;
+        Rgba16f => f32, 4;
+        R32f => f32, 1;
+        Rgba8 => f32, 4;
+        Rgba8Snorm => f32, 4;
+        Rg32f => f32, 2;
+        Rg16f => f32, 2;
+        R11fG11fB10f => f32, 3;
+        R16f => f32, 1;
+        Rgba16 => f32, 4;
+        Rgb10A2 => f32, 4;
+        Rg16 => f32, 2;
+        Rg8 => f32, 2;
+        R16 => f32, 1;
+        R8 => f32, 1;
+        Rgba16Snorm => f32, 4;
+        Rg16Snorm => f32, 2;
+        Rg8Snorm => f32, 2;
+        R16Snorm => f32, 1;
+        R8Snorm => f32, 1;
+        Rgba32i => i32, 4;
+        Rgba16i => i32, 4;
+        Rgba8i => i32, 4;
+        R32i => i32, 1;
+        Rg32i => i32, 2;
+        Rg16i => i32, 2;
+        Rg8i => i32, 2;
+        R16i => i32, 1;
+        R8i => i32, 1;
+        Rgba32ui => u32, 4;
+        Rgba16ui => u32, 4;
+        Rgba8ui => u32, 4;
+        R32ui => u32, 1;
+        Rgb10a2ui => u32, 4;
+        Rg32ui => u32, 2;
+        Rg16ui => u32, 2;
+        Rg8ui => u32, 2;
+        R16ui => u32, 1;
+        R8ui => u32, 1;
+        R64ui => u64, 1;
+        R64i => i64, 1;
+    }
+
     pub trait ImageDims {}
     impl<const DIMS: usize> ImageDims for super::sealed_structs::ImageDims<DIMS> {}
 
@@ -456,10 +2104,95 @@ mod sealed_traits {
     pub trait ImageDepth {}
     impl<const DEPTH: usize> ImageDepth for super::sealed_structs::ImageDepth<DEPTH> {}
 
+    /// Implemented only for depth images, which are the only images that can
+    /// be sampled with a depth reference (`Dref`).
+    pub trait DepthComparison: ImageDepth {}
+    impl DepthComparison for super::depth::Yes {}
+
     pub trait ImageSampled {}
     impl<const SAMPLED: usize> ImageSampled for super::sealed_structs::ImageSampled<SAMPLED> {}
     pub trait ImageArrayed {}
     impl<const ARRAYED: usize> ImageArrayed for super::sealed_structs::ImageArrayed<ARRAYED> {}
     pub trait ImageMultisampled {}
     impl<const MS: usize> ImageMultisampled for super::sealed_structs::ImageMultisampled<MS> {}
+
+    /// Implemented only for multisampled images, which are the only images
+    /// that can be accessed per sample.
+    pub trait Multisampled: ImageMultisampled {}
+    impl Multisampled for multisample::Yes {}
+
+    /// The number of components returned by an image size query.
+    pub trait SizeComponents<const N: usize> {}
+    impl SizeComponents<1> for (dims::D1, array::No) {}
+    impl SizeComponents<2> for (dims::D1, array::Yes) {}
+    impl SizeComponents<2> for (dims::D2, array::No) {}
+    impl SizeComponents<3> for (dims::D2, array::Yes) {}
+    impl SizeComponents<3> for (dims::D3, array::No) {}
+    impl SizeComponents<2> for (dims::Cube, array::No) {}
+    impl SizeComponents<3> for (dims::Cube, array::Yes) {}
+    impl SizeComponents<2> for (dims::Rect, array::No) {}
+    impl SizeComponents<1> for (dims::Buffer, array::No) {}
+
+    /// The number of coordinate components used to sample an image.
+    pub trait SampleCoordinates<const N: usize> {}
+    impl SampleCoordinates<1> for (dims::D1, array::No) {}
+    impl SampleCoordinates<2> for (dims::D1, array::Yes) {}
+    impl SampleCoordinates<2> for (dims::D2, array::No) {}
+    impl SampleCoordinates<3> for (dims::D2, array::Yes) {}
+    impl SampleCoordinates<3> for (dims::D3, array::No) {}
+    impl SampleCoordinates<3> for (dims::Cube, array::No) {}
+    impl SampleCoordinates<4> for (dims::Cube, array::Yes) {}
+    impl SampleCoordinates<2> for (dims::Rect, array::No) {}
+
+    /// Images that can be queried with `OpImageQuerySize`.
+    pub trait QuerySize {}
+    impl<
+            T: SampledType + Copy,
+            Dims: ImageDims,
+            Depth: ImageDepth,
+            Format: ImageFormat,
+            Arrayed: ImageArrayed,
+        > QuerySize for super::Image<T, Dims, Depth, sample::No, Format, Arrayed, multisample::No>
+    {
+    }
+    impl<
+            T: SampledType + Copy,
+            Dims: ImageDims,
+            Depth: ImageDepth,
+            Sampled: ImageSampled,
+            Format: ImageFormat,
+            Arrayed: ImageArrayed,
+        > QuerySize for super::Image<T, Dims, Depth, Sampled, Format, Arrayed, multisample::Yes>
+    {
+    }
+    impl<T: SampledType + Copy, Depth: ImageDepth, Format: ImageFormat, Arrayed: ImageArrayed>
+        QuerySize
+        for super::Image<T, dims::Rect, Depth, sample::Yes, Format, Arrayed, multisample::No>
+    {
+    }
+    impl<T: SampledType + Copy, Depth: ImageDepth, Format: ImageFormat, Arrayed: ImageArrayed>
+        QuerySize
+        for super::Image<T, dims::Buffer, Depth, sample::Yes, Format, Arrayed, multisample::No>
+    {
+    }
+
+    /// Images that can have mipmaps, and so can be queried per level of detail.
+    pub trait Mipmapped {}
+    impl<
+            T: SampledType + Copy,
+            Dims: MipmappedDims,
+            Depth: ImageDepth,
+            Format: ImageFormat,
+            Arrayed: ImageArrayed,
+        > Mipmapped
+        for super::Image<T, Dims, Depth, sample::Yes, Format, Arrayed, multisample::No>
+    {
+    }
+
+    /// Dimensionalities that can have mipmaps.
+    pub trait MipmappedDims: ImageDims {}
+    impl MipmappedDims for dims::D1 {}
+    impl MipmappedDims for dims::D2 {}
+    impl MipmappedDims for dims::D3 {}
+    impl MipmappedDims for dims::Cube {}
 }
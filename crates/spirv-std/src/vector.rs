@@ -1,3 +1,237 @@
+#[cfg(feature = "const-generics")]
+use crate::float::f16;
+
 /// Abstract trait representing a SPIR-V vector type.
+///
+/// `glam` implements this for its vector types when built for SPIR-V (as it
+/// depends on `spirv-std`, not the other way around).
 #[cfg(feature = "const-generics")]
 pub unsafe trait Vector<T: crate::scalar::Scalar, const N: usize>: Default {}
+
+// A scalar acts as a single component vector, such as the texel of a single
+// channel storage image.
+#[cfg(feature = "const-generics")]
+macro_rules! impl_scalar_vector {
+    ($($ty:ty),+) => {
+        $(unsafe impl Vector<$ty, 1> for $ty {})+
+    };
+}
+
+#[cfg(feature = "const-generics")]
+impl_scalar_vector!(bool, f16, f32, f64, u8, u16, u32, u64, i8, i16, i32, i64);
+
+// Named swizzles, each forwarding to the `swizzleN` method for its length.
+#[cfg(feature = "const-generics")]
+macro_rules! swizzles {
+    ($($swizzle:ident, $len:literal => $($name:ident($($index:literal),+)),+;)+) => {
+        $($(
+            #[doc = "Swizzle of the components named by this method."]
+            #[inline]
+            fn $name<V: Vector<T, $len>>(self) -> V {
+                self.$swizzle::<V, $($index),+>()
+            }
+        )+)+
+    };
+}
+
+/// Swizzles of a SPIR-V vector, building a new vector out of some of its
+/// components in any order, like `v.xyz()` or `v.wzyx()` in GLSL.
+///
+/// Components are numbered from 0 (`x`) to 3 (`w`); using a component the
+/// vector doesn't have (e.g. `z` of a 2-component vector) is invalid. The
+/// type of the resulting vector is inferred, e.g. `let rgb: Vec3 = rgba.xyz()`.
+/// Unlike glam's own swizzle traits, each swizzle is a single
+/// `OpVectorShuffle`, so only import one of them to avoid ambiguity.
+#[cfg(feature = "const-generics")]
+pub trait Swizzle<T: crate::scalar::Scalar, const N: usize>: Vector<T, N> + Sized {
+    /// A 2-component vector of components `X` and `Y`.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpVectorShuffle")]
+    #[inline]
+    fn swizzle2<V: Vector<T, 2>, const X: u32, const Y: u32>(self) -> V {
+        let mut result = V::default();
+
+        unsafe {
+            asm! {
+                "%vector = OpLoad _ {vector}",
+                "%result = OpVectorShuffle typeof*{result} %vector %vector {x} {y}",
+                "OpStore {result} %result",
+                vector = in(reg) &self,
+                x = const X,
+                y = const Y,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+
+    /// A 3-component vector of components `X`, `Y` and `Z`.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpVectorShuffle")]
+    #[inline]
+    fn swizzle3<V: Vector<T, 3>, const X: u32, const Y: u32, const Z: u32>(self) -> V {
+        let mut result = V::default();
+
+        unsafe {
+            asm! {
+                "%vector = OpLoad _ {vector}",
+                "%result = OpVectorShuffle typeof*{result} %vector %vector {x} {y} {z}",
+                "OpStore {result} %result",
+                vector = in(reg) &self,
+                x = const X,
+                y = const Y,
+                z = const Z,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+
+    /// A 4-component vector of components `X`, `Y`, `Z` and `W`.
+    #[spirv_std_macros::gpu_only]
+    #[doc(alias = "OpVectorShuffle")]
+    #[inline]
+    fn swizzle4<V: Vector<T, 4>, const X: u32, const Y: u32, const Z: u32, const W: u32>(
+        self,
+    ) -> V {
+        let mut result = V::default();
+
+        unsafe {
+            asm! {
+                "%vector = OpLoad _ {vector}",
+                "%result = OpVectorShuffle typeof*{result} %vector %vector {x} {y} {z} {w}",
+                "OpStore {result} %result",
+                vector = in(reg) &self,
+                x = const X,
+                y = const Y,
+                z = const Z,
+                w = const W,
+                result = in(reg) &mut result,
+            }
+        }
+
+        result
+    }
+
+    swizzles! {
+        swizzle2, 2 =>
+            xx(0, 0),
+            xy(0, 1),
+            xz(0, 2),
+            yx(1, 0),
+            yy(1, 1),
+            yz(1, 2),
+            zw(2, 3),
+            zz(2, 2),
+            ww(3, 3);
+        swizzle3, 3 =>
+            xxx(0, 0, 0),
+            xyz(0, 1, 2),
+            yzw(1, 2, 3),
+            zyx(2, 1, 0),
+            yyy(1, 1, 1),
+            zzz(2, 2, 2),
+            www(3, 3, 3);
+        swizzle4, 4 =>
+            xxxx(0, 0, 0, 0),
+            xyzw(0, 1, 2, 3),
+            wzyx(3, 2, 1, 0),
+            yyyy(1, 1, 1, 1),
+            zzzz(2, 2, 2, 2),
+            wwww(3, 3, 3, 3);
+    }
+}
+
+#[cfg(feature = "const-generics")]
+impl<T: crate::scalar::Scalar, V: Vector<T, N>, const N: usize> Swizzle<T, N> for V {}
+
+/// Geometric operations on SPIR-V vectors of floats, which only have a
+/// meaning for vectors with at least 2 components.
+///
+/// These are methods of a trait, so glam's own inherent methods of the same
+/// names take precedence on glam types, but they're available to code generic
+/// over [`Vector`]s, e.g. sampling coordinates.
+#[cfg(feature = "const-generics")]
+pub trait FloatVector<F: crate::float::Float, const N: usize>: Vector<F, N> + Sized {
+    /// Dot product of `self` and `other`, see [`crate::arch::dot`].
+    #[inline]
+    fn dot(self, other: Self) -> F {
+        crate::arch::dot(self, other)
+    }
+
+    /// The length of `self`, see [`crate::arch::length`].
+    #[inline]
+    fn length(self) -> F {
+        crate::arch::length(self)
+    }
+
+    /// `self` scaled to a length of 1, see [`crate::arch::normalize`].
+    #[inline]
+    fn normalize(self) -> Self {
+        crate::arch::normalize(self)
+    }
+
+    /// Cross product of `self` and `other`, see [`crate::arch::cross`].
+    #[inline]
+    fn cross(self, other: Self) -> Self
+    where
+        Self: Vector<F, 3>,
+    {
+        crate::arch::cross(self, other)
+    }
+}
+
+#[cfg(feature = "const-generics")]
+impl<F: crate::float::Float, V: Vector<F, N>, const N: usize> FloatVector<F, N> for V {}
+
+/// Component-wise rounding of floats and vectors of floats, each a single
+/// `GLSL.std.450` instruction rather than the software (`libm`) versions
+/// `num_traits::Float` provides without `std`.
+///
+/// `f32` and `f64` also get methods of the same names from
+/// `num_traits::Float`, so don't import both traits in the same scope.
+#[cfg(feature = "const-generics")]
+pub trait FloatMath<F: crate::float::Float, const N: usize>: Vector<F, N> + Sized {
+    /// 1, 0 or -1 depending on the sign of each component, see
+    /// [`crate::arch::sign`].
+    #[inline]
+    fn signum(self) -> Self {
+        crate::arch::sign(self)
+    }
+
+    /// Each component rounded down, see [`crate::arch::floor`].
+    #[inline]
+    fn floor(self) -> Self {
+        crate::arch::floor(self)
+    }
+
+    /// Each component rounded up, see [`crate::arch::ceil`].
+    #[inline]
+    fn ceil(self) -> Self {
+        crate::arch::ceil(self)
+    }
+
+    /// Each component rounded to the nearest integer, see
+    /// [`crate::arch::round`].
+    #[inline]
+    fn round(self) -> Self {
+        crate::arch::round(self)
+    }
+
+    /// Each component rounded towards zero, see [`crate::arch::trunc`].
+    #[inline]
+    fn trunc(self) -> Self {
+        crate::arch::trunc(self)
+    }
+
+    /// The fractional part of each component, see [`crate::arch::fract`].
+    #[inline]
+    fn fract(self) -> Self {
+        crate::arch::fract(self)
+    }
+}
+
+#[cfg(feature = "const-generics")]
+impl<F: crate::float::Float, V: Vector<F, N>, const N: usize> FloatMath<F, N> for V {}
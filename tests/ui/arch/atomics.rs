@@ -0,0 +1,15 @@
+// Test the `OpAtomic*` instructions on `Workgroup` memory.
+// build-pass
+
+use spirv_std::arch;
+use spirv_std::storage_class::Workgroup;
+
+#[spirv(compute(threads(64)))]
+pub fn main(mut counter: Workgroup<u32>, mut signed: Workgroup<i32>) {
+    let old = arch::atomic_i_add(&mut *counter, 1);
+    arch::atomic_u_max(&mut *counter, old);
+    arch::atomic_compare_exchange(&mut *counter, 0, old);
+    arch::atomic_exchange(&mut *counter, 5);
+    arch::atomic_s_min(&mut *signed, -1);
+    arch::atomic_xor(&mut *signed, 0x55);
+}
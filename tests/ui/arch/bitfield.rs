@@ -0,0 +1,11 @@
+// Test `OpBitFieldSExtract`, `OpBitFieldUExtract` and `OpBitFieldInsert`
+// build-pass
+
+use spirv_std::arch;
+
+#[spirv(fragment)]
+pub fn main() {
+    let packed = arch::bitfield_insert(0u32, 0xabu32, 8, 8);
+    assert!(arch::bitfield_extract(packed, 8, 8) == 0xab);
+    assert!(arch::bitfield_extract(-16i32, 4, 4) == -1);
+}
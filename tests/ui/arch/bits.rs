@@ -0,0 +1,17 @@
+// Test `OpBitCount`, `OpBitReverse` and the `FindILsb`, `FindSMsb` and `FindUMsb`
+// extended instructions
+// build-pass
+
+use spirv_std::arch;
+
+#[spirv(fragment)]
+pub fn main() {
+    let x = 0b1011_0000u32;
+    let count = arch::bit_count(x);
+    let reversed = arch::bit_reverse(x);
+    let lsb = arch::find_lsb(x);
+    let umsb = arch::find_msb(x);
+    let smsb = arch::find_msb(-5i32);
+    let counts: glam::UVec2 = arch::bit_count(glam::UVec2::new(x, reversed));
+    assert!(count + lsb + umsb + counts.x == 17 && smsb == 2);
+}
@@ -0,0 +1,15 @@
+// Test `OpControlBarrier` and `OpMemoryBarrier`
+// build-pass
+
+use spirv_std::arch::{self, scope, semantics};
+
+#[spirv(compute(threads(64)))]
+pub fn main() {
+    arch::workgroup_barrier();
+    arch::memory_barrier();
+    arch::control_barrier::<
+        { scope::WORKGROUP },
+        { scope::DEVICE },
+        { semantics::UNIFORM_MEMORY | semantics::ACQUIRE_RELEASE },
+    >();
+}
@@ -0,0 +1,31 @@
+// Test `OpCopyMemory` staging a buffer into workgroup memory
+// build-pass
+
+use spirv_std::storage_class::{Bind, Input, StorageBuffer};
+
+#[spirv(block, layout = "std430")]
+pub struct Tile {
+    pub values: [f32; 64],
+}
+
+#[spirv(compute(threads(64)))]
+pub fn main(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    input: Bind<StorageBuffer<Tile>, 0, 0>,
+    mut output: Bind<StorageBuffer<Tile>, 0, 1>,
+) {
+    #[spirv(workgroup)]
+    static mut SHARED: [f32; 64] = [0.0; 64];
+
+    let index = *index as usize;
+    unsafe {
+        if index == 0 {
+            spirv_std::arch::copy_memory(&mut SHARED, &input.values);
+        }
+        spirv_std::arch::workgroup_barrier();
+        spirv_std::arch::copy_memory_aligned::<_, 4>(
+            &mut output.deref_mut().values[index],
+            &SHARED[63 - index],
+        );
+    }
+}
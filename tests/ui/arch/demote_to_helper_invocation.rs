@@ -0,0 +1,12 @@
+// Test `OpDemoteToHelperInvocationEXT`
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(fragment)]
+pub fn main(color: Input<glam::Vec4>, mut output: Output<glam::Vec4>) {
+    if color.w < 0.5 {
+        spirv_std::demote_to_helper_invocation();
+    }
+    *output = *color;
+}
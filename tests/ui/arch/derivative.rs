@@ -0,0 +1,16 @@
+// Test `OpDPdx`, `OpDPdy`, `OpFwidth` and their fine/coarse variants
+// build-pass
+
+use spirv_std::{
+    arch,
+    storage_class::{Input, Output},
+};
+
+#[spirv(fragment)]
+pub fn main(uv: Input<glam::Vec2>, mut output: Output<glam::Vec4>) {
+    let x = arch::ddx(uv.x) + arch::ddx_fine(uv.x) + arch::ddx_coarse(uv.x);
+    let y = arch::ddy(uv.y) + arch::ddy_fine(uv.y) + arch::ddy_coarse(uv.y);
+    let width = arch::fwidth(uv.x) + arch::fwidth_fine(uv.x) + arch::fwidth_coarse(uv.x);
+    let uv_width: glam::Vec2 = arch::fwidth_vector(*uv);
+    *output = glam::Vec4::new(x, y, width, uv_width.x + uv_width.y);
+}
@@ -0,0 +1,19 @@
+// Test `OpEmitStreamVertex` and `OpEndStreamPrimitive`
+// build-pass
+
+use spirv_std::arch;
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(geometry(input_points, output_points, output_vertices = 2))]
+pub fn main(
+    #[spirv(position)] in_position: Input<[glam::Vec4; 1]>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+) {
+    *out_position = in_position[0];
+    arch::emit_stream_vertex::<0>();
+    arch::end_stream_primitive::<0>();
+
+    *out_position = in_position[0] * 2.0;
+    arch::emit_stream_vertex::<1>();
+    arch::end_stream_primitive::<1>();
+}
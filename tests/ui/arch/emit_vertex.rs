@@ -0,0 +1,24 @@
+// Test `OpEmitVertex` and `OpEndPrimitive`, outputting each input triangle
+// twice, as two separate triangles.
+// build-pass
+
+use spirv_std::arch;
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(geometry(input_triangles, output_triangle_strip, output_vertices = 6))]
+pub fn main(
+    #[spirv(position)] in_position: Input<[glam::Vec4; 3]>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+) {
+    let mut copy = 0;
+    while copy < 2 {
+        let mut i = 0;
+        while i < 3 {
+            *out_position = in_position[i] + glam::Vec4::new(copy as f32 * 0.5, 0.0, 0.0, 0.0);
+            arch::emit_vertex();
+            i += 1;
+        }
+        arch::end_primitive();
+        copy += 1;
+    }
+}
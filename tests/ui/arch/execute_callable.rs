@@ -0,0 +1,10 @@
+// Test `OpExecuteCallableKHR`
+// build-pass
+
+use spirv_std::{arch, storage_class::CallableDataKHR};
+
+#[spirv(ray_generation_khr)]
+pub fn main(mut data: CallableDataKHR<glam::Vec4>) {
+    *data = glam::Vec4::new(1.0, 0.0, 0.0, 1.0);
+    arch::execute_callable(0, &mut data);
+}
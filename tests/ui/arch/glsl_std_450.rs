@@ -0,0 +1,44 @@
+// Test the `GLSL.std.450` extended instructions with a small lighting kernel
+// build-pass
+
+use glam::{Vec3, Vec3A, Vec4};
+use spirv_std::{
+    arch,
+    storage_class::{Input, Output},
+};
+
+#[spirv(fragment)]
+pub fn main(
+    normal: Input<Vec3A>,
+    position: Input<Vec3A>,
+    light_position: Input<Vec3A>,
+    mut output: Output<Vec4>,
+) {
+    let n = arch::normalize(*normal);
+    let to_light = *light_position - *position;
+    let l = to_light * arch::inverse_sqrt(to_light.dot(to_light));
+    let v = arch::normalize(-*position);
+
+    let attenuation = 1.0 / (1.0 + arch::distance(*light_position, *position));
+    let diffuse = arch::clamp(n.dot(l), 0.0, 1.0);
+
+    let r = arch::reflect(-l, n);
+    let specular = arch::pow(arch::clamp(r.dot(v), 0.0, 1.0), 32.0);
+    let refracted = arch::refract(-v, n, 1.0 / 1.33);
+    let tangent = arch::cross(n, Vec3A::new(0.0, 1.0, 0.0));
+
+    let rim = arch::smoothstep(0.5, 1.0, 1.0 - n.dot(v));
+    let wave = arch::sin(position.x) * arch::cos(position.y) + arch::tan(position.z);
+    let fog = arch::exp(-arch::length(*position))
+        + arch::log(attenuation + 1.0)
+        + arch::exp2(diffuse)
+        + arch::log2(specular + 1.0);
+    let glow = arch::sqrt(rim) + arch::length(refracted) + arch::length(tangent);
+
+    let color = arch::fmix(
+        Vec3A::new(0.1, 0.1, 0.1),
+        Vec3A::new(1.0, 0.9, 0.8),
+        Vec3A::splat(attenuation * (diffuse + specular)),
+    );
+    *output = Vec3::from(color).extend(wave + fog + glow);
+}
@@ -0,0 +1,12 @@
+// Test `OpIgnoreIntersectionKHR`
+// build-pass
+
+use spirv_std::{arch, storage_class::IncomingRayPayloadKHR};
+
+#[spirv(any_hit_khr)]
+pub fn main(mut payload: IncomingRayPayloadKHR<f32>) {
+    if arch::ray_t_current() > 100.0 {
+        arch::ignore_intersection();
+    }
+    *payload = arch::ray_t_current();
+}
@@ -0,0 +1,20 @@
+// Test `OpIsHelperInvocationEXT`, skipping an atomic in helper invocations
+// build-pass
+
+use spirv_std::storage_class::{Bind, Input, StorageBuffer};
+
+#[spirv(block, layout = "std430")]
+pub struct Histogram {
+    pub bins: [u32; 16],
+}
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(flat)] bin: Input<u32>,
+    mut histogram: Bind<StorageBuffer<Histogram>, 0, 0>,
+) {
+    if !spirv_std::is_helper_invocation() {
+        let bins = unsafe { &mut histogram.deref_mut().bins };
+        spirv_std::arch::atomic_i_add(&mut bins[*bin as usize % 16], 1);
+    }
+}
@@ -0,0 +1,22 @@
+// Test `OpIsNan` and `OpIsInf`, on a NaN constant, and on scalar and vector inputs
+// build-pass
+
+use spirv_std::arch;
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(fragment)]
+pub fn main(value: Input<f32>, color: Input<glam::Vec4>, mut output: Output<glam::Vec4>) {
+    assert!(arch::is_nan(f32::NAN));
+    assert!(!arch::is_finite(f32::INFINITY));
+
+    let value = if arch::is_finite(*value) { *value } else { 0.0 };
+    let nan: glam::BVec4 = arch::is_nan_vector(*color);
+    let inf: glam::BVec4 = arch::is_inf_vector(*color);
+    let finite: glam::BVec4 = arch::is_finite_vector(*color);
+    let sanitized = arch::select_vector(finite, *color, glam::Vec4::splat(0.0));
+    *output = if arch::any(nan) || arch::any(inf) || arch::is_inf(value) {
+        glam::Vec4::splat(0.0)
+    } else {
+        sanitized * value
+    };
+}
@@ -0,0 +1,20 @@
+// Test matrix-vector and matrix-matrix multiplication and transposition
+// build-pass
+
+use spirv_std::matrix::Mat4;
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(vertex)]
+pub fn main(
+    position: Input<glam::Vec4>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+) {
+    let model = Mat4::from_cols(
+        glam::Vec4::new(1.0, 0.0, 0.0, 0.0),
+        glam::Vec4::new(0.0, 1.0, 0.0, 0.0),
+        glam::Vec4::new(0.0, 0.0, 1.0, 0.0),
+        glam::Vec4::new(0.5, 0.5, 0.0, 1.0),
+    );
+    let view = model.transpose();
+    *out_position = (view * model) * *position + model.left_mul(*position);
+}
@@ -0,0 +1,19 @@
+// Test the `GLSL.std.450` packing and unpacking extended instructions
+// build-pass
+
+use glam::{Vec2, Vec4};
+use spirv_std::{
+    arch,
+    storage_class::{Input, Output},
+};
+
+#[spirv(fragment)]
+pub fn main(#[spirv(flat)] packed: Input<u32>, mut output: Output<Vec4>) {
+    let color: Vec4 = arch::unpack_unorm_4x8(*packed);
+    let signed: Vec4 = arch::unpack_snorm_4x8(arch::pack_snorm_4x8(color));
+    let half: Vec2 = arch::unpack_half_2x16(arch::pack_half_2x16(Vec2::new(color.x, color.y)));
+    let unorm: Vec2 = arch::unpack_unorm_2x16(arch::pack_unorm_2x16(half));
+    let snorm: Vec2 = arch::unpack_snorm_2x16(arch::pack_snorm_2x16(unorm));
+    assert!(arch::pack_unorm_4x8(color) == *packed);
+    *output = signed + Vec4::new(snorm.x, snorm.y, 0.0, 0.0);
+}
@@ -0,0 +1,23 @@
+// Test reading and writing through buffer device addresses.
+// build-pass
+
+use spirv_std::arch;
+use spirv_std::storage_class::PushConstant;
+
+#[derive(Copy, Clone)]
+#[spirv(block)]
+pub struct Addresses {
+    pub input: u64,
+    pub output: u64,
+}
+
+#[spirv(compute(threads(1)))]
+pub fn main(addresses: PushConstant<Addresses>) {
+    let input = arch::from_u64::<f32>(addresses.input);
+    let output = arch::from_u64::<f32>(addresses.output);
+    unsafe {
+        let value = arch::load_aligned::<_, 4>(input);
+        arch::store_aligned::<_, 4>(output, value * 2.0);
+    }
+    let _address = arch::to_u64(output);
+}
@@ -0,0 +1,23 @@
+// Test the ray tracing builtin accessors
+// build-pass
+
+use spirv_std::{arch, storage_class::IncomingRayPayloadKHR};
+
+#[spirv(closest_hit_khr)]
+pub fn main(mut payload: IncomingRayPayloadKHR<glam::Vec4>) {
+    let launch_id: glam::UVec3 = arch::launch_id();
+    let launch_size: glam::UVec3 = arch::launch_size();
+    let origin: glam::Vec3A = arch::world_ray_origin();
+    let direction: glam::Vec3A = arch::world_ray_direction();
+    let object_origin: glam::Vec3A = arch::object_ray_origin();
+    let object_direction: glam::Vec3A = arch::object_ray_direction();
+    let t = arch::ray_t_current() - arch::ray_t_min();
+    let hit = origin + direction * t + object_origin + object_direction;
+    let index = arch::instance_custom_index()
+        + arch::instance_id()
+        + arch::hit_kind()
+        + arch::incoming_ray_flags()
+        + launch_id.x
+        + launch_size.x;
+    *payload = glam::Vec3::from(hit).extend(index as f32);
+}
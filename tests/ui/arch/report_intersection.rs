@@ -0,0 +1,12 @@
+// Test `OpReportIntersectionKHR`
+// build-pass
+
+use spirv_std::arch;
+
+#[spirv(intersection_khr)]
+pub fn main() {
+    let t_hit = arch::ray_t_min() + 1.0;
+    if !arch::report_intersection(t_hit, 0) {
+        arch::report_intersection(t_hit + 1.0, 1);
+    }
+}
@@ -0,0 +1,19 @@
+// Test `OpSelect` on scalars and, component-wise, on vectors
+// build-pass
+
+use spirv_std::arch;
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(fragment)]
+pub fn main(
+    color: Input<glam::Vec4>,
+    #[spirv(flat)] index: Input<u32>,
+    mut output: Output<glam::Vec4>,
+    mut out_index: Output<u32>,
+) {
+    let mask = glam::BVec4::new(color.x > 0.5, true, false, color.w > 0.0);
+    let clamped = arch::select_vector(mask, *color, glam::Vec4::splat(0.5));
+    let scale = arch::select(*index > 2, 2.0, 1.0);
+    *output = clamped * scale;
+    *out_index = arch::select(*index > 2, *index - 2, *index);
+}
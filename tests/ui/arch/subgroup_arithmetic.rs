@@ -0,0 +1,14 @@
+// Test the `OpGroupNonUniform*` arithmetic instructions
+// build-pass
+
+use spirv_std::arch;
+
+#[spirv(compute(threads(32)))]
+pub fn main() {
+    let sum = arch::subgroup_reduce_add(1u32);
+    let prefix = arch::subgroup_exclusive_add(glam::Vec2::new(1.0, 2.0));
+    let inclusive = arch::subgroup_inclusive_add(-1i32);
+    let min = arch::subgroup_reduce_min(glam::IVec2::new(-1, 1));
+    let max = arch::subgroup_reduce_max(2.0f32);
+    assert!(sum as f32 + prefix.x + inclusive as f32 + min.x as f32 + max != 0.0);
+}
@@ -0,0 +1,7 @@
+// build-pass
+
+#[spirv(compute(threads(32)))]
+pub fn main() {
+    let ballot: glam::UVec4 = spirv_std::arch::subgroup_ballot(true);
+    assert!(ballot.x != 0);
+}
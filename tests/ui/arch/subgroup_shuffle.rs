@@ -0,0 +1,13 @@
+// Test the `OpGroupNonUniformShuffle*` instructions
+// build-pass
+
+use spirv_std::arch;
+
+#[spirv(compute(threads(32)))]
+pub fn main() {
+    let vector = arch::subgroup_shuffle(glam::Vec2::new(1.0, 2.0), 1);
+    let xor = arch::subgroup_shuffle_xor(7u32, 1);
+    let up = arch::subgroup_shuffle_up(glam::UVec2::new(xor, 3), 1);
+    let down = arch::subgroup_shuffle_down(-5i32, 1);
+    assert!(vector.x + up.x as f32 + down as f32 != 0.0);
+}
@@ -0,0 +1,11 @@
+// Test `OpGroupNonUniformAll`, `OpGroupNonUniformAny` and `OpGroupNonUniformElect`
+// build-pass
+
+use spirv_std::arch;
+
+#[spirv(compute(threads(32)))]
+pub fn main() {
+    let elected = arch::subgroup_elect();
+    assert!(arch::subgroup_any(elected));
+    assert!(!arch::subgroup_all(!elected) || arch::subgroup_all(true));
+}
@@ -0,0 +1,12 @@
+// Test `OpTerminateRayKHR`
+// build-pass
+
+use spirv_std::{arch, storage_class::IncomingRayPayloadKHR};
+
+#[spirv(any_hit_khr)]
+pub fn main(mut payload: IncomingRayPayloadKHR<f32>) {
+    *payload = arch::ray_t_current();
+    if arch::hit_kind() == 0xfe {
+        arch::terminate_ray();
+    }
+}
@@ -0,0 +1,28 @@
+// Test `OpTraceRayKHR`
+// build-pass
+
+use spirv_std::{
+    arch,
+    storage_class::{Bind, RayPayloadKHR, UniformConstant},
+    AccelerationStructure,
+};
+
+#[spirv(ray_generation_khr)]
+pub fn main(
+    acceleration_structure: Bind<UniformConstant<AccelerationStructure>, 0, 0>,
+    mut payload: RayPayloadKHR<glam::Vec4>,
+) {
+    arch::trace_ray(
+        &acceleration_structure,
+        arch::ray_flags::OPAQUE,
+        0xff,
+        0,
+        0,
+        0,
+        glam::Vec3A::new(0.0, 0.0, 0.0),
+        0.001,
+        glam::Vec3A::new(0.0, 0.0, 1.0),
+        1000.0,
+        &mut payload,
+    );
+}
@@ -0,0 +1,19 @@
+// Test `OpUndef` as the starting value of an `asm!` result
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(fragment)]
+pub fn main(input: Input<glam::Vec4>, mut output: Output<glam::Vec4>) {
+    let mut result: glam::Vec4 = unsafe { spirv_std::arch::uninit() };
+    unsafe {
+        asm! {
+            "%input = OpLoad _ {input}",
+            "%result = OpFNegate _ %input",
+            "OpStore {result} %result",
+            input = in(reg) &*input,
+            result = in(reg) &mut result,
+        }
+    }
+    *output = result;
+}
@@ -0,0 +1,15 @@
+// Test swizzles lowering to `OpVectorShuffle`
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+use spirv_std::vector::Swizzle;
+
+#[spirv(fragment)]
+pub fn main(color: Input<glam::Vec4>, mut out: Output<glam::Vec4>) {
+    let rgb: glam::Vec3 = color.xyz();
+    let uv: glam::Vec2 = rgb.yx();
+    let abgr: glam::Vec4 = color.wzyx();
+    let alpha: glam::Vec4 = color.wwww();
+    let mixed: glam::Vec4 = color.swizzle4::<_, 2, 0, 3, 1>();
+    *out = abgr + alpha + mixed + rgb.extend(uv.x);
+}
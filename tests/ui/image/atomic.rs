@@ -0,0 +1,17 @@
+// Test `OpImageTexelPointer` with atomic operations
+// build-pass
+
+use spirv_std::{arch, image_options::format, storage_class::{Output, UniformConstant}, StorageImage2d};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<StorageImage2d<format::R32ui>>,
+    mut exchanged: Output<u32>,
+    mut added: Output<u32>,
+    mut compare_exchanged: Output<u32>,
+) {
+    let coordinate = glam::IVec2::new(0, 1);
+    *exchanged = image.atomic_exchange(coordinate, 0, 1);
+    *added = image.atomic_add(coordinate, 0, 1);
+    *compare_exchanged = image.atomic_compare_exchange(coordinate, 0, 2, 1);
+}
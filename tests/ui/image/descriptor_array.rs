@@ -0,0 +1,22 @@
+// Test indexing arrays of samplers and images bound to a single binding
+// build-pass
+
+use spirv_std::{
+    storage_class::{Bind, Input, Output, UniformConstant},
+    Image2d, Sampler,
+};
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(flat)] index: Input<u32>,
+    samplers: Bind<[UniformConstant<Sampler>; 2], 1, 0>,
+    images: Bind<[UniformConstant<Image2d>; 4], 1, 1>,
+    mut output: Output<glam::Vec4>,
+    mut nonuniform_output: Output<glam::Vec4>,
+) {
+    let coord = glam::Vec2::new(0.0, 1.0);
+    *output = images[3].sample(samplers[0], coord);
+    *nonuniform_output = images
+        .index_nonuniform(*index as usize)
+        .sample(*samplers.index_nonuniform(1), coord);
+}
@@ -0,0 +1,10 @@
+// Test `OpImageFetch` on an arrayed image, with the layer as the last coordinate
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, Image2dArray};
+
+#[spirv(fragment)]
+pub fn main(image: UniformConstant<Image2dArray>, mut output: Output<glam::Vec4>) {
+    let texel = image.fetch(glam::IVec3::new(0, 1, 2));
+    *output = texel;
+}
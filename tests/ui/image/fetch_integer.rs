@@ -0,0 +1,19 @@
+// Test that fetching from and sampling integer images gives vectors of their
+// sampled type
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, Image2d, Image2dArray, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d<u32>>,
+    image_array: UniformConstant<Image2dArray<i32>>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::UVec4>,
+    mut array_output: Output<glam::IVec4>,
+) {
+    let texel: glam::UVec4 = image.fetch(glam::IVec2::new(0, 1));
+    let sampled: glam::UVec4 = image.sample_by_lod(*sampler, glam::Vec2::new(0.0, 1.0), 0.0);
+    *output = texel + sampled;
+    *array_output = image_array.fetch(glam::IVec3::new(0, 1, 2));
+}
@@ -0,0 +1,15 @@
+// Test `OpImageFetch` with the `Lod` and `ConstOffset` image operands
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, Image2d};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    mut output_lod: Output<glam::Vec4>,
+    mut output_offset: Output<glam::Vec4>,
+) {
+    let coordinate = glam::IVec2::new(0, 1);
+    *output_lod = image.fetch_lod(coordinate, 2);
+    *output_offset = image.fetch_with_offset::<_, _, _, 1, -1>(coordinate);
+}
@@ -0,0 +1,14 @@
+// Test `OpImageFetch` with the `Sample` image operand and `OpImageQuerySamples`
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Image2dMS};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2dMS>,
+    mut output: Output<glam::Vec4>,
+    mut sample_count: Output<u32>,
+) {
+    *output = image.fetch_multisample(glam::IVec2::new(0, 1), 2);
+    *sample_count = image.sample_count();
+}
@@ -0,0 +1,17 @@
+// Test `OpImageGather` and `OpImageDrefGather`
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, ComparisonSampler, Image2d, Image2dDepth, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    depth_image: UniformConstant<Image2dDepth>,
+    sampler: UniformConstant<Sampler>,
+    comparison_sampler: UniformConstant<ComparisonSampler>,
+    mut output: Output<glam::Vec4>,
+    mut depth_output: Output<glam::Vec4>,
+) {
+    *output = image.gather::<_, _, 0>(*sampler, glam::Vec2::new(0.0, 1.0));
+    *depth_output = depth_image.gather_depth_compare(*comparison_sampler, glam::Vec2::new(0.0, 1.0), 0.5);
+}
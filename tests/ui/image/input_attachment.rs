@@ -0,0 +1,20 @@
+// Test reading subpass input attachments at the fragment's location.
+// build-pass
+
+use spirv_std::{
+    storage_class::{Output, UniformConstant},
+    InputAttachment,
+};
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(descriptor_set = 1, binding = 0, input_attachment_index = 0)]
+    albedo: UniformConstant<InputAttachment>,
+    #[spirv(descriptor_set = 1, binding = 1, input_attachment_index = 1)]
+    normal: UniformConstant<InputAttachment>,
+    mut output: Output<glam::Vec4>,
+) {
+    let albedo: glam::Vec4 = albedo.load(glam::IVec2::new(0, 0));
+    let normal: glam::Vec4 = normal.load(glam::IVec2::new(0, 0));
+    *output = albedo * normal.z.max(0.0);
+}
@@ -0,0 +1,15 @@
+// Test `OpImageQueryLevels` and `OpImageQueryLod`
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Image2d, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    mut levels: Output<u32>,
+    mut lod: Output<glam::Vec2>,
+) {
+    *levels = image.query_levels();
+    *lod = image.query_lod(*sampler, glam::Vec2::new(0.0, 1.0));
+}
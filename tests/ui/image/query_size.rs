@@ -0,0 +1,18 @@
+// Test `OpImageQuerySize` and `OpImageQuerySizeLod`
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Image2d, Image2dArray, StorageImage2d};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    image_array: UniformConstant<Image2dArray>,
+    storage_image: UniformConstant<StorageImage2d>,
+    mut size_lod: Output<glam::UVec2>,
+    mut array_size_lod: Output<glam::UVec3>,
+    mut size: Output<glam::UVec2>,
+) {
+    *size_lod = image.size_lod(0);
+    *array_size_lod = image_array.size_lod(0);
+    *size = storage_image.size();
+}
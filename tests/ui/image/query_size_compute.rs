@@ -0,0 +1,22 @@
+// Test bounding compute shader writes to a storage image by its size, queried
+// with `OpImageQuerySize`
+// build-pass
+
+use spirv_std::{
+    image_options::format,
+    storage_class::{Input, UniformConstant},
+    StorageImage2d,
+};
+
+#[spirv(compute(threads(8, 8)))]
+pub fn main(
+    #[spirv(global_invocation_id)] gid: Input<glam::UVec3>,
+    image: UniformConstant<StorageImage2d<format::Rgba32f>>,
+) {
+    let size: glam::UVec2 = image.size();
+    if gid.x < size.x && gid.y < size.y {
+        unsafe {
+            image.write(gid.truncate(), glam::Vec4::new(1.0, 0.0, 0.0, 1.0));
+        }
+    }
+}
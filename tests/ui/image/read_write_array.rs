@@ -0,0 +1,14 @@
+// Test `OpImageRead` and `OpImageWrite` on an arrayed storage image, with the
+// layer as the last coordinate
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, StorageImage2dArray};
+
+#[spirv(fragment)]
+pub fn main(image: UniformConstant<StorageImage2dArray>, mut output: Output<glam::Vec2>) {
+    let texels: glam::Vec2 = image.read(glam::IVec3::new(0, 1, 2));
+    unsafe {
+        image.write(glam::UVec3::new(0, 1, 3), texels);
+    }
+    *output = texels;
+}
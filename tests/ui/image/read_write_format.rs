@@ -0,0 +1,21 @@
+// Test `OpImageRead` and `OpImageWrite` on storage images with a declared format
+// build-pass
+
+use spirv_std::{arch, image_options::format, storage_class::{Output, UniformConstant}, StorageImage2d};
+
+#[spirv(fragment)]
+pub fn main(
+    r32f_image: UniformConstant<StorageImage2d<format::R32f>>,
+    rgba8ui_image: UniformConstant<StorageImage2d<format::Rgba8ui>>,
+    mut r32f_output: Output<f32>,
+    mut rgba8ui_output: Output<glam::UVec4>,
+) {
+    let coordinate = glam::IVec2::new(0, 1);
+    let texel: f32 = r32f_image.read(coordinate);
+    *r32f_output = texel;
+    *rgba8ui_output = rgba8ui_image.read(coordinate);
+    unsafe {
+        r32f_image.write(coordinate, 1.0);
+        rgba8ui_image.write(coordinate, glam::UVec4::new(1, 2, 3, 4));
+    }
+}
@@ -0,0 +1,24 @@
+// Test sampling and fetching of 1D and 3D images
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Image1d, Image3d, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image_1d: UniformConstant<Image1d>,
+    image_3d: UniformConstant<Image3d>,
+    sampler: UniformConstant<Sampler>,
+    mut output_1d: Output<glam::Vec4>,
+    mut output_1d_lod: Output<glam::Vec4>,
+    mut output_1d_fetch: Output<glam::Vec4>,
+    mut output_3d: Output<glam::Vec4>,
+    mut output_3d_lod: Output<glam::Vec4>,
+    mut output_3d_fetch: Output<glam::Vec4>,
+) {
+    *output_1d = image_1d.sample(*sampler, 0.5);
+    *output_1d_lod = image_1d.sample_by_lod(*sampler, 0.5, 0.0);
+    *output_1d_fetch = image_1d.fetch(1);
+    *output_3d = image_3d.sample(*sampler, glam::Vec3A::new(0.0, 0.5, 1.0));
+    *output_3d_lod = image_3d.sample_by_lod(*sampler, glam::Vec3A::new(0.0, 0.5, 1.0), 0.0);
+    *output_3d_fetch = image_3d.fetch(glam::IVec3::new(0, 1, 2));
+}
@@ -0,0 +1,16 @@
+// Test `OpImageSampleImplicitLod` with the `Bias` image operand
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Image2d, Image2dArray, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    image_array: UniformConstant<Image2dArray>,
+    sampler: UniformConstant<Sampler>,
+    mut image_output: Output<glam::Vec4>,
+    mut image_array_output: Output<glam::Vec4>,
+) {
+    *image_output = image.sample_bias(*sampler, glam::Vec2::new(0.0, 1.0), 1.0);
+    *image_array_output = image_array.sample_bias(*sampler, glam::Vec3A::new(0.0, 0.0, 1.0), 1.0);
+}
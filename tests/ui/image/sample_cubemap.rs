@@ -0,0 +1,22 @@
+// Test sampling of cube maps and cube map arrays
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Cubemap, CubemapArray, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    cubemap: UniformConstant<Cubemap>,
+    cubemap_array: UniformConstant<CubemapArray>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::Vec4>,
+    mut output_lod: Output<glam::Vec4>,
+    mut array_output: Output<glam::Vec4>,
+    mut array_output_lod: Output<glam::Vec4>,
+) {
+    let direction = glam::Vec3A::new(0.0, 0.0, 1.0);
+    *output = cubemap.sample(*sampler, direction);
+    *output_lod = cubemap.sample_by_lod(*sampler, direction, 0.0);
+    let direction_layer = glam::Vec4::new(0.0, 0.0, 1.0, 2.0);
+    *array_output = cubemap_array.sample(*sampler, direction_layer);
+    *array_output_lod = cubemap_array.sample_by_lod(*sampler, direction_layer, 0.0);
+}
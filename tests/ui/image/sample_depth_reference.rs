@@ -0,0 +1,15 @@
+// Test `OpImageSampleDrefImplicitLod` and `OpImageSampleDrefExplicitLod`
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, ComparisonSampler, Image2dDepth};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2dDepth>,
+    sampler: UniformConstant<ComparisonSampler>,
+    mut output: Output<f32>,
+    mut output_lod: Output<f32>,
+) {
+    *output = image.sample_depth_reference(*sampler, glam::Vec2::new(0.0, 1.0), 0.5);
+    *output_lod = image.sample_depth_reference_by_lod(*sampler, glam::Vec2::new(0.0, 1.0), 0.5, 0.0);
+}
@@ -0,0 +1,16 @@
+// Test `OpImageSampleImplicitLod` with the `MinLod` image operand, alone and
+// combined with `Bias`
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, Image2d, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::Vec4>,
+    mut bias_output: Output<glam::Vec4>,
+) {
+    *output = image.sample_min_lod(*sampler, glam::Vec2::new(0.0, 1.0), 2.0);
+    *bias_output = image.sample_bias_min_lod(*sampler, glam::Vec2::new(0.0, 1.0), 1.0, 2.0);
+}
@@ -0,0 +1,21 @@
+// Test `OpImageSampleProjImplicitLod`, `OpImageSampleProjExplicitLod` and
+// `OpImageSampleProjDrefImplicitLod`
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, ComparisonSampler, Image2d, Image2dDepth, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    depth_image: UniformConstant<Image2dDepth>,
+    sampler: UniformConstant<Sampler>,
+    comparison_sampler: UniformConstant<ComparisonSampler>,
+    mut output: Output<glam::Vec4>,
+    mut output_lod: Output<glam::Vec4>,
+    mut output_depth: Output<f32>,
+) {
+    let coordinate = glam::Vec3A::new(0.5, 1.0, 2.0);
+    *output = image.sample_proj(*sampler, coordinate);
+    *output_lod = image.sample_proj_by_lod(*sampler, coordinate, 0.0);
+    *output_depth = depth_image.sample_proj_depth_reference(*comparison_sampler, coordinate, 0.5);
+}
@@ -0,0 +1,17 @@
+// Test sampling and fetching of rectangle images, with unnormalized coordinates
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, ImageRect, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<ImageRect>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::Vec4>,
+    mut output_fetch: Output<glam::Vec4>,
+    mut size: Output<glam::UVec2>,
+) {
+    *output = image.sample(*sampler, glam::Vec2::new(16.5, 3.5));
+    *output_fetch = image.fetch(glam::IVec2::new(16, 3));
+    *size = image.size();
+}
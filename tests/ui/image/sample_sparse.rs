@@ -0,0 +1,19 @@
+// Test `OpImageSparseSampleImplicitLod` and `OpImageSparseTexelsResident`
+// build-pass
+
+use spirv_std::{storage_class::{Output, UniformConstant}, texels_resident, Image2d, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::Vec4>,
+) {
+    let (residency_code, texel): (u32, glam::Vec4) =
+        image.sample_sparse(*sampler, glam::Vec2::new(0.0, 1.0));
+    *output = if texels_resident(residency_code) {
+        texel
+    } else {
+        glam::Vec4::new(0.0, 0.0, 0.0, 0.0)
+    };
+}
@@ -0,0 +1,19 @@
+// Test `OpImageSampleImplicitLod` and `OpImageSampleExplicitLod` with the
+// `ConstOffset` and `Offset` image operands
+// build-pass
+
+use spirv_std::{arch, storage_class::{Output, UniformConstant}, Image2d, Sampler};
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    mut output: Output<glam::Vec4>,
+    mut output_lod: Output<glam::Vec4>,
+    mut output_dynamic: Output<glam::Vec4>,
+) {
+    let coordinate = glam::Vec2::new(0.0, 1.0);
+    *output = image.sample_with_offset::<_, _, 1, -1>(*sampler, coordinate);
+    *output_lod = image.sample_by_lod_with_offset::<_, _, -2, 3>(*sampler, coordinate, 0.0);
+    *output_dynamic = image.sample_with_dynamic_offset(*sampler, coordinate, glam::IVec2::new(1, 1));
+}
@@ -0,0 +1,24 @@
+// Test sampling combined image samplers of different dimensionalities
+// build-pass
+
+use spirv_std::{
+    storage_class::{Output, UniformConstant},
+    Cubemap, Image2d, Image2dArray, Image3d, SampledImage,
+};
+
+#[spirv(fragment)]
+pub fn main(
+    image_2d: UniformConstant<SampledImage<Image2d>>,
+    image_2d_array: UniformConstant<SampledImage<Image2dArray>>,
+    image_3d: UniformConstant<SampledImage<Image3d>>,
+    cubemap: UniformConstant<SampledImage<Cubemap>>,
+    mut output_2d: Output<glam::Vec4>,
+    mut output_2d_array: Output<glam::Vec4>,
+    mut output_3d: Output<glam::Vec4>,
+    mut output_cubemap: Output<glam::Vec4>,
+) {
+    *output_2d = image_2d.sample(glam::Vec2::new(0.0, 1.0));
+    *output_2d_array = image_2d_array.sample_by_lod(glam::Vec3A::new(0.0, 1.0, 2.0), 0.0);
+    *output_3d = image_3d.sample(glam::Vec3A::new(0.0, 1.0, 0.5));
+    *output_cubemap = cubemap.sample_by_lod(glam::Vec3A::new(1.0, 0.0, 0.0), 1.0);
+}
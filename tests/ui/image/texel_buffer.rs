@@ -0,0 +1,23 @@
+// Test fetching from a uniform texel buffer, and reading and writing a storage
+// texel buffer, with a scalar coordinate
+// build-pass
+
+use spirv_std::{
+    image_options::format,
+    storage_class::{Output, UniformConstant},
+    StorageTexelBuffer, TexelBuffer,
+};
+
+#[spirv(fragment)]
+pub fn main(
+    lookup: UniformConstant<TexelBuffer>,
+    storage: UniformConstant<StorageTexelBuffer<format::R32f>>,
+    mut output: Output<glam::Vec4>,
+) {
+    let texel: glam::Vec4 = lookup.fetch(7u32);
+    let value: f32 = storage.read(3i32);
+    unsafe {
+        storage.write(4u32, value * texel.x);
+    }
+    *output = texel;
+}
@@ -0,0 +1,20 @@
+// Test that `OpImageWrite` takes texels matching the format of the image: a
+// scalar `u32` for `R32ui`, 2 component vectors for `Rg16f`, and 3 component
+// vectors for `R11fG11fB10f`.
+// build-pass
+
+use spirv_std::{image_options::format, storage_class::UniformConstant, StorageImage2d};
+
+#[spirv(compute(threads(1)))]
+pub fn main(
+    r32ui_image: UniformConstant<StorageImage2d<format::R32ui>>,
+    rg16f_image: UniformConstant<StorageImage2d<format::Rg16f>>,
+    r11f_g11f_b10f_image: UniformConstant<StorageImage2d<format::R11fG11fB10f>>,
+) {
+    let coordinate = glam::UVec2::new(1, 2);
+    unsafe {
+        r32ui_image.write(coordinate, 7u32);
+        rg16f_image.write(coordinate, glam::Vec2::new(0.5, 1.0));
+        r11f_g11f_b10f_image.write(coordinate, glam::Vec3::new(0.25, 0.5, 1.0));
+    }
+}
@@ -0,0 +1,35 @@
+// Test `as` casts between integers of different widths and signedness, and
+// between integers and `f32`, as well as `f32::to_bits` and `f32::from_bits`.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(flat)] u: Input<u32>,
+    #[spirv(flat)] i: Input<i32>,
+    f: Input<f32>,
+    mut out_u: Output<u32>,
+    mut out_i: Output<i32>,
+    mut out_f: Output<f32>,
+) {
+    let (u, i, f) = (*u, *i, *f);
+
+    // Widening extends by the signedness of the source, not of the result.
+    let u_to_u64 = u as u64;
+    let u_to_i64 = u as i64;
+    let i_to_i64 = i as i64;
+    let i_to_u64 = i as u64;
+    let u8_to_i32 = (u as u8) as i32;
+    let i8_to_u32 = (i as i8) as u32;
+
+    *out_u = (u_to_u64 as u32)
+        ^ (u_to_i64 as u32)
+        ^ ((i_to_u64 >> 32) as u32)
+        ^ i8_to_u32
+        ^ (i as u32)
+        ^ (f as u32)
+        ^ f.to_bits();
+    *out_i = (i_to_i64 as i32) ^ u8_to_i32 ^ (u as i32) ^ (f as i32);
+    *out_f = f32::from_bits(u) + u as f32 + i as f32 + u_to_u64 as f32 + i_to_i64 as f32;
+}
@@ -0,0 +1,16 @@
+// Test that `f16` math is done at half precision, converting from and to `f32`.
+// build-pass
+
+use spirv_std::float::f16;
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(fragment)]
+pub fn main(i: Input<f32>, mut o: Output<f32>) {
+    let x = f16::from(*i);
+    let mut y = x * x + f16::ONE;
+    y -= x / (f16::ONE + f16::ONE);
+    if y > f16::ZERO && y != f16::INFINITY {
+        y = -y % x;
+    }
+    *o = f32::from(y) + f32::from(f16::from_bits(x.to_bits()));
+}
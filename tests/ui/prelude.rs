@@ -0,0 +1,15 @@
+// Test that a shader can get everything it needs from `spirv_std::prelude`.
+// build-pass
+
+use spirv_std::prelude::*;
+
+#[spirv(fragment)]
+pub fn main(
+    image: UniformConstant<Image2d>,
+    sampler: UniformConstant<Sampler>,
+    uv: Input<glam::Vec2>,
+    mut output: Output<glam::Vec4>,
+) {
+    let color: glam::Vec4 = image.sample(*sampler, *uv);
+    *output = arch::clamp(color, glam::Vec4::splat(0.0), glam::Vec4::splat(1.0));
+}
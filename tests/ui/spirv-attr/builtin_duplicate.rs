@@ -0,0 +1,13 @@
+// A builtin can only be declared once per storage class by an entry point.
+// build-fail
+
+use spirv_std::storage_class::Output;
+
+#[spirv(vertex)]
+pub fn main(
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    #[spirv(position)] mut out_position_again: Output<glam::Vec4>,
+) {
+    *out_position = glam::Vec4::new(0.0, 0.0, 0.0, 1.0);
+    *out_position_again = glam::Vec4::new(0.0, 0.0, 0.0, 1.0);
+}
@@ -0,0 +1,28 @@
+// Builtins declared with the storage class and type the Vulkan environment expects.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(vertex)]
+pub fn main_vs(
+    #[spirv(vertex_index)] vertex_index: Input<i32>,
+    #[spirv(instance_index)] instance_index: Input<u32>,
+    #[spirv(position)] mut position: Output<glam::Vec4>,
+    #[spirv(point_size)] mut point_size: Output<f32>,
+) {
+    *position = glam::Vec4::new(*vertex_index as f32, *instance_index as f32, 0.0, 1.0);
+    *point_size = 1.0;
+}
+
+#[spirv(fragment)]
+pub fn main_fs(
+    #[spirv(frag_coord)] frag_coord: Input<glam::Vec4>,
+    #[spirv(point_coord)] point_coord: Input<glam::Vec2>,
+    #[spirv(front_facing)] front_facing: Input<bool>,
+    #[spirv(helper_invocation)] helper_invocation: Input<bool>,
+    mut output: Output<glam::Vec4>,
+) {
+    if *front_facing && !*helper_invocation {
+        *output = *frag_coord + glam::Vec4::new(point_coord.x, point_coord.y, 0.0, 0.0);
+    }
+}
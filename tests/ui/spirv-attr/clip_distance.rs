@@ -0,0 +1,39 @@
+// Arrayed clip and cull distance builtins, passed along from the vertex shader
+// through a geometry shader, where they are arrayed per vertex, to the fragment shader.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(vertex)]
+pub fn main_vs(
+    position: Input<glam::Vec4>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    #[spirv(clip_distance)] mut clip_distance: Output<[f32; 2]>,
+    #[spirv(cull_distance)] mut cull_distance: Output<[f32; 1]>,
+) {
+    *out_position = *position;
+    *clip_distance = [position.x, position.y];
+    *cull_distance = [position.z];
+}
+
+#[spirv(geometry(input_triangles, output_triangle_strip, output_vertices = 3))]
+pub fn main_gs(
+    #[spirv(position)] position: Input<[glam::Vec4; 3]>,
+    #[spirv(clip_distance)] clip_distance: Input<[[f32; 2]; 3]>,
+    #[spirv(cull_distance)] cull_distance: Input<[[f32; 1]; 3]>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    #[spirv(clip_distance)] mut out_clip_distance: Output<[f32; 2]>,
+    #[spirv(cull_distance)] mut out_cull_distance: Output<[f32; 1]>,
+) {
+    *out_position = position[0];
+    *out_clip_distance = clip_distance[0];
+    *out_cull_distance = cull_distance[0];
+}
+
+#[spirv(fragment)]
+pub fn main_fs(
+    #[spirv(clip_distance)] clip_distance: Input<[f32; 2]>,
+    mut output: Output<glam::Vec4>,
+) {
+    *output = glam::Vec4::new(clip_distance[0], clip_distance[1], 0.0, 1.0);
+}
@@ -0,0 +1,28 @@
+// Several small inputs and outputs packed into the components of one location.
+// build-pass
+
+use spirv_std::storage_class::{Input, Location, Output};
+
+#[spirv(vertex)]
+pub fn main_vs(
+    position: Input<glam::Vec4, Location<0>>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    mut out_uv: Output<glam::Vec2, Location<0>>,
+    #[spirv(component = 2)] mut out_depth: Output<f32, Location<0>>,
+    #[spirv(component = 3)] mut out_alpha: Output<f32, Location<0>>,
+) {
+    *out_position = *position;
+    *out_uv = glam::Vec2::new(position.x, position.y);
+    *out_depth = position.z;
+    *out_alpha = 1.0;
+}
+
+#[spirv(fragment)]
+pub fn main_fs(
+    in_uv: Input<glam::Vec2, Location<0>>,
+    #[spirv(component = 2)] in_depth: Input<f32, Location<0>>,
+    #[spirv(component = 3)] in_alpha: Input<f32, Location<0>>,
+    mut output: Output<glam::Vec4>,
+) {
+    *output = glam::Vec4::new(in_uv.x, in_uv.y, *in_depth, *in_alpha);
+}
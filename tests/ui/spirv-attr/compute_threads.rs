@@ -0,0 +1,7 @@
+// Local work group sizes up to the default invocation limit are accepted.
+// build-pass
+
+use spirv_std as _;
+
+#[spirv(compute(threads(8, 8, 2)))]
+pub fn main() {}
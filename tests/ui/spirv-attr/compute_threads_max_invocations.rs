@@ -0,0 +1,8 @@
+// The invocation limit can be raised for devices that support larger local work groups.
+// build-pass
+// compile-flags: -C llvm-args=--max-compute-workgroup-invocations=256
+
+use spirv_std as _;
+
+#[spirv(compute(threads(16, 16)))]
+pub fn main() {}
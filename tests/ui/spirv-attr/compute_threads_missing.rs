@@ -0,0 +1,7 @@
+// Compute entry points must declare their local work group size.
+// build-fail
+
+use spirv_std as _;
+
+#[spirv(compute)]
+pub fn main() {}
@@ -0,0 +1,7 @@
+// Local work group size dimensions can be specialization constants.
+// build-pass
+
+use spirv_std as _;
+
+#[spirv(compute(threads(spec_constant(id = 0, default = 64), 1, 1)))]
+pub fn main() {}
@@ -0,0 +1,7 @@
+// Local work groups are limited to 128 invocations by default.
+// build-fail
+
+use spirv_std as _;
+
+#[spirv(compute(threads(16, 16)))]
+pub fn main() {}
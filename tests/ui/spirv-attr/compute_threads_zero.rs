@@ -0,0 +1,7 @@
+// Every dimension of the local work group size must be nonzero.
+// build-fail
+
+use spirv_std as _;
+
+#[spirv(compute(threads(8, 0)))]
+pub fn main() {}
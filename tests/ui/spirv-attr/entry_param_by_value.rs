@@ -0,0 +1,12 @@
+// Entry point parameters are interface variables, so aggregates can't be passed by value.
+// build-fail
+
+use spirv_std::storage_class::Output;
+
+#[spirv(fragment)]
+pub fn main(
+    values: [f32; 8],
+    mut out: Output<f32>,
+) {
+    *out = values[0];
+}
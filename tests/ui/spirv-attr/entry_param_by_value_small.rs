@@ -0,0 +1,12 @@
+// Small aggregates are passed by value as an integer, which isn't allowed either.
+// build-fail
+
+use spirv_std::storage_class::Output;
+
+#[spirv(fragment)]
+pub fn main(
+    value: [f32; 1],
+    mut out: Output<f32>,
+) {
+    *out = value[0];
+}
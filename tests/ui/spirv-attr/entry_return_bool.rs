@@ -0,0 +1,9 @@
+// Entry points can only return a single scalar or vector, which `bool`s can't be in an `Output`.
+// build-fail
+
+use spirv_std as _;
+
+#[spirv(fragment)]
+pub fn main() -> bool {
+    true
+}
@@ -0,0 +1,9 @@
+// Only some stages can return a value, which is written to an `Output`.
+// build-fail
+
+use spirv_std as _;
+
+#[spirv(compute(threads(1)))]
+pub fn main() -> f32 {
+    0.0
+}
@@ -0,0 +1,14 @@
+// Writing `FragDepth` adds the `DepthReplacing` execution mode by itself.
+// build-pass
+
+use spirv_std as _;
+
+#[spirv(fragment)]
+pub fn main(#[spirv(frag_depth)] out_depth: &mut f32) {
+    *out_depth = 0.5;
+}
+
+#[spirv(fragment)]
+pub fn main_greater(#[spirv(frag_depth_greater)] out_depth: &mut f32) {
+    *out_depth = 0.75;
+}
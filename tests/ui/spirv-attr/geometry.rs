@@ -0,0 +1,15 @@
+// Geometry shaders get their inputs as arrays with one element per input vertex.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(geometry(input_triangles, output_triangle_strip, output_vertices = 3))]
+pub fn main(
+    #[spirv(position)] in_position: Input<[glam::Vec4; 3]>,
+    in_color: Input<[glam::Vec4; 3]>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    mut out_color: Output<glam::Vec4>,
+) {
+    *out_position = in_position[0];
+    *out_color = in_color[0];
+}
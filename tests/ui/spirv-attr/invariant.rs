@@ -0,0 +1,13 @@
+// Outputs, builtin or not, can be marked invariant.
+// build-pass
+
+use spirv_std::storage_class::{Location, Output};
+
+#[spirv(vertex)]
+pub fn main(
+    #[spirv(position, invariant)] mut out_position: Output<glam::Vec4>,
+    #[spirv(invariant)] mut out_color: Output<glam::Vec4, Location<0>>,
+) {
+    *out_position = glam::Vec4::new(0.0, 0.0, 0.0, 1.0);
+    *out_color = glam::Vec4::splat(1.0);
+}
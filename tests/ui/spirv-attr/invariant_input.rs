@@ -0,0 +1,12 @@
+// Only outputs can be invariant.
+// build-fail
+
+use spirv_std::storage_class::{Input, Location, Output};
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(invariant)] in_color: Input<glam::Vec4, Location<0>>,
+    mut out_color: Output<glam::Vec4, Location<0>>,
+) {
+    *out_color = *in_color;
+}
@@ -0,0 +1,14 @@
+// Parameters of the same storage class can't share a location.
+// build-fail
+
+use spirv_std::storage_class::{Input, Location, Output};
+
+#[spirv(fragment)]
+pub fn main(
+    in_color: Input<glam::Vec4, Location<0>>,
+    mut out_color: Output<glam::Vec4, Location<1>>,
+    mut out_normal: Output<glam::Vec4, Location<1>>,
+) {
+    *out_color = *in_color;
+    *out_normal = *in_color;
+}
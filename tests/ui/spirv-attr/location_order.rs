@@ -0,0 +1,20 @@
+// Explicit locations may be declared in any order, and take up as many
+// locations as their type needs.
+// build-pass
+
+use spirv_std::matrix::Mat4;
+use spirv_std::storage_class::{Input, Location, Output};
+
+#[spirv(vertex)]
+pub fn main(
+    position: Input<glam::Vec4, Location<0>>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    mut out_color: Output<glam::Vec4, Location<4>>,
+    mut out_transform: Output<Mat4<glam::Vec4>, Location<0>>,
+    mut out_uv: Output<glam::Vec2, Location<5>>,
+) {
+    *out_position = *position;
+    *out_color = *position;
+    *out_transform = Mat4::from_cols(*position, *position, *position, *position);
+    *out_uv = glam::Vec2::new(position.x, position.y);
+}
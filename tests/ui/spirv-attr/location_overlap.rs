@@ -0,0 +1,14 @@
+// A matrix takes up one location per column, which other parameters can't reuse.
+// build-fail
+
+use spirv_std::matrix::Mat4;
+use spirv_std::storage_class::{Location, Output};
+
+#[spirv(vertex)]
+pub fn main(
+    mut out_color: Output<glam::Vec4, Location<2>>,
+    mut out_transform: Output<Mat4<glam::Vec4>, Location<0>>,
+) {
+    *out_color = glam::Vec4::splat(1.0);
+    *out_transform = Mat4::default();
+}
@@ -0,0 +1,33 @@
+// Test that `#[spirv(loop(...))]` bounds are accepted by `OpLoopMerge`, also
+// when combined with `#[spirv(unroll_loops(count = N))]`.
+// build-pass
+// compile-flags: -C target-feature=+spirv1.4
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(loop(min_iterations = 1, max_iterations = 64))]
+fn march(origin: f32, step: f32) -> f32 {
+    let mut t = origin;
+    let mut i = 0;
+    while i < 64 && t < 100.0 {
+        t += step;
+        i += 1;
+    }
+    t
+}
+
+#[spirv(loop(dependency_length = 2), unroll_loops(count = 4))]
+fn sum(count: u32) -> u32 {
+    let mut total = 0;
+    let mut i = 0;
+    while i < count {
+        total += i;
+        i += 1;
+    }
+    total
+}
+
+#[spirv(fragment)]
+pub fn main(#[spirv(flat)] count: Input<u32>, mut out: Output<f32>) {
+    *out = march(0.0, 0.5) + sum(*count) as f32;
+}
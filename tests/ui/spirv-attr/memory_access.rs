@@ -0,0 +1,21 @@
+// Memory access qualifiers on storage buffers, from attributes and from `ReadOnlyStorageBuffer`.
+// build-pass
+
+use spirv_std::storage_class::{Bind, Input, ReadOnlyStorageBuffer, StorageBuffer};
+
+#[derive(Copy, Clone)]
+#[spirv(block, layout = "std430")]
+pub struct Data {
+    pub values: [f32; 64],
+}
+
+#[spirv(compute(threads(64)))]
+pub fn main(
+    #[spirv(local_invocation_index)] index: Input<u32>,
+    input: Bind<ReadOnlyStorageBuffer<Data>, 0, 0>,
+    #[spirv(readonly)] scale: Bind<StorageBuffer<Data>, 0, 1>,
+    #[spirv(writeonly, restrict)] mut output: Bind<StorageBuffer<Data>, 0, 2>,
+) {
+    let index = *index as usize;
+    unsafe { output.deref_mut() }.values[index] = input.values[index] * scale.values[index];
+}
@@ -0,0 +1,10 @@
+// The same function can be used as the entry point of several execution models,
+// each with its own interface variables.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(vertex, fragment)]
+pub fn main(input: Input<f32>, mut output: Output<f32>) {
+    *output = *input;
+}
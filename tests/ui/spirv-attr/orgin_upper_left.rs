@@ -0,0 +1,7 @@
+// The old misspelling of `origin_upper_left` is still accepted, with a warning.
+// build-pass
+
+use spirv_std as _;
+
+#[spirv(fragment(orgin_upper_left))]
+pub fn main() {}
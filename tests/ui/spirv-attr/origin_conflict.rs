@@ -0,0 +1,7 @@
+// A fragment entry point can only have one origin.
+// build-fail
+
+use spirv_std as _;
+
+#[spirv(fragment(origin_upper_left, origin_lower_left))]
+pub fn main() {}
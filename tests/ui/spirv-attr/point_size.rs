@@ -0,0 +1,17 @@
+// Point sizes passed through a geometry shader, which needs the
+// `GeometryPointSize` capability.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(geometry(input_points, output_points, output_vertices = 1))]
+pub fn main(
+    #[spirv(position)] in_position: Input<[glam::Vec4; 1]>,
+    #[spirv(point_size)] in_point_size: Input<[f32; 1]>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    #[spirv(point_size)] mut out_point_size: Output<f32>,
+) {
+    *out_position = in_position[0];
+    *out_point_size = in_point_size[0] * 2.0;
+    spirv_std::arch::emit_vertex();
+}
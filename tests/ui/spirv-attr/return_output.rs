@@ -0,0 +1,18 @@
+// Entry points returning a value, which is written to an `Output` at location 0.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(vertex)]
+pub fn main_vs(
+    position: Input<glam::Vec4>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+) -> glam::Vec2 {
+    *out_position = *position;
+    glam::Vec2::new(position.x, position.y)
+}
+
+#[spirv(fragment)]
+pub fn main_fs(uv: Input<glam::Vec2>) -> glam::Vec4 {
+    glam::Vec4::new(uv.x, uv.y, 0.0, 1.0)
+}
@@ -0,0 +1,24 @@
+// Per-sample shading, from the `sample_id` and `sample_position` builtins and
+// `sample` interpolation.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(vertex)]
+pub fn main_vs(
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+    #[spirv(sample)] mut out_uv: Output<glam::Vec2>,
+) {
+    *out_position = glam::Vec4::splat(1.0);
+    *out_uv = glam::Vec2::new(0.0, 1.0);
+}
+
+#[spirv(fragment)]
+pub fn main_fs(
+    #[spirv(sample_id)] sample_id: Input<u32>,
+    #[spirv(sample_position)] sample_position: Input<glam::Vec2>,
+    #[spirv(sample)] uv: Input<glam::Vec2>,
+    mut output: Output<glam::Vec4>,
+) {
+    *output = glam::Vec4::new(uv.x, uv.y, sample_position.x, *sample_id as f32);
+}
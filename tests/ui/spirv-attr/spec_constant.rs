@@ -0,0 +1,19 @@
+// Entry point parameters can be specialization constants.
+// build-pass
+
+use spirv_std::storage_class::Output;
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(spec_constant(id = 0, default = 4))] count: u32,
+    #[spirv(spec_constant(id = 1, default = 0.5))] scale: f32,
+    #[spirv(spec_constant(id = 2, default = true))] enabled: bool,
+    #[spirv(spec_constant(id = 3))] offset: i32,
+    mut out: Output<f32>,
+) {
+    *out = if enabled {
+        count as f32 * scale + offset as f32
+    } else {
+        0.0
+    };
+}
@@ -0,0 +1,12 @@
+// Spec constants can't be `const`s, whose value rustc inlines wherever they're used.
+// build-fail
+
+use spirv_std::storage_class::Output;
+
+#[spirv(spec_constant(id = 0, default = 64))]
+const SIZE: u32 = 64;
+
+#[spirv(fragment)]
+pub fn main(mut out: Output<u32>) {
+    *out = SIZE * 2;
+}
@@ -0,0 +1,21 @@
+// A `SpecId` can only be used once per entry point, and the same way by every entry point.
+// build-fail
+
+use spirv_std::storage_class::Output;
+
+#[spirv(fragment)]
+pub fn main(
+    #[spirv(spec_constant(id = 0))] count: u32,
+    #[spirv(spec_constant(id = 0))] offset: u32,
+    mut out: Output<u32>,
+) {
+    *out = count + offset;
+}
+
+#[spirv(fragment)]
+pub fn main_scaled(
+    #[spirv(spec_constant(id = 0, default = 0.5))] scale: f32,
+    mut out: Output<f32>,
+) {
+    *out = scale;
+}
@@ -0,0 +1,16 @@
+// Statics can be specialization constants, and integer arithmetic on them stays one.
+// build-pass
+
+use spirv_std::storage_class::Output;
+
+#[spirv(spec_constant(id = 0))]
+static SIZE: u32 = 64;
+
+#[spirv(spec_constant(id = 1, default = 0.5))]
+static SCALE: f32 = 0.5;
+
+#[spirv(fragment)]
+pub fn main(mut count: Output<u32>, mut scaled: Output<f32>) {
+    *count = SIZE * 2 + 1;
+    *scaled = SCALE * 4.0;
+}
@@ -0,0 +1,14 @@
+// Spec constant statics can't be `mut`, and default to their initializer.
+// build-fail
+
+#[spirv(spec_constant(id = 0))]
+pub static mut COUNT: u32 = 4;
+
+#[spirv(spec_constant(id = 1))]
+pub static ENABLED: bool = true;
+
+#[spirv(spec_constant(id = 2, default = 8))]
+pub static SIZE: u32 = 64;
+
+#[spirv(fragment)]
+pub fn main() {}
@@ -0,0 +1,34 @@
+// Tessellation shaders get their per-vertex inputs and outputs as arrays,
+// and write the tessellation levels as builtin arrays.
+// build-pass
+
+use spirv_std::storage_class::{Input, Output};
+
+#[spirv(tessellation_control(output_vertices = 3))]
+pub fn tess_control(
+    #[spirv(invocation_id)] invocation_id: Input<u32>,
+    in_position: Input<[glam::Vec4; 32]>,
+    mut out_position: Output<[glam::Vec4; 3]>,
+    #[spirv(patch)] mut out_patch_scale: Output<f32>,
+    #[spirv(tess_level_outer)] mut tess_level_outer: Output<[f32; 4]>,
+    #[spirv(tess_level_inner)] mut tess_level_inner: Output<[f32; 2]>,
+) {
+    let invocation_id = *invocation_id as usize;
+    out_position[invocation_id] = in_position[invocation_id];
+    *out_patch_scale = 1.0;
+    *tess_level_outer = [4.0, 4.0, 4.0, 1.0];
+    *tess_level_inner = [4.0, 1.0];
+}
+
+#[spirv(tessellation_evaluation(triangles, spacing_equal, vertex_order_ccw))]
+pub fn tess_evaluation(
+    #[spirv(tess_coord)] tess_coord: Input<glam::Vec3>,
+    in_position: Input<[glam::Vec4; 32]>,
+    #[spirv(patch)] in_patch_scale: Input<f32>,
+    #[spirv(position)] mut out_position: Output<glam::Vec4>,
+) {
+    *out_position = (in_position[0] * tess_coord.x
+        + in_position[1] * tess_coord.y
+        + in_position[2] * tess_coord.z)
+        * *in_patch_scale;
+}
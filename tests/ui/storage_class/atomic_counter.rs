@@ -0,0 +1,11 @@
+// Test the operations on `AtomicCounter` storage.
+// build-pass
+
+use spirv_std::storage_class::{AtomicCounter, Output};
+
+#[spirv(fragment)]
+pub fn main(counter: AtomicCounter<u32>, mut out: Output<u32>) {
+    let index = counter.increment();
+    counter.decrement();
+    *out = index + counter.load();
+}
@@ -0,0 +1,22 @@
+// A `ShaderStruct` that is fine under `#[repr(C)]`, but not under std140,
+// which aligns nested structs to 16 bytes.
+// build-fail
+
+use spirv_std::ShaderStruct;
+
+#[repr(C)]
+pub struct Material {
+    pub roughness: f32,
+    pub metallic: f32,
+    pub ior: f32,
+}
+
+#[derive(ShaderStruct)]
+#[repr(C)]
+pub struct Light {
+    pub intensity: f32,
+    pub material: Material,
+}
+
+#[spirv(fragment)]
+pub fn main() {}
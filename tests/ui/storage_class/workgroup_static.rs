@@ -0,0 +1,21 @@
+// `#[spirv(workgroup)]` statics can be zeroed or uninitialized, as their initializer is ignored.
+// build-pass
+
+use core::mem::MaybeUninit;
+use spirv_std::storage_class::Input;
+
+#[spirv(workgroup)]
+static mut COUNTS: [u32; 64] = [0; 64];
+
+#[spirv(workgroup)]
+static mut TILE: MaybeUninit<[f32; 64]> = MaybeUninit::uninit();
+
+#[spirv(compute(threads(64)))]
+pub fn main(#[spirv(local_invocation_index)] index: Input<u32>) {
+    let index = *index as usize;
+    unsafe {
+        COUNTS[index] = 1;
+        (*TILE.as_mut_ptr())[index] = 1.0;
+    }
+    spirv_std::arch::workgroup_barrier();
+}
@@ -0,0 +1,13 @@
+// The initializer of `#[spirv(workgroup)]` statics would be ignored, so it must be zeroed.
+// build-fail
+
+use spirv_std::storage_class::Input;
+
+#[spirv(workgroup)]
+static mut TILE: [f32; 64] = [1.0; 64];
+
+#[spirv(compute(threads(64)))]
+pub fn main(#[spirv(local_invocation_index)] index: Input<u32>) {
+    unsafe { TILE[*index as usize] += 1.0 };
+    spirv_std::arch::workgroup_barrier();
+}